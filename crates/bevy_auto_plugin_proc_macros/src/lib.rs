@@ -102,12 +102,24 @@ pub fn auto_component(attr: CompilerStream, input: CompilerStream) -> CompilerSt
     handle_attribute(expand::attr::auto_component, attr, input)
 }
 
+#[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/rewrites/auto_relationship.md"))]
+#[proc_macro_attribute]
+pub fn auto_relationship(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(expand::attr::auto_relationship, attr, input)
+}
+
 #[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/rewrites/auto_resource.md"))]
 #[proc_macro_attribute]
 pub fn auto_resource(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
     handle_attribute(expand::attr::auto_resource, attr, input)
 }
 
+#[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/rewrites/auto_bundle.md"))]
+#[proc_macro_attribute]
+pub fn auto_bundle(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(expand::attr::auto_bundle, attr, input)
+}
+
 #[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/rewrites/auto_event.md"))]
 #[proc_macro_attribute]
 pub fn auto_event(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
@@ -156,6 +168,24 @@ pub fn auto_bind_plugin(attr: CompilerStream, input: CompilerStream) -> Compiler
     handle_attribute(expand::attr::auto_bind_plugin::auto_bind_plugin_outer, attr, input)
 }
 
+#[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/auto_assoc_fns.md"))]
+#[proc_macro_attribute]
+pub fn auto_assoc_fns(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(expand::attr::auto_assoc_fns::auto_assoc_fns_outer, attr, input)
+}
+
+#[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/auto_export.md"))]
+#[proc_macro_attribute]
+pub fn auto_export(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
+    handle_attribute(expand::attr::auto_export::auto_export_outer, attr, input)
+}
+
+#[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_macros/auto_generics_set.md"))]
+#[proc_macro]
+pub fn auto_generics_set(input: CompilerStream) -> CompilerStream {
+    expand::func::auto_generics_set::auto_generics_set_outer(input.into()).into()
+}
+
 #[doc = include_str!(concat!(env!("OUT_DIR"), "/docs/proc_attributes/actions/auto_plugin_build_hook.md"))]
 #[proc_macro_attribute]
 pub fn auto_plugin_build_hook(attr: CompilerStream, input: CompilerStream) -> CompilerStream {
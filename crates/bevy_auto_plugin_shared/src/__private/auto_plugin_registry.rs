@@ -1,5 +1,9 @@
 // derived from Bevy Butler - MIT/Apache 2.0 https://github.com/TGRCdev/bevy-butler/blob/4eca26421d275134e0adc907e8c851bdcf10823a/bevy-butler/src/__private/plugin.rs
 
+use bevy_ecs::{
+    schedule::ScheduleConfigs,
+    system::ScheduleSystem,
+};
 use proc_macro2::{
     Ident,
     TokenStream as MacroStream,
@@ -19,6 +23,7 @@ use syn::{
 };
 
 pub use bevy_app;
+pub use bevy_ecs;
 #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
 pub use inventory;
 #[cfg(all(not(target_arch = "wasm32"), not(feature = "inventory")))]
@@ -49,12 +54,18 @@ pub static AUTO_PLUGIN_REGISTRY_BEFORE_BUILD: LazyLock<AutoPluginRegistry> = Laz
 
     #[allow(unused_variables)]
     let mut count = 0;
-    let mut registry: HashMap<TypeId, Vec<(RegistryOrder, BevyAppBuildFn)>> = HashMap::new();
+    let mut registry: HashMap<TypeId, Vec<RegistryEntry>> = HashMap::new();
 
-    for (ix, AutoPluginRegistryEntryFactoryBeforeBuild(type_factory, sys_factory, order)) in
-        iter.enumerate()
+    for (
+        ix,
+        AutoPluginRegistryEntryFactoryBeforeBuild(type_factory, sys_factory, order, plugin_default),
+    ) in iter.enumerate()
     {
-        registry.entry(type_factory()).or_default().push((*order, *sys_factory));
+        registry.entry(type_factory()).or_default().push(RegistryEntry {
+            order: *order,
+            build_fn: *sys_factory,
+            plugin_default: *plugin_default,
+        });
         #[allow(unused_assignments)]
         {
             count = ix + 1;
@@ -62,17 +73,7 @@ pub static AUTO_PLUGIN_REGISTRY_BEFORE_BUILD: LazyLock<AutoPluginRegistry> = Laz
     }
 
     // Sort per-plugin entries by definition order for deterministic execution.
-    let mut registry = registry
-        .into_iter()
-        .map(|(type_id, mut entries)| {
-            entries.sort_by_key(|a| a.0);
-            let mut build_fns = Vec::with_capacity(entries.len());
-            for (_, build_fn) in entries {
-                build_fns.push(build_fn);
-            }
-            (type_id, build_fns)
-        })
-        .collect::<HashMap<_, _>>();
+    registry.values_mut().for_each(|entries| entries.sort_by_key(|entry| entry.order));
 
     // Trim down
     registry.values_mut().for_each(|vec| vec.shrink_to_fit());
@@ -95,12 +96,16 @@ pub static AUTO_PLUGIN_REGISTRY_AFTER_BUILD: LazyLock<AutoPluginRegistry> = Lazy
 
     #[allow(unused_variables)]
     let mut count = 0;
-    let mut registry: HashMap<TypeId, Vec<(RegistryOrder, BevyAppBuildFn)>> = HashMap::new();
+    let mut registry: HashMap<TypeId, Vec<RegistryEntry>> = HashMap::new();
 
     for (ix, AutoPluginRegistryEntryFactoryAfterBuild(type_factory, sys_factory, order)) in
         iter.enumerate()
     {
-        registry.entry(type_factory()).or_default().push((*order, *sys_factory));
+        registry.entry(type_factory()).or_default().push(RegistryEntry {
+            order: *order,
+            build_fn: *sys_factory,
+            plugin_default: false,
+        });
         #[allow(unused_assignments)]
         {
             count = ix + 1;
@@ -108,17 +113,7 @@ pub static AUTO_PLUGIN_REGISTRY_AFTER_BUILD: LazyLock<AutoPluginRegistry> = Lazy
     }
 
     // Sort per-plugin entries by definition order for deterministic execution.
-    let mut registry = registry
-        .into_iter()
-        .map(|(type_id, mut entries)| {
-            entries.sort_by_key(|a| a.0);
-            let mut build_fns = Vec::with_capacity(entries.len());
-            for (_, build_fn) in entries {
-                build_fns.push(build_fn);
-            }
-            (type_id, build_fns)
-        })
-        .collect::<HashMap<_, _>>();
+    registry.values_mut().for_each(|entries| entries.sort_by_key(|entry| entry.order));
 
     // Trim down
     registry.values_mut().for_each(|vec| vec.shrink_to_fit());
@@ -158,23 +153,80 @@ pub trait AutoPlugin: AutoPluginTypeId {
     fn after_build(&self, app: &mut bevy_app::App) {
         Self::static_after_build(app);
     }
+    /// Where entries without an explicit `after_build` run, set by `#[auto_plugin(defaults(after_build))]`
+    const DEFAULT_AFTER_BUILD: bool = false;
+    /// `#[auto_plugin(requires(..))]`, runs before any entry
+    #[inline]
+    fn require_plugins(_app: &mut bevy_app::App) {}
+    /// `#[auto_plugin(defaults(system(in_set = ..)))]`, skipped by systems with their own `in_set`
+    #[inline]
+    fn default_system_in_set(
+        systems: ScheduleConfigs<ScheduleSystem>,
+    ) -> ScheduleConfigs<ScheduleSystem> {
+        systems
+    }
+    /// `#[auto_plugin(defaults(system(run_if = ..)))]`, skipped by systems with their own `run_if`
+    #[inline]
+    fn default_system_run_if(
+        systems: ScheduleConfigs<ScheduleSystem>,
+    ) -> ScheduleConfigs<ScheduleSystem> {
+        systems
+    }
     fn static_build(app: &mut bevy_app::App) {
         let type_id = <Self as AutoPluginTypeId>::type_id();
-        AUTO_PLUGIN_REGISTRY_BEFORE_BUILD.get_entries(type_id).iter().for_each(|build_fn| {
-            build_fn(app);
-        });
+        super::plugin_dependencies::check_dependency_cycles(type_id, Self::static_name());
+        Self::require_plugins(app);
+        AUTO_PLUGIN_REGISTRY_BEFORE_BUILD
+            .get_entries(type_id)
+            .iter()
+            .filter(|entry| !(entry.plugin_default && Self::DEFAULT_AFTER_BUILD))
+            .for_each(|entry| {
+                (entry.build_fn)(app);
+            });
     }
     fn static_after_build(app: &mut bevy_app::App) {
         let type_id = <Self as AutoPluginTypeId>::type_id();
-        AUTO_PLUGIN_REGISTRY_AFTER_BUILD.get_entries(type_id).iter().for_each(|build_fn| {
-            build_fn(app);
+        let mut entries =
+            AUTO_PLUGIN_REGISTRY_AFTER_BUILD.get_entries(type_id).iter().collect::<Vec<_>>();
+        if Self::DEFAULT_AFTER_BUILD {
+            entries.extend(
+                AUTO_PLUGIN_REGISTRY_BEFORE_BUILD
+                    .get_entries(type_id)
+                    .iter()
+                    .filter(|entry| entry.plugin_default),
+            );
+            entries.sort_by_key(|entry| entry.order);
+        }
+        entries.into_iter().for_each(|entry| {
+            (entry.build_fn)(app);
         });
     }
 }
 
+/// Schedule used by `auto_system`/`auto_add_system`/`auto_configure_system_set` without a schedule
+///
+/// Implemented by `#[auto_plugin(default_schedule = ...)]`
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` has no default schedule",
+    label = "missing `schedule = ...`",
+    note = "either pass `schedule = ...` (or `fixed`, `startup`, `update`, `post_update`), or add `#[auto_plugin(default_schedule = ...)]` to `{Self}`"
+)]
+pub trait AutoPluginDefaultSchedule {
+    fn default_schedule() -> bevy_ecs::schedule::InternedScheduleLabel;
+}
+
+/// Type data a bare `reflect` on `auto_component` registers for `T`
+///
+/// Implemented by the `AutoPlugin` derive from `#[auto_plugin(defaults(component(reflect(...))))]`
+pub trait AutoPluginComponentReflect<T> {
+    #[inline]
+    fn register_component_reflect(_app: &mut bevy_app::App) {}
+}
+
 pub type TypeIdFn = fn() -> TypeId;
 pub type BevyAppBuildFn = fn(&mut bevy_app::App);
-pub struct AutoPluginRegistryEntryFactoryBeforeBuild(TypeIdFn, BevyAppBuildFn, RegistryOrder);
+/// The last field marks entries without an explicit `after_build`, which follow the plugin's default
+pub struct AutoPluginRegistryEntryFactoryBeforeBuild(TypeIdFn, BevyAppBuildFn, RegistryOrder, bool);
 pub struct AutoPluginRegistryEntryFactoryAfterBuild(TypeIdFn, BevyAppBuildFn, RegistryOrder);
 
 #[macro_export]
@@ -202,13 +254,26 @@ impl RegistryOrder {
     }
 }
 
+impl std::fmt::Display for RegistryOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.file, self.line, self.column)
+    }
+}
+
 impl AutoPluginRegistryEntryFactoryBeforeBuild {
     pub const fn new(
         type_factory: fn() -> TypeId,
         sys_factory: fn(&mut bevy_app::App),
         order: RegistryOrder,
     ) -> Self {
-        Self(type_factory, sys_factory, order)
+        Self(type_factory, sys_factory, order, false)
+    }
+    pub const fn plugin_default(
+        type_factory: fn() -> TypeId,
+        sys_factory: fn(&mut bevy_app::App),
+        order: RegistryOrder,
+    ) -> Self {
+        Self(type_factory, sys_factory, order, true)
     }
 }
 impl AutoPluginRegistryEntryFactoryAfterBuild {
@@ -220,10 +285,16 @@ impl AutoPluginRegistryEntryFactoryAfterBuild {
         Self(type_factory, sys_factory, order)
     }
 }
-pub struct AutoPluginRegistry(HashMap<TypeId, Vec<BevyAppBuildFn>>);
+pub(crate) struct RegistryEntry {
+    order: RegistryOrder,
+    build_fn: BevyAppBuildFn,
+    plugin_default: bool,
+}
+
+pub struct AutoPluginRegistry(HashMap<TypeId, Vec<RegistryEntry>>);
 
 impl AutoPluginRegistry {
-    pub(crate) fn get_entries(&'static self, marker: TypeId) -> &'static [BevyAppBuildFn] {
+    pub(crate) fn get_entries(&'static self, marker: TypeId) -> &'static [RegistryEntry] {
         self.0.get(&marker).map(|v| v.as_slice()).unwrap_or_default()
     }
 }
@@ -241,6 +312,24 @@ pub fn _plugin_entry_block(static_ident: &Ident, plugin: &Path, expr: &ExprClosu
     }
 }
 
+/// Entry without an explicit `after_build`, placed by the plugin's `defaults(after_build)`
+pub fn _plugin_entry_block_plugin_default(
+    static_ident: &Ident,
+    plugin: &Path,
+    expr: &ExprClosure,
+) -> MacroStream {
+    quote! {
+        ::bevy_auto_plugin::__private::shared::_plugin_entry!(
+            #static_ident,
+            ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPluginRegistryEntryFactoryBeforeBuild::plugin_default(
+                || <#plugin as ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPluginTypeId>::type_id(),
+                #expr,
+                ::bevy_auto_plugin::__private::shared::registry_order!()
+            )
+        );
+    }
+}
+
 pub fn _plugin_entry_block_after_build(
     static_ident: &Ident,
     plugin: &Path,
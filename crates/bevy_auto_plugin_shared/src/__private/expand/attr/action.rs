@@ -1,6 +1,22 @@
 use crate::{
+    __private::expand::{
+        attr::{
+            auto_export::exports_macro_invocation,
+            plugin_family::resolve_plugin_family,
+            sibling_conflicts::check_sibling_conflicts,
+        },
+        dump::dump,
+        func::auto_generics_set::generics_set_invocation,
+    },
     macro_api::prelude::*,
-    util::macros::ok_or_emit_with,
+    syntax::parse::generics_set::{
+        GenericsSets,
+        resolve_generics_sets,
+    },
+    util::macros::{
+        compile_error_with,
+        ok_or_emit_with,
+    },
 };
 use proc_macro2::TokenStream as MacroStream;
 use quote::{
@@ -18,9 +34,24 @@ where
         + ItemAttributeTargetMut
         + ItemAttributeUniqueIdent
         + ItemAttributeContext
-        + ItemAttributePlugin,
+        + ItemAttributePlugin
+        + ItemAttributeUseExportKind
+        + ItemAttributeArgsDebug,
     AppMutationEmitter<T>: ToTokens + EmitAppMutationTokens,
 {
+    let attr = match ok_or_emit_with!(resolve_generics_sets(attr.clone()), input) {
+        GenericsSets::Resolved(attr) => attr,
+        GenericsSets::Pending(set) => {
+            return generics_set_invocation(&set, T::full_attribute_path(), &attr, &input);
+        }
+    };
+    if let Some(output) =
+        ok_or_emit_with!(resolve_plugin_family(T::full_attribute_path(), &attr, &input), input)
+    {
+        return output;
+    }
+    let warnings = ok_or_emit_with!(check_sibling_conflicts(T::IDENT, &attr, &input), input);
+    let og_attr = attr.clone();
     let args = ok_or_emit_with!(
         T::from_attr_input_with_context(attr, input.clone(), Context::default()),
         input
@@ -36,13 +67,41 @@ where
         }
         app_mut_emitter.args.input_item().to_token_stream()
     };
-    let use_targets = ok_or_emit_with!(
-        app_mut_emitter.args.input_item().use_target_paths(),
-        processed_item.clone()
-    );
+    let use_targets =
+        ok_or_emit_with!(app_mut_emitter.args.input_item().use_targets(), processed_item.clone());
+    let mut emitted_item = processed_item.clone();
     let after_item_tokens = if let Some(use_targets) = use_targets {
+        emitted_item = ok_or_emit_with!(
+            app_mut_emitter.args.input_item().without_use_globs(),
+            processed_item.clone()
+        )
+        .to_token_stream();
         let mut tokens = MacroStream::new();
         for target in use_targets {
+            let target = match target {
+                UseTarget::Item(path) => path,
+                UseTarget::Module(module) => {
+                    let Some(kind) = app_mut_emitter.args.use_export_kind() else {
+                        let err = syn::Error::new_spanned(
+                            &module,
+                            format!(
+                                "`{}` can't target a glob or `self` import of an `#[auto_export]` \
+                                 module, import the items by name",
+                                T::IDENT
+                            ),
+                        );
+                        return compile_error_with!(err, processed_item);
+                    };
+                    // re-apply this attribute to every item of the module the action can take
+                    tokens.extend(exports_macro_invocation(
+                        &module,
+                        kind,
+                        T::full_attribute_path(),
+                        &og_attr,
+                    ));
+                    continue;
+                }
+            };
             let mut args = app_mut_emitter.args.clone();
             args.set_target(target);
             let mut per_target_emitter =
@@ -57,8 +116,52 @@ where
     } else {
         ok_or_emit_with!(app_mut_emitter.wrap_body(|body| quote! { #body }), processed_item)
     };
-    quote! {
-        #processed_item
+    let output = quote! {
+        #warnings
+        #emitted_item
         #after_item_tokens
-    }
+    };
+    dump(
+        T::IDENT,
+        app_mut_emitter.args.get_unique_ident(),
+        &input,
+        app_mut_emitter.args.args_debug(),
+        &output,
+    );
+    output
+}
+
+/// Expands an action attribute placed on an associated fn (no receiver) of an inherent impl.
+///
+/// Only the registry entry is returned, the fn itself stays inside the impl block.
+pub fn proc_attribute_assoc_fn_inner<T>(
+    attr: MacroStream,
+    item_impl: &syn::ItemImpl,
+    self_path: &syn::Path,
+    impl_fn: &syn::ImplItemFn,
+) -> syn::Result<MacroStream>
+where
+    T: ItemAttributeArgs
+        + ItemAttributeParse
+        + ItemAttributeInput
+        + ItemAttributeTarget
+        + ItemAttributeTargetMut
+        + ItemAttributeUniqueIdent
+        + ItemAttributeContext
+        + ItemAttributePlugin,
+    AppMutationEmitter<T>: ToTokens + EmitAppMutationTokens,
+{
+    // impl generics come first so `generics(...)` lists them before the fn's own params
+    let mut sig = impl_fn.sig.clone();
+    let mut params = item_impl.generics.params.clone();
+    params.extend(sig.generics.params);
+    sig.generics.params = params;
+    let input = quote! { #sig {} };
+
+    let mut args = T::from_attr_input_with_context(attr, input, Context::default())?;
+    let fn_ident = &impl_fn.sig.ident;
+    args.set_target(syn::parse_quote!(#self_path::#fn_ident));
+    let mut app_mut_emitter = AppMutationEmitter::from_args(args);
+    app_mut_emitter.post_process_inner_item().map_err(|(_, err)| err)?;
+    app_mut_emitter.wrap_body(|body| quote! { #body })
 }
@@ -0,0 +1,194 @@
+use crate::{
+    __private::expand::{
+        attr::{
+            action::proc_attribute_assoc_fn_inner,
+            rewrite::proc_attribute_rewrite_inner,
+        },
+        dump::dump,
+    },
+    macro_api::prelude::*,
+    util::macros::compile_error_with,
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::quote;
+use std::collections::VecDeque;
+use syn::{
+    ImplItem,
+    ImplItemFn,
+    ItemFn,
+    ItemImpl,
+    PathArguments,
+    Type,
+    parse2,
+    spanned::Spanned,
+};
+
+enum AssocFnAttr {
+    Action(fn(MacroStream, &ItemImpl, &syn::Path, &ImplItemFn) -> syn::Result<MacroStream>),
+    Rewrite(fn(MacroStream, MacroStream) -> syn::Result<MacroStream>),
+}
+
+fn resolve_assoc_fn_attr(attr: &syn::Attribute) -> Option<AssocFnAttr> {
+    let ident = attr.path().segments.last()?.ident.to_string();
+    Some(match ident.as_str() {
+        AddSystemArgs::IDENT => AssocFnAttr::Action(proc_attribute_assoc_fn_inner::<IaAddSystem>),
+        AddObserverArgs::IDENT => {
+            AssocFnAttr::Action(proc_attribute_assoc_fn_inner::<IaAddObserver>)
+        }
+        RunOnBuildArgs::IDENT => AssocFnAttr::Action(proc_attribute_assoc_fn_inner::<IaRunOnBuild>),
+        SystemArgs::IDENT => AssocFnAttr::Rewrite(proc_attribute_rewrite_inner::<IaSystem>),
+        ObserverArgs::IDENT => AssocFnAttr::Rewrite(proc_attribute_rewrite_inner::<IaObserver>),
+        _ => return None,
+    })
+}
+
+fn attr_args(attr: &syn::Attribute) -> syn::Result<MacroStream> {
+    Ok(match &attr.meta {
+        syn::Meta::Path(_) => MacroStream::new(),
+        syn::Meta::List(list) => list.tokens.clone(),
+        syn::Meta::NameValue(_) => {
+            return Err(syn::Error::new(attr.span(), "expected `#[attr]` or `#[attr(...)]`"));
+        }
+    })
+}
+
+/// `Foo<T>` -> `Foo::<T>` so the path can be used in expression position
+fn self_ty_path(item_impl: &ItemImpl) -> syn::Result<syn::Path> {
+    match item_impl.self_ty.as_ref() {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let mut path = type_path.path.clone();
+            for segment in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                    args.colon2_token = Some(Default::default());
+                }
+            }
+            Ok(path)
+        }
+        other => Err(syn::Error::new(other.span(), "auto_assoc_fns requires a named self type")),
+    }
+}
+
+fn expand_impl_fn(
+    item_impl: &ItemImpl,
+    self_path: &syn::Path,
+    impl_fn: &mut ImplItemFn,
+    out: &mut MacroStream,
+) -> syn::Result<()> {
+    let mut pending = impl_fn.attrs.drain(..).collect::<VecDeque<_>>();
+    let mut kept = Vec::new();
+    while let Some(attr) = pending.pop_front() {
+        let Some(kind) = resolve_assoc_fn_attr(&attr) else {
+            kept.push(attr);
+            continue;
+        };
+        if let Some(receiver) = impl_fn.sig.receiver() {
+            return Err(syn::Error::new(
+                receiver.span(),
+                "auto_* attributes require an associated fn without a `self` receiver",
+            ));
+        }
+        let args = attr_args(&attr)?;
+        match kind {
+            AssocFnAttr::Action(expand) => {
+                out.extend(expand(args, item_impl, self_path, impl_fn)?);
+            }
+            AssocFnAttr::Rewrite(rewrite) => {
+                // rewrites only swap attributes, so expand them against a free fn and
+                // queue the resulting attributes in place of the original
+                let ImplItemFn { sig, block, .. } = &*impl_fn;
+                let rewritten = parse2::<ItemFn>(rewrite(args, quote! { #sig #block })?)?;
+                for attr in rewritten.attrs.into_iter().rev() {
+                    pending.push_front(attr);
+                }
+            }
+        }
+    }
+    impl_fn.attrs = kept;
+    Ok(())
+}
+
+pub fn auto_assoc_fns_inner(attr: MacroStream, input: MacroStream) -> syn::Result<MacroStream> {
+    if !attr.is_empty() {
+        return Err(syn::Error::new(attr.span(), "auto_assoc_fns does not take any arguments"));
+    }
+    let mut item_impl = parse2::<ItemImpl>(input.clone())?;
+    if let Some((_, trait_path, _)) = &item_impl.trait_ {
+        return Err(syn::Error::new(
+            trait_path.span(),
+            "auto_assoc_fns is only supported on inherent impl blocks",
+        ));
+    }
+    let self_path = self_ty_path(&item_impl)?;
+    let mut entries = MacroStream::new();
+    let mut items = std::mem::take(&mut item_impl.items);
+    for item in items.iter_mut() {
+        if let ImplItem::Fn(impl_fn) = item {
+            expand_impl_fn(&item_impl, &self_path, impl_fn, &mut entries)?;
+        }
+    }
+    item_impl.items = items;
+    let output = quote! {
+        #item_impl
+        #entries
+    };
+    let key =
+        self_path.segments.iter().map(|segment| segment.ident.to_string()).collect::<Vec<_>>();
+    dump("auto_assoc_fns", format_args!("auto_assoc_fns_{}", key.join("_")), &input, &(), &output);
+    Ok(output)
+}
+
+pub fn auto_assoc_fns_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
+    let og_input = input.clone();
+    auto_assoc_fns_inner(attr, input).unwrap_or_else(|err| compile_error_with!(err, og_input))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use quote::ToTokens;
+
+    #[xtest]
+    fn test_auto_assoc_fns_strips_attributes() {
+        let output = auto_assoc_fns_inner(
+            quote!(),
+            quote! {
+                impl Player {
+                    #[auto_add_system(plugin = TestPlugin, schedule = Update)]
+                    #[inline]
+                    fn movement() {}
+                }
+            },
+        )
+        .expect("expected expansion");
+        let item_impl = parse2::<syn::File>(output).expect("expected items").items.remove(0);
+        assert_eq!(
+            item_impl.to_token_stream().to_string(),
+            quote! {
+                impl Player {
+                    #[inline]
+                    fn movement() {}
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[xtest]
+    fn test_auto_assoc_fns_rejects_receiver() {
+        let err = auto_assoc_fns_inner(
+            quote!(),
+            quote! {
+                impl Player {
+                    #[auto_add_system(plugin = TestPlugin, schedule = Update)]
+                    fn movement(&self) {}
+                }
+            },
+        )
+        .expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "auto_* attributes require an associated fn without a `self` receiver"
+        );
+    }
+}
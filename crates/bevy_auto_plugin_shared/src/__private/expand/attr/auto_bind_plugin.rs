@@ -1,4 +1,5 @@
 use crate::{
+    __private::expand::dump::dump,
     macro_api::prelude::*,
     syntax::extensions::item::ItemAttrsExt,
     util::macros::compile_error_with,
@@ -21,7 +22,8 @@ pub fn auto_bind_plugin_inner(
         )?;
 
     let plugin_path = item_attribute.args.plugin();
-    let plugin_after_build = item_attribute.args.plugin.after_build.is_present();
+    let plugin_after_build =
+        item_attribute.args.plugin.after_build.as_ref().is_some_and(|flag| flag.is_present());
     let item = item_attribute.input_item.ensure_ast_mut()?;
     let mut attrs = item.take_attrs().map_err(|err| syn::Error::new(item.span(), err))?;
 
@@ -29,7 +31,12 @@ pub fn auto_bind_plugin_inner(
 
     let Ok(_) = item.put_attrs(attrs) else { unreachable!() };
 
-    Ok(item.to_token_stream())
+    let output = item.to_token_stream();
+    if let Some(ident) = item.get_ident() {
+        let key = format!("auto_bind_plugin_{ident}");
+        dump("auto_bind_plugin", key, &input, &item_attribute.args, &output);
+    }
+    Ok(output)
 }
 
 pub fn auto_bind_plugin_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
@@ -0,0 +1,245 @@
+use crate::{
+    __private::expand::dump::dump,
+    macro_api::prelude::*,
+    syntax::analysis::item::has_derive,
+    util::macros::compile_error_with,
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    format_ident,
+    quote,
+};
+use syn::{
+    Ident,
+    Item,
+    ItemMod,
+    Visibility,
+    parse2,
+    spanned::Spanned,
+};
+
+/// name of the per-module macro re-export that `use module::*` expands through
+const EXPORTS_MACRO_IDENT: &str = "__bevy_auto_plugin_exports";
+
+pub fn auto_export_inner(attr: MacroStream, input: MacroStream) -> syn::Result<MacroStream> {
+    let args = parse2::<AutoExportArgs>(attr)?;
+    let mut item_mod = parse2::<ItemMod>(input.clone())?;
+    let mod_ident = item_mod.ident.clone();
+    let Some((_, items)) = &mut item_mod.content else {
+        return Err(syn::Error::new(
+            item_mod.span(),
+            "auto_export requires an inline module body: `mod name { ... }`",
+        ));
+    };
+
+    for excluded in args.exclude.iter() {
+        let found =
+            items.iter().any(|item| exported_ident(item).is_some_and(|i| excluded.is_ident(i)));
+        if !found {
+            return Err(syn::Error::new(
+                excluded.span(),
+                format!("`{}` is not an item of module `{mod_ident}`", excluded.to_token_stream()),
+            ));
+        }
+    }
+
+    let is_excluded = |ident: &Ident| args.exclude.iter().any(|path| path.is_ident(ident));
+    // each kind lists the items an action of that kind can take, globs skip everything else
+    let mut lists = UseExportKind::ALL.iter().map(|kind| (*kind, Vec::new())).collect::<Vec<_>>();
+    for item in items.iter() {
+        let ident = match item {
+            Item::Struct(item) if item.generics.params.is_empty() => &item.ident,
+            Item::Enum(item) if item.generics.params.is_empty() => &item.ident,
+            Item::Fn(item) if item.sig.generics.params.is_empty() => &item.sig.ident,
+            _ => continue,
+        };
+        if !exportable(item) || is_excluded(ident) {
+            continue;
+        }
+        for (kind, list) in lists.iter_mut() {
+            let listed = match item_attrs(item) {
+                Some(attrs) => kind.derives().iter().any(|derive| has_derive(attrs, derive)),
+                None => *kind == UseExportKind::Fns,
+            };
+            if listed {
+                list.push(ident.clone());
+            }
+        }
+    }
+
+    let hash = {
+        use std::hash::{
+            Hash,
+            Hasher,
+        };
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        item_mod.to_token_stream().to_string().hash(&mut hasher);
+        hasher.finish()
+    };
+    let macro_ident = format_ident!("{EXPORTS_MACRO_IDENT}_{mod_ident}_{hash:x}");
+    let alias_ident = format_ident!("{EXPORTS_MACRO_IDENT}");
+
+    let arm = |kind: UseExportKind, idents: &[Ident]| {
+        let description = match kind.derives() {
+            [] => "fns".to_string(),
+            derives => {
+                let derives = derives.iter().map(|derive| format!("`{derive}`"));
+                format!("types deriving {}", derives.collect::<Vec<_>>().join(" or "))
+            }
+        };
+        let kind = kind.ident();
+        if idents.is_empty() {
+            let message = format!(
+                "module `{mod_ident}` does not export any {description} for auto_* attributes"
+            );
+            quote! {
+                (#kind, [$($module:tt)*], [$($attr:tt)*]) => {
+                    ::core::compile_error!(#message);
+                };
+            }
+        } else {
+            quote! {
+                (#kind, [$($module:tt)*], [$($attr:tt)*]) => {
+                    #[$($attr)*]
+                    #[allow(unused_imports)]
+                    use $($module)*::{ #(#idents as _),* };
+                };
+            }
+        }
+    };
+    let arms = lists.iter().map(|(kind, idents)| arm(*kind, idents));
+
+    let exports: Vec<Item> = vec![
+        syn::parse_quote! {
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! #macro_ident {
+                #(#arms)*
+            }
+        },
+        syn::parse_quote! {
+            #[doc(hidden)]
+            pub use #macro_ident as #alias_ident;
+        },
+    ];
+    let Some((_, items)) = &mut item_mod.content else { unreachable!() };
+    items.extend(exports);
+
+    let output = item_mod.to_token_stream();
+    dump("auto_export", format_args!("auto_export_{mod_ident}"), &input, &args, &output);
+    Ok(output)
+}
+
+pub fn auto_export_outer(attr: MacroStream, input: MacroStream) -> MacroStream {
+    let og_input = input.clone();
+    auto_export_inner(attr, input).unwrap_or_else(|err| compile_error_with!(err, og_input))
+}
+
+/// Invokes the exports macro generated by `#[auto_export]` on `module`,
+/// re-applying `attr_path(attr)` to each of its exported items of the given kind
+pub fn exports_macro_invocation(
+    module: &syn::Path,
+    kind: UseExportKind,
+    attr_path: impl ToTokens,
+    attr: &MacroStream,
+) -> MacroStream {
+    let kind = kind.ident();
+    let alias_ident = format_ident!("{EXPORTS_MACRO_IDENT}");
+    quote! {
+        #module::#alias_ident!(#kind, [#module], [#attr_path(#attr)]);
+    }
+}
+
+fn exported_ident(item: &Item) -> Option<&Ident> {
+    match item {
+        Item::Struct(item) => Some(&item.ident),
+        Item::Enum(item) => Some(&item.ident),
+        Item::Type(item) => Some(&item.ident),
+        Item::Fn(item) => Some(&item.sig.ident),
+        _ => None,
+    }
+}
+
+fn item_attrs(item: &Item) -> Option<&[syn::Attribute]> {
+    match item {
+        Item::Struct(item) => Some(&item.attrs),
+        Item::Enum(item) => Some(&item.attrs),
+        _ => None,
+    }
+}
+
+/// private items can't be reached from the caller and `#[cfg]` would be evaluated
+/// in the caller's crate instead of this one, so both are skipped
+fn exportable(item: &Item) -> bool {
+    let (vis, attrs) = match item {
+        Item::Struct(item) => (&item.vis, &item.attrs),
+        Item::Enum(item) => (&item.vis, &item.attrs),
+        Item::Type(item) => (&item.vis, &item.attrs),
+        Item::Fn(item) => (&item.vis, &item.attrs),
+        _ => return false,
+    };
+    !matches!(vis, Visibility::Inherited) && !attrs.iter().any(|attr| attr.path().is_ident("cfg"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_auto_export_collects_public_items() {
+        let output = auto_export_outer(
+            quote!(exclude(Skipped)),
+            quote! {
+                mod components {
+                    #[derive(Reflect, Resource)]
+                    pub struct Foo;
+                    #[derive(Resource, States)]
+                    pub enum Bar { A }
+                    #[derive(Resource)]
+                    pub struct Skipped;
+                    pub struct Generic<T>(T);
+                    struct Private;
+                    pub fn system() {}
+                }
+            },
+        );
+        let item_mod = parse2::<ItemMod>(output).expect("expected a module");
+        let (_, items) = item_mod.content.expect("expected an inline module");
+        let Some(Item::Macro(exports)) = items.iter().rev().nth(1) else {
+            panic!("expected the exports macro");
+        };
+        let body = exports.mac.tokens.to_string();
+        let arm = |kind: &str, idents: MacroStream| {
+            let kind = format_ident!("{kind}");
+            quote!((#kind, [$($module:tt)*], [$($attr:tt)*]) => {
+                #[$($attr)*]
+                #[allow(unused_imports)]
+                use $($module)*::{ #idents };
+            })
+            .to_string()
+        };
+        assert!(body.contains(&arm("reflect", quote!(Foo as _))), "{body}");
+        assert!(body.contains(&arm("resource", quote!(Foo as _, Bar as _))), "{body}");
+        assert!(body.contains(&arm("state_types", quote!(Bar as _))), "{body}");
+        assert!(body.contains(&arm("fns", quote!(system as _))), "{body}");
+        assert!(
+            body.contains("module `components` does not export any types deriving `Message`"),
+            "{body}"
+        );
+        assert!(!body.contains("Skipped"), "{body}");
+        assert!(!body.contains("Private"), "{body}");
+        assert!(!body.contains("Generic"), "{body}");
+    }
+
+    #[xtest]
+    fn test_auto_export_unknown_exclude() {
+        let err = auto_export_inner(
+            quote!(exclude(Missing)),
+            quote! { mod components { pub struct Foo; } },
+        )
+        .expect_err("expected an error");
+        assert_eq!(err.to_string(), "`Missing` is not an item of module `components`");
+    }
+}
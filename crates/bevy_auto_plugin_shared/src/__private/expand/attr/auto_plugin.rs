@@ -1,7 +1,10 @@
-use crate::util::macros::{
-    compile_error_with,
-    ok_or_emit_with,
-    parse_macro_input2_or_emit_with,
+use crate::{
+    __private::expand::dump::dump,
+    util::macros::{
+        compile_error_with,
+        ok_or_emit_with,
+        parse_macro_input2_or_emit_with,
+    },
 };
 use proc_macro2::TokenStream as MacroStream;
 use syn::ItemFn;
@@ -77,7 +80,7 @@ pub fn expand_auto_plugin(attr: MacroStream, input: MacroStream) -> MacroStream
                 og_input
             );
         }
-        let Some(plugin_ident) = params.plugin else {
+        let Some(plugin_ident) = &params.plugin else {
             return compile_error_with!(
                 syn::Error::new(
                     params.plugin.span(),
@@ -103,7 +106,7 @@ pub fn expand_auto_plugin(attr: MacroStream, input: MacroStream) -> MacroStream
         )
     };
 
-    quote! {
+    let output = quote! {
         #(#attrs)*
         #vis #sig
         {
@@ -113,5 +116,7 @@ pub fn expand_auto_plugin(attr: MacroStream, input: MacroStream) -> MacroStream
         }
 
         #impl_plugin
-    }
+    };
+    dump("auto_plugin", format_args!("auto_plugin_{fn_ident}"), &og_input, &params, &output);
+    output
 }
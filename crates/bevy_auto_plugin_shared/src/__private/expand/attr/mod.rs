@@ -2,9 +2,13 @@ use crate::macro_api::prelude::*;
 use proc_macro2::TokenStream as MacroStream;
 
 pub mod action;
+pub mod auto_assoc_fns;
 pub mod auto_bind_plugin;
+pub mod auto_export;
 pub mod auto_plugin;
+pub mod plugin_family;
 pub mod rewrite;
+pub mod sibling_conflicts;
 
 macro_rules! gen_action_outers {
     ( $( $fn:ident => $args:ty ),+ $(,)? ) => {
@@ -46,7 +50,9 @@ gen_action_outers! {
 }
 
 gen_rewrite_outers! {
+    auto_bundle    => IaBundle,
     auto_component => IaComponent,
+    auto_relationship => IaRelationship,
     auto_resource  => IaResource,
     auto_system    => IaSystem,
     auto_event     => IaEvent,
@@ -0,0 +1,201 @@
+//! `plugin = StatPlugin::<T>` on a generic item: one attribute for every instance in its `generics`
+//!
+//! Rust only builds `regen::<Health>` if something names `Health`, so the instances come from the
+//! attribute's own `generics(...)` (with `product(...)` and `auto_generics_set!` sets), each
+//! substituted into the plugin, e.g. `plugin = StatPlugin::<Health>, generics(Health)`.
+
+use crate::syntax::ast::type_list::type_lists_from_meta;
+use darling::ast::NestedMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+use std::collections::HashMap;
+use syn::{
+    Expr,
+    GenericArgument,
+    GenericParam,
+    Generics,
+    Item,
+    Meta,
+    PathArguments,
+    Token,
+    Type,
+    parse::Parser,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// `Some(output)` when the attribute targets a plugin family, `None` to expand it as is
+pub fn resolve_plugin_family(
+    attr_path: impl ToTokens,
+    attr: &MacroStream,
+    input: &MacroStream,
+) -> syn::Result<Option<MacroStream>> {
+    let Ok(items) = Punctuated::<NestedMeta, Token![,]>::parse_terminated.parse2(attr.clone())
+    else {
+        return Ok(None);
+    };
+    let Some(plugin) = items.iter().find_map(|item| match item {
+        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("plugin") => match &nv.value {
+            Expr::Path(path) => Some(path.path.clone()),
+            _ => None,
+        },
+        _ => None,
+    }) else {
+        return Ok(None);
+    };
+    let Some(generics) = item_generics(input) else {
+        return Ok(None);
+    };
+    let params = generics
+        .params
+        .iter()
+        .filter(|param| !matches!(param, GenericParam::Lifetime(_)))
+        .cloned()
+        .collect::<Vec<_>>();
+    let param_idents = params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(param) => Some(param.ident.to_string()),
+            GenericParam::Const(param) => Some(param.ident.to_string()),
+            GenericParam::Lifetime(_) => None,
+        })
+        .collect::<Vec<_>>();
+    let plugin_args = match plugin.segments.last().map(|segment| &segment.arguments) {
+        Some(PathArguments::AngleBracketed(args)) => args.args.iter().cloned().collect::<Vec<_>>(),
+        _ => vec![],
+    };
+    let param_of = |arg: &GenericArgument| match arg {
+        GenericArgument::Type(Type::Path(ty)) if ty.qself.is_none() => ty
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .filter(|ident| param_idents.contains(ident)),
+        _ => None,
+    };
+    if !plugin_args.iter().any(|arg| param_of(arg).is_some()) {
+        return Ok(None);
+    }
+
+    let mut instances = vec![];
+    let mut kept = vec![];
+    for item in &items {
+        match item {
+            NestedMeta::Meta(meta) if meta.path().is_ident("generics") => {
+                instances.extend(type_lists_from_meta(meta)?);
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("plugin") => {}
+            item => kept.push(item.to_token_stream()),
+        }
+    }
+    if instances.is_empty() {
+        return Err(syn::Error::new(
+            plugin.span(),
+            format!(
+                "`plugin = {}` needs the instances to build, e.g. `generics(Health)` or a set \
+                 declared with `auto_generics_set!`",
+                plugin.to_token_stream().to_string().replace(' ', ""),
+            ),
+        ));
+    }
+
+    let attr_path = attr_path.to_token_stream();
+    let mut attrs = vec![];
+    for instance in &instances {
+        let args = instance.resolve_args(&params)?;
+        let assigned = param_idents.iter().cloned().zip(args).collect::<HashMap<_, _>>();
+        // `StatPlugin::<T, u8>` keeps `u8`, only the item's params are substituted
+        let concrete_args = plugin_args
+            .iter()
+            .map(|arg| match param_of(arg) {
+                Some(param) => assigned[&param].clone(),
+                None => arg.clone(),
+            })
+            .collect::<Vec<_>>();
+        let mut concrete_plugin = plugin.clone();
+        if let Some(segment) = concrete_plugin.segments.last_mut() {
+            segment.arguments =
+                PathArguments::AngleBracketed(syn::parse_quote!(::<#(#concrete_args),*>));
+        }
+        attrs.push(quote! {
+            #[#attr_path(plugin = #concrete_plugin, generics(#instance), #(#kept),*)]
+        });
+    }
+    Ok(Some(quote! {
+        #(#attrs)*
+        #input
+    }))
+}
+
+fn item_generics(input: &MacroStream) -> Option<Generics> {
+    let generics = match syn::parse2::<Item>(input.clone()).ok()? {
+        Item::Fn(item) => item.sig.generics,
+        Item::Struct(item) => item.generics,
+        Item::Enum(item) => item.generics,
+        Item::Union(item) => item.generics,
+        Item::Type(item) => item.generics,
+        _ => return None,
+    };
+    Some(generics)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    fn resolve(attr: MacroStream, input: MacroStream) -> syn::Result<Option<String>> {
+        resolve_plugin_family(quote!(auto_add_system), &attr, &input)
+            .map(|output| output.map(|output| output.to_string()))
+    }
+
+    #[xtest]
+    fn test_concrete_plugin_is_not_a_family() -> syn::Result<()> {
+        let input = quote! { fn regen<T: Stat>() {} };
+        assert_eq!(
+            resolve(quote! { plugin = StatPlugin::<Health>, generics(Health) }, input)?,
+            None
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_family_expands_every_instance() -> syn::Result<()> {
+        let input = quote! { fn regen<T: Stat>() {} };
+        let attr = quote! {
+            plugin = stats::StatPlugin::<T, u8>,
+            generics(Health),
+            generics(product(T = [Mana, Stamina])),
+            schedule = Update
+        };
+        assert_eq!(
+            resolve(attr, input.clone())?,
+            Some(
+                quote! {
+                    #[auto_add_system(plugin = stats::StatPlugin::<Health, u8>, generics(Health), schedule = Update)]
+                    #[auto_add_system(plugin = stats::StatPlugin::<Mana, u8>, generics(T = Mana), schedule = Update)]
+                    #[auto_add_system(plugin = stats::StatPlugin::<Stamina, u8>, generics(T = Stamina), schedule = Update)]
+                    #input
+                }
+                .to_string()
+            )
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_family_errors() {
+        let input = quote! { fn regen<T: Stat, U>() {} };
+        let err = resolve(quote! { plugin = StatPlugin::<T>, generics(Health) }, input.clone())
+            .unwrap_err();
+        assert!(err.to_string().contains("unexpected number of generic arguments"), "{err}");
+
+        let err = resolve(quote! { plugin = StatPlugin::<T> }, input).unwrap_err();
+        assert!(
+            err.to_string().starts_with("`plugin = StatPlugin::<T>` needs the instances"),
+            "{err}"
+        );
+    }
+}
@@ -1,5 +1,14 @@
 use crate::{
+    __private::expand::{
+        attr::sibling_conflicts::check_sibling_conflicts,
+        dump::dump,
+        func::auto_generics_set::generics_set_invocation,
+    },
     macro_api::prelude::*,
+    syntax::parse::generics_set::{
+        GenericsSets,
+        resolve_generics_sets,
+    },
     util::macros::ok_or_emit_with,
 };
 use proc_macro2::TokenStream as MacroStream;
@@ -7,12 +16,42 @@ use quote::ToTokens;
 
 pub fn proc_attribute_rewrite_outer<T>(attr: MacroStream, input: MacroStream) -> MacroStream
 where
-    AttrExpansionEmitter<T>: ToTokens,
-    T: ItemAttributeArgs + ItemAttributeParse + ItemAttributeInput + ItemAttributeContext,
+    AttrExpansionEmitter<T>: ToTokens + AttrExpansionEmitterToExpandAttr,
+    T: ItemAttributeArgs
+        + ItemAttributeParse
+        + ItemAttributeInput
+        + ItemAttributeContext
+        + ItemAttributeUniqueIdent
+        + ItemAttributeArgsDebug,
 {
-    let args = ok_or_emit_with!(
-        T::from_attr_input_with_context(attr, input.clone(), Context::default()),
-        input
-    );
-    AttrExpansionEmitter::from_item_attribute(args).to_token_stream()
+    let attr = match ok_or_emit_with!(resolve_generics_sets(attr.clone()), input) {
+        GenericsSets::Resolved(attr) => attr,
+        GenericsSets::Pending(set) => {
+            return generics_set_invocation(&set, T::full_attribute_path(), &attr, &input);
+        }
+    };
+    ok_or_emit_with!(proc_attribute_rewrite_inner::<T>(attr, input.clone()), input)
+}
+
+pub fn proc_attribute_rewrite_inner<T>(
+    attr: MacroStream,
+    input: MacroStream,
+) -> syn::Result<MacroStream>
+where
+    AttrExpansionEmitter<T>: ToTokens + AttrExpansionEmitterToExpandAttr,
+    T: ItemAttributeArgs
+        + ItemAttributeParse
+        + ItemAttributeInput
+        + ItemAttributeContext
+        + ItemAttributeUniqueIdent
+        + ItemAttributeArgsDebug,
+{
+    let warnings = check_sibling_conflicts(T::IDENT, &attr, &input)?;
+    let args = T::from_attr_input_with_context(attr, input.clone(), Context::default())?;
+    let emitter = AttrExpansionEmitter::from_item_attribute(args);
+    emitter.validate()?;
+    let mut output = warnings;
+    output.extend(emitter.to_token_stream());
+    dump(T::IDENT, emitter.args.get_unique_ident(), &input, emitter.args.args_debug(), &output);
+    Ok(output)
 }
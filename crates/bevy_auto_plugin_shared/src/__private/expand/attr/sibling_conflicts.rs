@@ -0,0 +1,305 @@
+use crate::syntax::extensions::item::ItemAttrsExt;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    format_ident,
+    quote_spanned,
+};
+use syn::{
+    Meta,
+    PathArguments,
+    Token,
+    parse::Parser,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// Attributes that can't be stacked on the same item
+struct Conflict {
+    attributes: [&'static str; 2],
+    /// only conflicts when both attributes target the same plugin and generics
+    same_plugin: bool,
+    /// stacking used to be accepted, so it's a deprecation warning instead of an error
+    deprecated: bool,
+    reason: &'static str,
+}
+
+const CONFLICTS: &[Conflict] = &[
+    Conflict {
+        attributes: ["auto_component", "auto_resource"],
+        same_plugin: false,
+        deprecated: false,
+        reason: "a type is either a component or a resource",
+    },
+    Conflict {
+        attributes: ["auto_component", "auto_bundle"],
+        same_plugin: false,
+        deprecated: false,
+        reason: "every component is already a bundle",
+    },
+    Conflict {
+        attributes: ["auto_component", "auto_relationship"],
+        same_plugin: false,
+        deprecated: false,
+        reason: "`auto_relationship` already derives the component",
+    },
+    Conflict {
+        attributes: ["auto_states", "auto_sub_states"],
+        same_plugin: false,
+        deprecated: false,
+        reason: "a state is either a top level state or a sub state",
+    },
+    Conflict {
+        attributes: ["auto_init_state", "auto_init_sub_state"],
+        same_plugin: true,
+        deprecated: false,
+        reason: "a state is either a top level state or a sub state",
+    },
+    Conflict {
+        attributes: ["auto_init_resource", "auto_insert_resource"],
+        same_plugin: true,
+        deprecated: true,
+        reason: "the inserted value already initializes the resource, remove `#[auto_init_resource]`",
+    },
+];
+
+fn attr_ident(path: &syn::Path) -> Option<String> {
+    path.segments.last().map(|segment| segment.ident.to_string())
+}
+
+/// Segments of a path without the turbofish, `crate::Test::<u8>` as `["Test<u8>"]`
+///
+/// Leading `crate`/`self` segments are dropped, so `Test` and `crate::Test` compare equal.
+fn path_segments(path: &syn::Path) -> Vec<String> {
+    path.segments
+        .iter()
+        .skip_while(|segment| segment.ident == "crate" || segment.ident == "self")
+        .map(|segment| {
+            let mut segment = segment.clone();
+            if let PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                args.colon2_token = None;
+            }
+            segment.to_token_stream().to_string()
+        })
+        .collect()
+}
+
+/// `plugin = ..` and `generics(..)` of the attribute args
+#[derive(Default)]
+struct PluginAndGenerics {
+    plugin: Option<Vec<String>>,
+    generics: Vec<String>,
+}
+
+impl PluginAndGenerics {
+    fn from_args(args: MacroStream) -> Option<Self> {
+        let list = Punctuated::<Meta, Token![,]>::parse_terminated.parse2(args).ok()?;
+        let mut this = Self::default();
+        for meta in list {
+            match meta {
+                Meta::NameValue(nv) if nv.path.is_ident("plugin") => {
+                    let syn::Expr::Path(plugin) = nv.value else {
+                        return None;
+                    };
+                    this.plugin = Some(path_segments(&plugin.path));
+                }
+                Meta::List(list) if list.path.is_ident("generics") => {
+                    let types = list
+                        .parse_args_with(Punctuated::<syn::Type, Token![,]>::parse_terminated)
+                        .ok()?;
+                    this.generics.push(quote::quote!(#types).to_string());
+                }
+                _ => {}
+            }
+        }
+        Some(this)
+    }
+
+    /// Paths can't be resolved in a macro, so a plugin path that ends the other one is the same
+    /// plugin, e.g. `Test` and `plugins::Test`
+    fn same_target(&self, other: &Self) -> bool {
+        let same_plugin = match (&self.plugin, &other.plugin) {
+            (Some(a), Some(b)) => a.ends_with(b) || b.ends_with(a),
+            (a, b) => a == b,
+        };
+        same_plugin && self.generics == other.generics
+    }
+}
+
+/// Deprecation warning at `span`, proc macros can't emit warnings of their own on stable
+fn deprecation_warning(span: proc_macro2::Span, ident: &str, note: &str) -> MacroStream {
+    let warning = format_ident!("{ident}", span = span);
+    quote_spanned! {span=>
+        const _: () = {
+            #[deprecated(note = #note)]
+            #[allow(non_upper_case_globals)]
+            const #warning: () = ();
+            #warning
+        };
+    }
+}
+
+/// Errors when one of the remaining attributes on the item conflicts with `ident`, deprecated
+/// combinations are returned as warnings to emit with the expansion instead
+///
+/// Attributes expand top to bottom, so the sibling attributes still on the item are the ones
+/// below the expanding attribute and every conflicting pair is reported once.
+pub fn check_sibling_conflicts(
+    ident: &str,
+    attr: &MacroStream,
+    input: &MacroStream,
+) -> syn::Result<MacroStream> {
+    let mut warnings = MacroStream::new();
+    // anything that isn't an item is reported by the attribute itself
+    let Ok(item) = syn::parse2::<syn::Item>(input.clone()) else {
+        return Ok(warnings);
+    };
+    let Some(attrs) = item.attrs() else {
+        return Ok(warnings);
+    };
+    let plugin_and_generics = PluginAndGenerics::from_args(attr.clone());
+    for sibling in attrs {
+        let Some(sibling_ident) = attr_ident(sibling.path()) else {
+            continue;
+        };
+        let Some(conflict) = CONFLICTS.iter().find(|conflict| {
+            conflict.attributes.contains(&ident)
+                && conflict.attributes.contains(&sibling_ident.as_str())
+                && ident != sibling_ident
+        }) else {
+            continue;
+        };
+        if conflict.same_plugin {
+            let sibling_args = match &sibling.meta {
+                Meta::List(list) => list.tokens.clone(),
+                _ => MacroStream::new(),
+            };
+            // args that don't parse are reported by their own attribute
+            let (Some(sibling), Some(this)) =
+                (PluginAndGenerics::from_args(sibling_args), &plugin_and_generics)
+            else {
+                continue;
+            };
+            if !sibling.same_target(this) {
+                continue;
+            }
+        }
+        if conflict.deprecated {
+            let note = format!(
+                "stacking `#[{sibling_ident}]` with `#[{ident}]` for the same plugin is deprecated: {}",
+                conflict.reason
+            );
+            warnings.extend(deprecation_warning(
+                sibling.span(),
+                &format!("{ident}_with_{sibling_ident}"),
+                &note,
+            ));
+            continue;
+        }
+        return Err(syn::Error::new(
+            sibling.span(),
+            format!(
+                "`#[{sibling_ident}]` conflicts with `#[{ident}]` on the same item: {} - keep only one of them",
+                conflict.reason
+            ),
+        ));
+    }
+    Ok(warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use quote::quote;
+
+    #[xtest]
+    fn test_conflicting_siblings() {
+        let err = check_sibling_conflicts(
+            "auto_component",
+            &quote!(plugin = Test),
+            &quote! {
+                #[auto_resource(plugin = Test)]
+                struct Foo;
+            },
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`#[auto_resource]` conflicts with `#[auto_component]` on the same item: a type is either a component or a resource - keep only one of them"
+        );
+    }
+
+    #[xtest]
+    fn test_conflict_requires_same_plugin() {
+        let input = quote! {
+            #[auto_init_sub_state(plugin = Other)]
+            struct Foo;
+        };
+        assert!(check_sibling_conflicts("auto_init_state", &quote!(plugin = Test), &input).is_ok());
+        assert!(
+            check_sibling_conflicts("auto_init_state", &quote!(plugin = Other), &input).is_err()
+        );
+        assert!(
+            check_sibling_conflicts("auto_init_state", &quote!(plugin = crate::Other), &input)
+                .is_err()
+        );
+        assert!(
+            check_sibling_conflicts("auto_init_state", &quote!(plugin = other::Other), &input)
+                .is_err()
+        );
+        assert!(
+            check_sibling_conflicts("auto_init_state", &quote!(plugin = Other::<u8>), &input)
+                .is_ok()
+        );
+    }
+
+    #[xtest]
+    fn test_same_plugin_paths() {
+        let args = |tokens| PluginAndGenerics::from_args(tokens).expect("expected args");
+        let test = args(quote!(plugin = Test, generics(u8)));
+        assert!(test.same_target(&args(quote!(plugin = crate::Test, generics(u8)))));
+        assert!(test.same_target(&args(quote!(plugin = plugins::Test, generics(u8)))));
+        assert!(!test.same_target(&args(quote!(plugin = Test, generics(u16)))));
+        assert!(!test.same_target(&args(quote!(plugin = Other, generics(u8)))));
+        assert!(
+            !args(quote!(plugin = a::Test)).same_target(&args(quote!(plugin = b::Test))),
+            "different modules are different plugins"
+        );
+        assert!(
+            args(quote!(plugin = Test::<u8>))
+                .same_target(&args(quote!(plugin = crate::Test::<u8>)))
+        );
+        assert!(
+            !args(quote!(plugin = Test::<u8>)).same_target(&args(quote!(plugin = Test::<u16>)))
+        );
+    }
+
+    #[xtest]
+    fn test_deprecated_conflict_warns() -> syn::Result<()> {
+        let input = quote! {
+            #[derive(Default)]
+            #[auto_insert_resource(plugin = crate::Test, insert(Foo))]
+            struct Foo;
+        };
+        let warnings =
+            check_sibling_conflicts("auto_init_resource", &quote!(plugin = Test), &input)?;
+        let warnings = warnings.to_string();
+        assert!(warnings.contains("deprecated"), "{warnings}");
+        assert!(warnings.contains("auto_init_resource_with_auto_insert_resource"), "{warnings}");
+        let warnings =
+            check_sibling_conflicts("auto_init_resource", &quote!(plugin = Other), &input)?;
+        assert!(warnings.is_empty(), "{warnings}");
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_no_conflict() {
+        let input = quote! {
+            #[auto_register_type(plugin = Test)]
+            #[auto_name(plugin = Test)]
+            struct Foo;
+        };
+        assert!(check_sibling_conflicts("auto_component", &quote!(plugin = Test), &input).is_ok());
+    }
+}
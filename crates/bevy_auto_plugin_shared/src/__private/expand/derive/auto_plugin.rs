@@ -1,4 +1,7 @@
-use crate::util::macros::parse_macro_input2;
+use crate::{
+    __private::expand::dump::dump,
+    util::macros::parse_macro_input2,
+};
 use proc_macro2::TokenStream as MacroStream;
 
 pub fn expand_derive_auto_plugin(input: MacroStream) -> MacroStream {
@@ -8,8 +11,12 @@ pub fn expand_derive_auto_plugin(input: MacroStream) -> MacroStream {
     };
     use darling::FromDeriveInput;
     use quote::quote;
-    use syn::DeriveInput;
+    use syn::{
+        DeriveInput,
+        spanned::Spanned,
+    };
 
+    let og_input = input.clone();
     let derive_input = parse_macro_input2!(input as DeriveInput);
     let params = {
         let mut params = match AutoPluginDeriveArgs::from_derive_input(&derive_input) {
@@ -24,11 +31,26 @@ pub fn expand_derive_auto_plugin(input: MacroStream) -> MacroStream {
     let generics = &params.generics; // `<T1, T2>`
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let defaults = &params.auto_plugin.defaults;
+    let auto_plugin_items = defaults.auto_plugin_items();
+    let requires = &params.auto_plugin.requires;
+    let require_plugins = requires_auto_plugin_items(requires);
     let mut output = quote! {
         impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPlugin
             for #ident #ty_generics #where_clause
-        {}
+        {
+            #auto_plugin_items
+            #require_plugins
+        }
     };
+    // registry statics can't be generic, generic plugins only check their requirements at build
+    if params.generics.params.is_empty() {
+        output.extend(requires_dependency_entries(ident, requires));
+    }
+    match defaults.component_reflect_impl(ident, generics) {
+        Ok(tokens) => output.extend(tokens),
+        Err(err) => return err.to_compile_error(),
+    }
 
     if params.auto_plugin.impl_plugin_trait.is_present() {
         output.extend(quote! {
@@ -43,6 +65,28 @@ pub fn expand_derive_auto_plugin(input: MacroStream) -> MacroStream {
         });
     }
 
+    let default_schedule = match (&params.auto_plugin.default_schedule, &defaults.system.schedule) {
+        (Some(_), Some(schedule)) => {
+            return syn::Error::new(
+                schedule.span(),
+                "`default_schedule` and `defaults(system(schedule = ...))` are mutually exclusive",
+            )
+            .to_compile_error();
+        }
+        (default_schedule, schedule) => default_schedule.as_ref().or(schedule.as_ref()),
+    };
+    if let Some(default_schedule) = default_schedule {
+        output.extend(quote! {
+            impl #impl_generics ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPluginDefaultSchedule
+                for #ident #ty_generics #where_clause
+            {
+                fn default_schedule() -> ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_ecs::schedule::InternedScheduleLabel {
+                    ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_ecs::schedule::ScheduleLabel::intern(&#default_schedule)
+                }
+            }
+        });
+    }
+
     #[cfg(feature = "default_plugin")]
     if params.auto_plugin.default_plugin.is_present() {
         if !params.generics.params.is_empty() {
@@ -62,7 +106,6 @@ pub fn expand_derive_auto_plugin(input: MacroStream) -> MacroStream {
         }
     }
 
-    quote! {
-        #output
-    }
+    dump("AutoPlugin", format_args!("AutoPlugin_{ident}"), &og_input, &params.auto_plugin, &output);
+    output
 }
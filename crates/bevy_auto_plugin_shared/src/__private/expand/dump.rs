@@ -0,0 +1,202 @@
+//! `BEVY_AUTO_PLUGIN_DUMP=<dir>` writes every expansion to `<dir>/<key>.rs`
+//!
+//! The env var is read while the macros run, so changing it needs a rebuild of the
+//! crates using the macros (e.g. `touch src/lib.rs` or `cargo clean -p my_crate`).
+
+use proc_macro2::{
+    Delimiter,
+    Spacing,
+    Span,
+    TokenStream as MacroStream,
+    TokenTree,
+};
+use std::{
+    fmt::{
+        Debug,
+        Display,
+    },
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::{
+        Command,
+        Stdio,
+    },
+};
+
+pub const DUMP_ENV_VAR: &str = "BEVY_AUTO_PLUGIN_DUMP";
+
+fn dump_dir() -> Option<PathBuf> {
+    std::env::var_os(DUMP_ENV_VAR).filter(|dir| !dir.is_empty()).map(PathBuf::from)
+}
+
+/// Writes `input`, `args` and `output` of an expansion to `<dump dir>/<key>.rs`
+///
+/// Does nothing unless [`DUMP_ENV_VAR`] is set. Failing to write is ignored so dumping can
+/// never break a build.
+pub fn dump(
+    expander: &str,
+    key: impl Display,
+    input: &MacroStream,
+    args: &dyn Debug,
+    output: &MacroStream,
+) {
+    let Some(dir) = dump_dir() else {
+        return;
+    };
+    // the key alone repeats for same-named items in different modules
+    let key = format!("{key}_{}", location_key(input));
+    let _ = write_dump(&dir, &key, &render(expander, &key, input, args, output));
+}
+
+/// Byte range of the first input token, e.g. `bytes(120..126)` as `bytes_120_126`
+///
+/// Offsets are unique across the files of a crate, and unlike line numbers they don't need
+/// proc-macro2's `span-locations`.
+fn location_key(input: &MacroStream) -> String {
+    let span = input.clone().into_iter().next().map_or_else(Span::call_site, |tt| tt.span());
+    // compiler spans are printed as `#<context> bytes(..)`
+    let debug = format!("{span:?}");
+    let range = debug.find("bytes").map_or(debug.as_str(), |ix| &debug[ix..]);
+    range
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn write_dump(dir: &Path, key: &str, contents: &str) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{key}.rs"));
+    fs::write(&path, contents)?;
+    // best effort, the dump is readable without it
+    let _ = Command::new("rustfmt")
+        .args(["--edition", "2024"])
+        .arg(&path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    Ok(())
+}
+
+fn render(
+    expander: &str,
+    key: &str,
+    input: &MacroStream,
+    args: &dyn Debug,
+    output: &MacroStream,
+) -> String {
+    // keep the args inside the block comment
+    let args = format!("{args:#?}").replace("*/", "* /");
+    format!(
+        "// `{expander}` expansion of `{key}`\n\n/* args:\n{args}\n*/\n\n// input\n{}\n\n// output\n{}\n",
+        pretty_tokens(input),
+        pretty_tokens(output),
+    )
+}
+
+/// Breaks the tokens into lines after `;` and around braces
+pub fn pretty_tokens(tokens: &MacroStream) -> String {
+    let mut out = String::new();
+    write_tokens(&mut out, tokens.clone(), 0);
+    out.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn write_tokens(out: &mut String, tokens: MacroStream, depth: usize) {
+    let indent = |out: &mut String, depth: usize| {
+        out.push('\n');
+        out.push_str(&"    ".repeat(depth));
+    };
+    let mut joint = false;
+    for tt in tokens {
+        if !joint && !out.is_empty() && !out.ends_with([' ', '\n', '(', '[']) {
+            out.push(' ');
+        }
+        joint = false;
+        match tt {
+            TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+                out.push('{');
+                indent(out, depth + 1);
+                write_tokens(out, group.stream(), depth + 1);
+                indent(out, depth);
+                out.push('}');
+                indent(out, depth);
+            }
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    _ => ("", ""),
+                };
+                out.push_str(open);
+                write_tokens(out, group.stream(), depth);
+                out.push_str(close);
+            }
+            TokenTree::Punct(punct) => {
+                out.push(punct.as_char());
+                joint = punct.spacing() == Spacing::Joint;
+                if punct.as_char() == ';' {
+                    indent(out, depth);
+                }
+            }
+            tt => out.push_str(&tt.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use quote::quote;
+
+    #[xtest]
+    fn test_pretty_tokens() {
+        let tokens = quote! {
+            fn build(app: &mut App) {
+                app.register_type::<Foo>();
+                app.init_resource::<Bar>();
+            }
+        };
+        assert_eq!(
+            pretty_tokens(&tokens),
+            [
+                "fn build (app : & mut App) {",
+                "    app . register_type :: < Foo > () ;",
+                "    app . init_resource :: < Bar > () ;",
+                "}",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[xtest]
+    fn test_location_key_tells_same_named_items_apart() {
+        let first = "struct Plugin;".parse::<MacroStream>().expect("expected tokens");
+        let second = "struct Plugin;".parse::<MacroStream>().expect("expected tokens");
+        assert_ne!(location_key(&first), location_key(&second));
+        assert!(location_key(&first).chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+    }
+
+    #[xtest]
+    fn test_render_keeps_args_in_comment() {
+        let rendered = render(
+            "auto_name",
+            "key",
+            &quote!(
+                struct Foo;
+            ),
+            &"*/",
+            &quote!(),
+        );
+        assert!(rendered.contains("\"* /\""), "{rendered}");
+        assert!(rendered.starts_with("// `auto_name` expansion of `key`"), "{rendered}");
+    }
+}
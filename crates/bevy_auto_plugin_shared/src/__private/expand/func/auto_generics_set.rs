@@ -0,0 +1,129 @@
+use crate::{
+    __private::expand::dump::dump,
+    syntax::{
+        ast::type_list::parse_generic_arg,
+        parse::generics_set::GENERICS_SET_META_IDENT,
+    },
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    format_ident,
+    quote,
+};
+use syn::{
+    GenericArgument,
+    Ident,
+    Token,
+    Visibility,
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    parse2,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// `[vis] NAME = [A, B, ...]`
+struct GenericsSetDecl {
+    vis: Visibility,
+    ident: Ident,
+    args: Punctuated<GenericArgument, Token![,]>,
+}
+
+impl Parse for GenericsSetDecl {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let ident = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let content;
+        syn::bracketed!(content in input);
+        let args = Punctuated::parse_terminated_with(&content, parse_generic_arg)?;
+        if !input.is_empty() {
+            return Err(input.error("expected a single `NAME = [..]` declaration"));
+        }
+        Ok(Self { vis, ident, args })
+    }
+}
+
+pub fn auto_generics_set_inner(input: MacroStream) -> syn::Result<MacroStream> {
+    let GenericsSetDecl { vis, ident, args } = parse2::<GenericsSetDecl>(input.clone())?;
+    if let Visibility::Public(_) = vis {
+        return Err(syn::Error::new(
+            vis.span(),
+            "generics sets can't be `pub`, use `pub(crate)` to share them within the crate",
+        ));
+    }
+    if args.is_empty() {
+        return Err(syn::Error::new(ident.span(), "generics sets must not be empty"));
+    }
+    let marker = format_ident!("{GENERICS_SET_META_IDENT}");
+    let macro_ident = format_ident!("__auto_generics_set_{ident}");
+    let set_args = args.iter();
+    let output = quote! {
+        #[doc(hidden)]
+        macro_rules! #macro_ident {
+            ([$($key:tt)*] [$($attr_path:tt)*] [$($args:tt)*] $($item:tt)*) => {
+                #[$($attr_path)*(#marker($($key)* = [#(#set_args),*]), $($args)*)]
+                $($item)*
+            };
+        }
+        #[allow(unused_imports)]
+        #vis use #macro_ident as #ident;
+    };
+    dump("auto_generics_set", format_args!("auto_generics_set_{ident}"), &input, &args, &output);
+    Ok(output)
+}
+
+pub fn auto_generics_set_outer(input: MacroStream) -> MacroStream {
+    auto_generics_set_inner(input).unwrap_or_else(|err| err.to_compile_error())
+}
+
+/// Hands `attr_path(attr)` and the item to the macro generated by `auto_generics_set!`,
+/// which re-applies the attribute with the set's types injected
+pub fn generics_set_invocation(
+    set: &syn::Path,
+    attr_path: impl ToTokens,
+    attr: &MacroStream,
+    input: &MacroStream,
+) -> MacroStream {
+    quote! {
+        #set! { [#set] [#attr_path] [#attr] #input }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_auto_generics_set_expansion() -> syn::Result<()> {
+        let output = auto_generics_set_inner(quote!(pub(crate) STATS = [Hp, Mp]))?;
+        let output = output.to_string();
+        assert!(
+            output.contains(&quote!(__generics_set($($key)* = [Hp, Mp])).to_string()),
+            "{output}"
+        );
+        assert!(
+            output.contains(
+                &quote!(
+                    pub(crate) use __auto_generics_set_STATS as STATS;
+                )
+                .to_string()
+            ),
+            "{output}"
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_auto_generics_set_rejects_pub() {
+        let err = auto_generics_set_inner(quote!(pub STATS = [Hp])).expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "generics sets can't be `pub`, use `pub(crate)` to share them within the crate"
+        );
+    }
+}
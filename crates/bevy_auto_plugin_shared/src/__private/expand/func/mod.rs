@@ -0,0 +1 @@
+pub mod auto_generics_set;
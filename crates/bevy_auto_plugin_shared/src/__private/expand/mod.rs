@@ -1,2 +1,4 @@
 pub mod attr;
 pub mod derive;
+pub mod dump;
+pub mod func;
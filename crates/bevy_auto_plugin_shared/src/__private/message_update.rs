@@ -0,0 +1,101 @@
+//! Runtime support for `update_in = ..` / `manual_update` on the message attributes
+//!
+//! The messages are registered in a [`MessageRegistry`] of their own instead of the world's, so
+//! they get Bevy's update bookkeeping without being updated in `First`.
+
+use bevy_app::App;
+use bevy_ecs::{
+    change_detection::{
+        Mut,
+        Tick,
+    },
+    message::{
+        Message,
+        MessageRegistry,
+        Messages,
+    },
+    resource::Resource,
+    schedule::{
+        InternedScheduleLabel,
+        ScheduleLabel,
+    },
+    system::Local,
+    world::World,
+};
+use std::collections::HashMap;
+
+/// Registries for the messages that aren't updated by the world's [`MessageRegistry`]
+#[derive(Resource, Default)]
+struct MessageUpdateRegistries {
+    scheduled: HashMap<InternedScheduleLabel, MessageRegistry>,
+    manual: MessageRegistry,
+}
+
+/// Registers `T` in `registry` by swapping it in for the world's [`MessageRegistry`]
+fn register_message_in<T: Message>(
+    world: &mut World,
+    registry: impl Fn(&mut MessageUpdateRegistries) -> &mut MessageRegistry,
+) {
+    let world_registry = world.remove_resource::<MessageRegistry>();
+    let mut registries = world.get_resource_or_init::<MessageUpdateRegistries>();
+    let target = core::mem::take(registry(&mut registries));
+    world.insert_resource(target);
+    MessageRegistry::register_message::<T>(world);
+    let target = world.remove_resource::<MessageRegistry>().expect("just registered");
+    *registry(&mut world.resource_mut::<MessageUpdateRegistries>()) = target;
+    if let Some(world_registry) = world_registry {
+        world.insert_resource(world_registry);
+    }
+}
+
+/// `app.add_message::<T>()`, updating `Messages<T>` in `schedule` instead of `First`
+pub fn add_message_updated_in<T: Message>(app: &mut App, schedule: impl ScheduleLabel) {
+    // same as `add_message`, an existing `Messages<T>` is already registered
+    if app.world().contains_resource::<Messages<T>>() {
+        return;
+    }
+    let schedule = schedule.intern();
+    let new_schedule = !app
+        .world()
+        .get_resource::<MessageUpdateRegistries>()
+        .is_some_and(|registries| registries.scheduled.contains_key(&schedule));
+    register_message_in::<T>(app.world_mut(), |registries| {
+        registries.scheduled.entry(schedule).or_default()
+    });
+    if new_schedule {
+        app.add_systems(schedule, move |world: &mut World, mut last_change_tick: Local<Tick>| {
+            run_updates(world, &mut last_change_tick, |registries| {
+                registries.scheduled.get_mut(&schedule)
+            });
+        });
+    }
+}
+
+/// `app.add_message::<T>()`, updating `Messages<T>` only when [`update_manual_messages`] runs
+pub fn add_manual_message<T: Message>(app: &mut App) {
+    if app.world().contains_resource::<Messages<T>>() {
+        return;
+    }
+    register_message_in::<T>(app.world_mut(), |registries| &mut registries.manual);
+}
+
+fn run_updates(
+    world: &mut World,
+    last_change_tick: &mut Tick,
+    registry: impl FnOnce(&mut MessageUpdateRegistries) -> Option<&mut MessageRegistry>,
+) {
+    world.try_resource_scope(|world, mut registries: Mut<MessageUpdateRegistries>| {
+        if let Some(registry) = registry(&mut registries) {
+            registry.run_updates(world, *last_change_tick);
+        }
+    });
+    *last_change_tick = world.change_tick();
+}
+
+/// Updates every message registered with `manual_update`
+///
+/// Add it to a schedule, or run it with `world.run_system_cached(update_manual_messages)`.
+/// Like Bevy's own message update, a message is dropped after it has been through two updates.
+pub fn update_manual_messages(world: &mut World, mut last_change_tick: Local<Tick>) {
+    run_updates(world, &mut last_change_tick, |registries| Some(&mut registries.manual));
+}
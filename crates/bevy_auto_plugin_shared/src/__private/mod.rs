@@ -1,5 +1,99 @@
 pub mod auto_plugin_registry;
 pub mod expand;
+pub mod message_update;
+pub mod observer;
+pub mod plugin_dependencies;
+pub mod reflect;
+#[cfg(feature = "resource_config")]
+pub mod resource_config;
+
+/// used by generated code
+pub use log;
+
+/// `auto_resource(load = "..", env_prefix = "..")` support, or a compile error naming the missing
+/// feature. The features are checked here since the proc macros don't see them.
+#[cfg(feature = "resource_config")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_load {
+    ($file:expr, $env_prefix:expr) => {
+        ::bevy_auto_plugin::__private::shared::__private::resource_config::load_resource(
+            $file,
+            $env_prefix,
+        )
+    };
+}
+
+#[cfg(not(feature = "resource_config"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_load {
+    ($file:expr, $env_prefix:expr) => {
+        ::core::compile_error!(
+            "`load` and `env_prefix` need the `resource_config` feature of `bevy_auto_plugin`"
+        )
+    };
+}
+
+#[cfg(feature = "resource_config")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_json {
+    () => {
+        ::bevy_auto_plugin::__private::shared::__private::resource_config::parse_json
+    };
+}
+
+#[cfg(not(feature = "resource_config"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_json {
+    () => {
+        ::core::compile_error!(
+            "`load = \"*.json\"` needs the `resource_config` feature of `bevy_auto_plugin`"
+        )
+    };
+}
+
+#[cfg(feature = "resource_config_toml")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_toml {
+    () => {
+        ::bevy_auto_plugin::__private::shared::__private::resource_config::parse_toml
+    };
+}
+
+#[cfg(not(feature = "resource_config_toml"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_toml {
+    () => {
+        ::core::compile_error!(
+            "`load = \"*.toml\"` needs the `resource_config_toml` feature of `bevy_auto_plugin`"
+        )
+    };
+}
+
+#[cfg(feature = "resource_config_ron")]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_ron {
+    () => {
+        ::bevy_auto_plugin::__private::shared::__private::resource_config::parse_ron
+    };
+}
+
+#[cfg(not(feature = "resource_config_ron"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _resource_config_parse_ron {
+    () => {
+        ::core::compile_error!(
+            "`load = \"*.ron\"` needs the `resource_config_ron` feature of `bevy_auto_plugin`"
+        )
+    };
+}
 
 macro_rules! bevy_crate_err_message {
     ($ident:ident) => {
@@ -20,6 +114,39 @@ pub(crate) mod paths {
     use proc_macro2::TokenStream;
     use quote::quote;
 
+    /// Panics in a macro, the tools report the error once they're done and use `fallback` until then
+    #[cfg_attr(not(feature = "tooling"), allow(unused_variables))]
+    fn expect_resolved(
+        resolved: Result<syn::Path, String>,
+        message: &str,
+        fallback: impl FnOnce() -> syn::Path,
+    ) -> syn::Path {
+        match resolved {
+            Ok(path) => path,
+            #[cfg(feature = "tooling")]
+            Err(err)
+                if crate::tooling::manifest::record_unresolved(format!("{message}: {err}")) =>
+            {
+                fallback()
+            }
+            Err(err) => panic!("{message}: {err:?}"),
+        }
+    }
+
+    #[cfg(feature = "tooling")]
+    pub mod app {
+        use super::*;
+
+        pub fn resolve() -> Result<syn::Path, String> {
+            bevy_crate_path!(app)
+        }
+        pub fn app_root_path() -> syn::Path {
+            expect_resolved(resolve(), bevy_crate_err_message!(app), || {
+                syn::parse_quote!(::bevy::app)
+            })
+        }
+    }
+
     pub mod ecs {
         use super::*;
 
@@ -27,7 +154,9 @@ pub(crate) mod paths {
             bevy_crate_path!(ecs)
         }
         pub fn ecs_root_path() -> syn::Path {
-            resolve().expect(bevy_crate_err_message!(ecs))
+            expect_resolved(resolve(), bevy_crate_err_message!(ecs), || {
+                syn::parse_quote!(::bevy::ecs)
+            })
         }
     }
 
@@ -39,7 +168,9 @@ pub(crate) mod paths {
         }
 
         pub fn reflect_root_path() -> syn::Path {
-            resolve().expect(bevy_crate_err_message!(reflect))
+            expect_resolved(resolve(), bevy_crate_err_message!(reflect), || {
+                syn::parse_quote!(::bevy::reflect)
+            })
         }
 
         pub fn reflect_default_use_tokens() -> TokenStream {
@@ -58,6 +189,14 @@ pub(crate) mod paths {
             }
         }
 
+        pub fn reflect_event_use_tokens() -> TokenStream {
+            let ecs_root = ecs::ecs_root_path();
+            quote! {
+                #[allow(unused_imports)]
+                use #ecs_root::reflect::ReflectEvent as _;
+            }
+        }
+
         pub fn reflect_resource_use_tokens() -> TokenStream {
             let ecs_root = ecs::ecs_root_path();
             quote! {
@@ -75,7 +214,9 @@ pub(crate) mod paths {
         }
 
         pub fn root_path() -> syn::Path {
-            resolve().expect(bevy_crate_err_message!(state))
+            expect_resolved(resolve(), bevy_crate_err_message!(state), || {
+                syn::parse_quote!(::bevy::state)
+            })
         }
 
         // breaks parse quote
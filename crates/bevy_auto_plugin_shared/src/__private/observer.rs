@@ -0,0 +1,58 @@
+//! Runtime support for `auto_observer(run_if = ..)` / `auto_add_observer(run_if = ..)`
+
+use bevy_ecs::{
+    bundle::Bundle,
+    event::Event,
+    schedule::SystemCondition,
+    system::{
+        CombinatorSystem,
+        Combine,
+        IntoObserverSystem,
+        IntoSystem,
+        ObserverSystem,
+        RunSystemError,
+        System,
+        SystemIn,
+        SystemInput,
+        SystemParamValidationError,
+    },
+};
+
+/// Runs the observer (`B`) only when the condition (`A`) returns `true`
+///
+/// A `false` condition is reported as a skipped run, which observers ignore.
+pub struct ObserverRunIf;
+
+impl<A, B> Combine<A, B> for ObserverRunIf
+where
+    A: System<In = (), Out = bool>,
+    B: System<Out = ()>,
+{
+    type In = B::In;
+    type Out = ();
+
+    fn combine<T>(
+        input: <Self::In as SystemInput>::Inner<'_>,
+        data: &mut T,
+        a: impl FnOnce(SystemIn<'_, A>, &mut T) -> Result<bool, RunSystemError>,
+        b: impl FnOnce(SystemIn<'_, B>, &mut T) -> Result<(), RunSystemError>,
+    ) -> Result<(), RunSystemError> {
+        if a((), data)? {
+            b(input, data)
+        } else {
+            Err(RunSystemError::Skipped(SystemParamValidationError::skipped::<Self>(
+                "run condition returned false",
+            )))
+        }
+    }
+}
+
+pub fn observer_run_if<E: Event, B: Bundle, M, CM>(
+    observer: impl IntoObserverSystem<E, B, M>,
+    condition: impl SystemCondition<CM>,
+) -> impl ObserverSystem<E, B> {
+    let condition = IntoSystem::into_system(condition);
+    let observer = IntoObserverSystem::into_system(observer);
+    let name = observer.name();
+    CombinatorSystem::<ObserverRunIf, _, _>::new(condition, observer, name)
+}
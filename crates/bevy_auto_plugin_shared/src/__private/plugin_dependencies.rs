@@ -0,0 +1,261 @@
+//! Plugin dependency graph built from `auto_add_plugin` and `#[auto_plugin(requires(...))]`
+//!
+//! Bevy recurses forever on a plugin that adds itself through other plugins and panics on a
+//! duplicate unique plugin without saying who added it. Every edge is registered at compile time
+//! with its source location, so both are reported with the attributes that caused them.
+
+use crate::__private::auto_plugin_registry::{
+    RegistryOrder,
+    TypeIdFn,
+};
+use bevy_app::{
+    App,
+    Plugin,
+};
+use std::{
+    any::{
+        TypeId,
+        type_name,
+    },
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt::Write,
+    sync::LazyLock,
+};
+
+#[cfg(all(not(target_arch = "wasm32"), not(feature = "inventory")))]
+#[linkme::distributed_slice]
+pub static AUTO_PLUGIN_DEPENDENCIES: [AutoPluginDependency];
+
+#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+inventory::collect!(AutoPluginDependency);
+
+pub type TypeNameFn = fn() -> &'static str;
+
+/// How a plugin depends on another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyKind {
+    /// `auto_add_plugin`
+    Adds,
+    /// `requires(..)`, adds the plugin if it's missing
+    Requires,
+    /// `requires(.., mode = assert)`, the plugin has to be added first
+    Asserts,
+}
+
+impl DependencyKind {
+    fn verb(self) -> &'static str {
+        match self {
+            DependencyKind::Adds => "adds",
+            DependencyKind::Requires => "requires",
+            DependencyKind::Asserts => "requires (asserted)",
+        }
+    }
+}
+
+/// Edge `plugin -> dependency`, registered next to the attribute that declares it
+pub struct AutoPluginDependency {
+    plugin: TypeIdFn,
+    plugin_name: TypeNameFn,
+    dependency: TypeIdFn,
+    dependency_name: TypeNameFn,
+    kind: DependencyKind,
+    order: RegistryOrder,
+}
+
+impl AutoPluginDependency {
+    pub const fn new(
+        plugin: TypeIdFn,
+        plugin_name: TypeNameFn,
+        dependency: TypeIdFn,
+        dependency_name: TypeNameFn,
+        kind: DependencyKind,
+        order: RegistryOrder,
+    ) -> Self {
+        Self { plugin, plugin_name, dependency, dependency_name, kind, order }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Edge {
+    plugin_name: &'static str,
+    dependency: TypeId,
+    dependency_name: &'static str,
+    kind: DependencyKind,
+    order: RegistryOrder,
+}
+
+impl Edge {
+    fn describe(&self) -> String {
+        format!(
+            "`{}` {} `{}` at {}",
+            self.plugin_name,
+            self.kind.verb(),
+            self.dependency_name,
+            self.order
+        )
+    }
+}
+
+struct DependencyGraph {
+    edges: HashMap<TypeId, Vec<Edge>>,
+}
+
+static AUTO_PLUGIN_DEPENDENCY_GRAPH: LazyLock<DependencyGraph> = LazyLock::new(|| {
+    #[cfg(target_arch = "wasm32")]
+    crate::_initialize();
+
+    #[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+    let iter = AUTO_PLUGIN_DEPENDENCIES.into_iter();
+    #[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+    let iter = ::inventory::iter::<AutoPluginDependency>.into_iter();
+
+    let mut edges: HashMap<TypeId, Vec<Edge>> = HashMap::new();
+    for dependency in iter {
+        edges.entry((dependency.plugin)()).or_default().push(Edge {
+            plugin_name: (dependency.plugin_name)(),
+            dependency: (dependency.dependency)(),
+            dependency_name: (dependency.dependency_name)(),
+            kind: dependency.kind,
+            order: dependency.order,
+        });
+    }
+    edges.values_mut().for_each(|edges| edges.sort_by_key(|edge| edge.order));
+    DependencyGraph { edges }
+});
+
+impl DependencyGraph {
+    fn edges(&self, plugin: TypeId) -> &[Edge] {
+        self.edges.get(&plugin).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Edges of the first cycle reachable from `plugin`
+    fn find_cycle(&self, plugin: TypeId) -> Option<Vec<Edge>> {
+        struct Search<'a> {
+            graph: &'a DependencyGraph,
+            /// plugins being visited, `path[i]` leads from `stack[i]` to `stack[i + 1]`
+            stack: Vec<TypeId>,
+            path: Vec<Edge>,
+            done: HashSet<TypeId>,
+        }
+        impl Search<'_> {
+            fn visit(&mut self, plugin: TypeId) -> Option<Vec<Edge>> {
+                self.stack.push(plugin);
+                for edge in self.graph.edges(plugin) {
+                    if let Some(start) = self.stack.iter().position(|id| *id == edge.dependency) {
+                        let mut cycle = self.path[start..].to_vec();
+                        cycle.push(*edge);
+                        return Some(cycle);
+                    }
+                    if self.done.contains(&edge.dependency) {
+                        continue;
+                    }
+                    self.path.push(*edge);
+                    if let Some(cycle) = self.visit(edge.dependency) {
+                        return Some(cycle);
+                    }
+                    self.path.pop();
+                }
+                self.stack.pop();
+                self.done.insert(plugin);
+                None
+            }
+        }
+        Search { graph: self, stack: vec![], path: vec![], done: HashSet::new() }.visit(plugin)
+    }
+
+    /// Every edge that adds `dependency` to the app
+    fn adders(&self, dependency: TypeId) -> Vec<Edge> {
+        let mut adders = self
+            .edges
+            .values()
+            .flatten()
+            .filter(|edge| edge.dependency == dependency && edge.kind != DependencyKind::Asserts)
+            .copied()
+            .collect::<Vec<_>>();
+        adders.sort_by_key(|edge| edge.order);
+        adders
+    }
+}
+
+/// Panics if building `plugin` would build it again through its dependencies
+pub fn check_dependency_cycles(plugin: TypeId, plugin_name: &str) {
+    if let Some(cycle) = AUTO_PLUGIN_DEPENDENCY_GRAPH.find_cycle(plugin) {
+        let mut message = format!("plugin dependency cycle while building `{plugin_name}`:");
+        for edge in cycle {
+            let _ = write!(message, "\n    {}", edge.describe());
+        }
+        panic!("{message}");
+    }
+}
+
+/// `auto_add_plugin`: adds `plugin`, naming every other attribute adding it on a duplicate
+pub fn add_plugin<By: 'static, P: Plugin>(app: &mut App, plugin: P, order: RegistryOrder) {
+    if plugin.is_unique() && app.is_plugin_added::<P>() {
+        let mut message = format!(
+            "`{}` adds `{}` at {order}, but it was already added",
+            type_name::<By>(),
+            type_name::<P>()
+        );
+        let others = AUTO_PLUGIN_DEPENDENCY_GRAPH
+            .adders(TypeId::of::<P>())
+            .into_iter()
+            .filter(|edge| edge.order != order)
+            .collect::<Vec<_>>();
+        if !others.is_empty() {
+            message.push_str(", it's also added by:");
+            for edge in others {
+                let _ = write!(message, "\n    {}", edge.describe());
+            }
+        }
+        panic!("{message}");
+    }
+    app.add_plugins(plugin);
+}
+
+/// `requires(P)`: adds `P::default()` unless the app already has it
+pub fn require_plugin<P: Plugin + Default>(app: &mut App) {
+    if !app.is_plugin_added::<P>() {
+        app.add_plugins(P::default());
+    }
+}
+
+/// `requires(P, mode = assert)`: `P` has to be added before the plugin requiring it
+pub fn assert_plugin<By: 'static, P: Plugin>(app: &App, order: RegistryOrder) {
+    assert!(
+        app.is_plugin_added::<P>(),
+        "`{}` requires `{}` (declared at {order}), add it to the app before `{}`",
+        type_name::<By>(),
+        type_name::<P>(),
+        type_name::<By>(),
+    );
+}
+
+#[cfg(not(any(target_arch = "wasm32", feature = "inventory")))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _plugin_dependency {
+    ($static_ident:ident, $dependency:expr) => {
+        #[::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::linkme::distributed_slice(
+            ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies::AUTO_PLUGIN_DEPENDENCIES
+        )]
+        #[linkme(crate = ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::linkme)]
+        #[allow(non_upper_case_globals)]
+        static $static_ident:
+            ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies::AutoPluginDependency =
+            $dependency;
+    };
+}
+
+#[cfg(any(target_arch = "wasm32", feature = "inventory"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! _plugin_dependency {
+    ($static_ident:ident, $dependency:expr) => {
+        ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::inventory::submit!(
+            $dependency
+        );
+    };
+}
@@ -0,0 +1,16 @@
+//! Compile-time check behind `auto_register_type` for targets whose derives it can't see
+
+use bevy_reflect::GetTypeRegistration;
+
+/// Implemented for every type the type registry accepts, only here for its diagnostic
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is registered with the type registry but doesn't implement `Reflect`",
+    label = "registered here",
+    note = "add `#[derive(Reflect)]` to `{Self}`, or `derive, reflect` to its `auto_*` attribute"
+)]
+pub trait RegisteredTypeImplementsReflect: GetTypeRegistration {}
+
+impl<T: GetTypeRegistration> RegisteredTypeImplementsReflect for T {}
+
+/// Fails to compile for a `T` without a `Reflect` impl
+pub fn assert_reflect<T: RegisteredTypeImplementsReflect>() {}
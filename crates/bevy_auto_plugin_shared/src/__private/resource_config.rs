@@ -0,0 +1,245 @@
+//! Runtime support for `auto_resource(load = "..", env_prefix = "..")`
+use serde::de::DeserializeOwned;
+use serde_json::{
+    Map,
+    Value,
+};
+
+/// Parses the contents of a config file into a format agnostic value
+pub type ParseFn = fn(&str) -> Result<Value, String>;
+
+/// separator for nested fields in env var names: `NET_TLS__CERT` => `tls.cert`
+const ENV_NESTED_SEPARATOR: &str = "__";
+
+pub fn parse_json(contents: &str) -> Result<Value, String> {
+    serde_json::from_str(contents).map_err(|err| err.to_string())
+}
+
+#[cfg(feature = "resource_config_toml")]
+pub fn parse_toml(contents: &str) -> Result<Value, String> {
+    toml::from_str(contents).map_err(|err| err.to_string())
+}
+
+#[cfg(feature = "resource_config_ron")]
+pub fn parse_ron(contents: &str) -> Result<Value, String> {
+    ron::from_str(contents).map_err(|err| err.to_string())
+}
+
+/// Loads `T` from the config file, applies `env_prefix` overrides, and falls back to `Default`
+pub fn load_resource<T: DeserializeOwned + Default>(
+    file: Option<(&str, ParseFn)>,
+    env_prefix: Option<&str>,
+) -> T {
+    let file = file.map(|(path, parse)| {
+        let contents = std::fs::read_to_string(path).map_err(|err| err.to_string());
+        (path, contents.and_then(|contents| parse(&contents)))
+    });
+    load_resource_from(file, env_prefix.map(|prefix| (prefix, std::env::vars())))
+}
+
+fn load_resource_from<T: DeserializeOwned + Default>(
+    file: Option<(&str, Result<Value, String>)>,
+    env: Option<(&str, impl IntoIterator<Item = (String, String)>)>,
+) -> T {
+    let type_name = core::any::type_name::<T>();
+    let mut value = match file {
+        Some((_, Ok(value))) => value,
+        Some((path, Err(err))) => {
+            log::warn!(
+                "failed to load `{type_name}` from `{path}`, falling back to `Default`: {err}"
+            );
+            if env.is_none() {
+                return T::default();
+            }
+            Value::Object(Map::new())
+        }
+        None => Value::Object(Map::new()),
+    };
+    let file_value = value.clone();
+    if let Some((prefix, vars)) = env {
+        for (key, raw) in vars {
+            let Some(field) = key.strip_prefix(prefix) else {
+                continue;
+            };
+            let path = field.to_lowercase();
+            let path = path.split(ENV_NESTED_SEPARATOR).collect::<Vec<_>>();
+            apply_env_var::<T>(&mut value, &key, &path, raw);
+        }
+    }
+    match serde_json::from_value(value) {
+        Ok(resource) => resource,
+        Err(err) => {
+            log::warn!(
+                "failed to deserialize `{type_name}` with env overrides, ignoring them: {err}"
+            );
+            match serde_json::from_value(file_value) {
+                Ok(resource) => resource,
+                Err(err) => {
+                    log::warn!(
+                        "failed to deserialize `{type_name}`, falling back to `Default`: {err}"
+                    );
+                    T::default()
+                }
+            }
+        }
+    }
+}
+
+/// Sets the field from an env var, as its typed value (`8080`, `true`) if the field takes it, else
+/// as the raw string
+///
+/// A variable that fits neither is skipped with a warning, unless the value is still incomplete
+/// without it (e.g. a required field set by another variable).
+fn apply_env_var<T: DeserializeOwned>(value: &mut Value, key: &str, path: &[&str], raw: String) {
+    let typed = serde_json::from_str::<Value>(&raw);
+    let string = Value::String(raw);
+    let candidates = match typed {
+        Ok(typed) if typed != string => vec![typed, string],
+        _ => vec![string],
+    };
+    let mut first_err = None;
+    for candidate in &candidates {
+        let mut next = value.clone();
+        set_path(&mut next, path, candidate.clone());
+        match serde_json::from_value::<T>(next.clone()) {
+            Ok(_) => {
+                *value = next;
+                return;
+            }
+            Err(err) => {
+                first_err.get_or_insert(err);
+            }
+        }
+    }
+    if serde_json::from_value::<T>(value.clone()).is_ok() {
+        let type_name = core::any::type_name::<T>();
+        let err = first_err.map(|err| err.to_string()).unwrap_or_default();
+        log::warn!("ignoring env var `{key}` for `{type_name}`: {err}");
+    } else {
+        set_path(value, path, candidates[0].clone());
+    }
+}
+
+fn set_path(value: &mut Value, path: &[&str], field_value: Value) {
+    let Some((field, rest)) = path.split_first() else {
+        *value = field_value;
+        return;
+    };
+    if !value.is_object() {
+        *value = Value::Object(Map::new());
+    }
+    let Value::Object(map) = value else {
+        unreachable!();
+    };
+    let entry = map.entry(*field).or_insert(Value::Null);
+    set_path(entry, rest, field_value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use serde::Deserialize;
+
+    #[derive(Deserialize, Debug, Default, PartialEq)]
+    #[serde(default)]
+    struct Tls {
+        cert: String,
+    }
+
+    #[derive(Deserialize, Debug, Default, PartialEq)]
+    #[serde(default)]
+    struct Net {
+        host: String,
+        port: u16,
+        tls: Tls,
+    }
+
+    #[derive(Deserialize, Debug, Default, PartialEq)]
+    #[serde(default)]
+    struct Labels {
+        name: String,
+        version: String,
+        enabled: bool,
+        retries: u8,
+    }
+
+    fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+        vars.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect()
+    }
+
+    #[xtest]
+    fn test_load_file_with_env_overrides() {
+        let file = parse_json(r#"{ "host": "localhost", "port": 80 }"#);
+        let net: Net = load_resource_from(
+            Some(("net.json", file)),
+            Some((
+                "NET_",
+                vars(&[
+                    ("NET_PORT", "8080"),
+                    ("NET_TLS__CERT", "cert.pem"),
+                    ("OTHER", "1"),
+                ]),
+            )),
+        );
+        assert_eq!(
+            net,
+            Net {
+                host: "localhost".to_string(),
+                port: 8080,
+                tls: Tls { cert: "cert.pem".to_string() }
+            }
+        );
+    }
+
+    #[xtest]
+    fn test_load_missing_file_falls_back_to_default() {
+        let net: Net = load_resource_from(
+            Some(("missing.json", Err("not found".to_string()))),
+            None::<(&str, Vec<(String, String)>)>,
+        );
+        assert_eq!(net, Net::default());
+    }
+
+    #[xtest]
+    fn test_load_invalid_value_falls_back_to_default() {
+        let net: Net =
+            load_resource_from(None, Some(("NET_", vars(&[("NET_PORT", "not a port")]))));
+        assert_eq!(net, Net::default());
+    }
+
+    #[xtest]
+    fn test_env_strings_that_look_typed() {
+        let labels: Labels = load_resource_from(
+            None,
+            Some((
+                "APP_",
+                vars(&[
+                    ("APP_NAME", "8080"),
+                    ("APP_VERSION", "true"),
+                    ("APP_ENABLED", "true"),
+                    ("APP_RETRIES", "3"),
+                ]),
+            )),
+        );
+        assert_eq!(
+            labels,
+            Labels {
+                name: "8080".to_string(),
+                version: "true".to_string(),
+                enabled: true,
+                retries: 3
+            }
+        );
+    }
+
+    #[xtest]
+    fn test_invalid_env_var_keeps_the_rest() {
+        let file = parse_json(r#"{ "host": "localhost", "port": 80 }"#);
+        let net: Net = load_resource_from(
+            Some(("net.json", file)),
+            Some(("NET_", vars(&[("NET_PORT", "not a port"), ("NET_HOST", "example.com")]))),
+        );
+        assert_eq!(net, Net { host: "example.com".to_string(), port: 80, tls: Tls::default() });
+    }
+}
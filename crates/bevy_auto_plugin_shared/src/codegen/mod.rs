@@ -11,6 +11,8 @@ use quote::{
 pub struct ExpandAttrs {
     pub attrs: Vec<MacroStream>,
     pub use_items: Vec<MacroStream>,
+    /// items emitted alongside the input item (e.g. a generated companion type)
+    pub items: Vec<MacroStream>,
 }
 
 impl PartialEq for ExpandAttrs {
@@ -23,11 +25,16 @@ impl ExpandAttrs {
     pub fn append(&mut self, other: Self) {
         self.attrs.extend(other.attrs);
         self.use_items.extend(other.use_items);
+        self.items.extend(other.items);
     }
 }
 
 impl ToTokens for ExpandAttrs {
     fn to_tokens(&self, tokens: &mut MacroStream) {
+        let items = &self.items;
+        tokens.extend(quote! {
+            #(#items)*
+        });
         let use_items = &self.use_items;
         tokens.extend(quote! {
             #(#use_items)*
@@ -22,6 +22,8 @@ pub fn reflect<'a>(idents: impl IntoIterator<Item = &'a Ident>) -> ExpandAttrs {
             Some(match ident.to_string().as_str() {
                 // Make the helper available for #[reflect(Component)]
                 "Component" => crate::__private::paths::reflect::reflect_component_use_tokens(),
+                // Make the helper available for #[reflect(Event)]
+                "Event" => crate::__private::paths::reflect::reflect_event_use_tokens(),
                 // Make the helper available for #[reflect(Resource)]
                 "Resource" => crate::__private::paths::reflect::reflect_resource_use_tokens(),
                 // Make the helper available for #[reflect(Default)]
@@ -37,6 +39,7 @@ pub fn reflect<'a>(idents: impl IntoIterator<Item = &'a Ident>) -> ExpandAttrs {
             #[reflect(#(#idents),*)]
         }],
         use_items,
+        items: vec![],
     }
 }
 
@@ -65,6 +68,10 @@ pub fn derive_resource_path() -> NonEmptyPath {
     ecs_import!(prelude::Resource)
 }
 
+pub fn derive_bundle_path() -> NonEmptyPath {
+    ecs_import!(prelude::Bundle)
+}
+
 pub fn derive_event_path() -> NonEmptyPath {
     ecs_import!(prelude::Event)
 }
@@ -107,6 +114,15 @@ pub fn derive_resource<'a>(extra_items: impl IntoIterator<Item = &'a NonEmptyPat
         .concat(),
     )
 }
+pub fn derive_bundle<'a>(extra_items: impl IntoIterator<Item = &'a NonEmptyPath>) -> TokenStream {
+    derive_from(
+        [
+            vec![&derive_bundle_path()],
+            extra_items.into_iter().collect::<Vec<_>>(),
+        ]
+        .concat(),
+    )
+}
 pub fn derive_event<'a>(extra_items: impl IntoIterator<Item = &'a NonEmptyPath>) -> TokenStream {
     derive_from(
         [
@@ -154,6 +170,7 @@ pub fn derive_states<'a>(extra_items: impl IntoIterator<Item = &'a NonEmptyPath>
             ]
             .concat(),
         )],
+        items: vec![],
     }
 }
 pub fn derive_sub_states<'a>(
@@ -176,8 +193,37 @@ pub fn derive_sub_states<'a>(
             ]
             .concat(),
         )],
+        items: vec![],
     }
 }
+pub fn component(items: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let items = items.into_iter();
+    quote! { #[component(#(#items),*)] }
+}
+pub fn require<'a>(items: impl IntoIterator<Item = &'a ComponentRequire>) -> TokenStream {
+    let items = items.into_iter();
+    quote! { #[require(#(#items),*)] }
+}
+pub fn relationship(relationship_target: &syn::Path) -> TokenStream {
+    quote! { #[relationship(relationship_target = #relationship_target)] }
+}
+pub fn relationship_target(relationship: &syn::Path, linked_spawn: bool) -> TokenStream {
+    if linked_spawn {
+        quote! { #[relationship_target(relationship = #relationship, linked_spawn)] }
+    } else {
+        quote! { #[relationship_target(relationship = #relationship)] }
+    }
+}
+pub fn relationship_target_path() -> syn::Path {
+    ecs_import!(relationship::RelationshipTarget)
+}
+pub fn entity_path() -> syn::Path {
+    ecs_import!(entity::Entity)
+}
+pub fn entity_event(items: impl IntoIterator<Item = TokenStream>) -> TokenStream {
+    let items = items.into_iter();
+    quote! { #[entity_event(#(#items),*)] }
+}
 pub fn derive_reflect() -> TokenStream {
     let derive_reflect_path = derive_reflect_path();
     quote! { #[derive(#derive_reflect_path)] }
@@ -217,6 +263,9 @@ pub fn auto_add_systems(args: AddSystemAttrEmitter) -> TokenStream {
 pub fn auto_add_observer(args: AddObserverAttrEmitter) -> TokenStream {
     args.to_token_stream()
 }
+pub fn auto_run_on_build(args: RunOnBuildAttrEmitter) -> TokenStream {
+    args.to_token_stream()
+}
 pub fn auto_add_message(args: AddMessageAttrEmitter) -> TokenStream {
     args.to_token_stream()
 }
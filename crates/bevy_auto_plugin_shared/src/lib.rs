@@ -6,8 +6,14 @@ mod macro_api;
 mod syntax;
 #[cfg(test)]
 mod test_util;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "tooling")]
+pub mod tooling;
 mod util;
 
+pub use __private::message_update::update_manual_messages;
+
 #[cfg(target_arch = "wasm32")]
 unsafe extern "C" {
     fn __wasm_call_ctors();
@@ -1,4 +1,10 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::{
+        message_update::MessageUpdateArgs,
+        prelude::*,
+    },
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -8,16 +14,19 @@ use quote::{
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
-pub struct AddMessageArgs {}
+pub struct AddMessageArgs {
+    #[darling(flatten)]
+    pub update: MessageUpdateArgs,
+}
 
 impl AttributeIdent for AddMessageArgs {
     const IDENT: &'static str = "auto_add_message";
 }
 
-pub type IaAddMessage = ItemAttribute<
-    Composed<AddMessageArgs, WithPlugin, WithZeroOrManyGenerics>,
-    AllowStructOrEnumOrUse,
->;
+impl_has_keys!(AddMessageArgs => ["update_in", "manual_update"]);
+
+pub type IaAddMessage =
+    ItemAttribute<Composed<AddMessageArgs, WithPlugin, WithZeroOrManyGenerics>, AllowMessageOrUse>;
 pub type AddMessageAppMutEmitter = AppMutationEmitter<IaAddMessage>;
 pub type AddMessageAttrEmitter = AttrEmitter<IaAddMessage>;
 
@@ -29,9 +38,9 @@ impl EmitAppMutationTokens for AddMessageAppMutEmitter {
     ) -> syn::Result<()> {
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
-            tokens.extend(quote! {
-                #app_param.add_message::<#concrete_path>();
-            });
+            tokens.extend(
+                self.args.args.base.update.to_app_mutation_tokens(app_param, &concrete_path),
+            );
         }
         Ok(())
     }
@@ -39,7 +48,8 @@ impl EmitAppMutationTokens for AddMessageAppMutEmitter {
 
 impl ToTokens for AddMessageAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let args = self.args.args.extra_args();
+        let mut args = self.args.args.extra_args();
+        args.extend(self.args.args.base.update.to_inner_arg_tokens_vec());
         tokens.extend(quote! {
             #(#args),*
         });
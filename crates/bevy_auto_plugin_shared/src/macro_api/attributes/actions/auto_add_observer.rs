@@ -1,4 +1,10 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::{
+        observer_config::ObserverConfigArgs,
+        prelude::*,
+    },
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -8,12 +14,17 @@ use quote::{
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
-pub struct AddObserverArgs {}
+pub struct AddObserverArgs {
+    #[darling(flatten)]
+    pub config: ObserverConfigArgs,
+}
 
 impl AttributeIdent for AddObserverArgs {
     const IDENT: &'static str = "auto_add_observer";
 }
 
+impl_has_keys!(AddObserverArgs => ["on_entity_with", "name", "run_if"]);
+
 pub type IaAddObserver =
     ItemAttribute<Composed<AddObserverArgs, WithPlugin, WithZeroOrManyGenerics>, AllowFnOrUse>;
 pub type AddObserverAppMutEmitter = AppMutationEmitter<IaAddObserver>;
@@ -25,11 +36,10 @@ impl EmitAppMutationTokens for AddObserverAppMutEmitter {
         tokens: &mut TokenStream,
         app_param: &syn::Ident,
     ) -> syn::Result<()> {
+        let config = &self.args.args.base.config;
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
-            tokens.extend(quote! {
-                #app_param.add_observer( #concrete_path );
-            });
+            tokens.extend(config.to_app_mutation_tokens(app_param, &concrete_path));
         }
         Ok(())
     }
@@ -37,7 +47,8 @@ impl EmitAppMutationTokens for AddObserverAppMutEmitter {
 
 impl ToTokens for AddObserverAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let args = self.args.args.extra_args();
+        let mut args = self.args.args.extra_args();
+        args.extend(self.args.args.base.config.to_inner_arg_tokens_vec());
         tokens.extend(quote! {
             #(#args),*
         });
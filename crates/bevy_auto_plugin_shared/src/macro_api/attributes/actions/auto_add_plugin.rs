@@ -1,11 +1,13 @@
 use crate::{
     macro_api::prelude::*,
     syntax::ast::flag_or_expr::FlagOrExpr,
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
     ToTokens,
+    format_ident,
     quote,
 };
 
@@ -20,6 +22,8 @@ impl AttributeIdent for AddPluginArgs {
     const IDENT: &'static str = "auto_add_plugin";
 }
 
+impl_has_keys!(AddPluginArgs => ["init"]);
+
 pub type IaAddPlugin = ItemAttribute<
     Composed<AddPluginArgs, WithPlugin, WithZeroOrManyGenerics>,
     AllowStructOrEnumOrUse,
@@ -33,24 +37,52 @@ impl EmitAppMutationTokens for AddPluginAppMutEmitter {
         tokens: &mut TokenStream,
         app_param: &syn::Ident,
     ) -> syn::Result<()> {
+        let plugin = self.args.plugin();
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
-            if let Some(expr) = &self.args.args.base.init.expr {
-                tokens.extend(quote! {
-                    #app_param.add_plugins({ let plugin: #concrete_path = #expr; plugin });
-                });
+            let value = if let Some(expr) = &self.args.args.base.init.expr {
+                quote! { { let plugin: #concrete_path = #expr; plugin } }
             } else if self.args.args.base.init.present {
-                tokens.extend(quote! {
-                    #app_param.add_plugins(#concrete_path::default());
-                });
+                quote! { #concrete_path::default() }
             } else {
-                tokens.extend(quote! {
-                    #app_param.add_plugins(#concrete_path);
-                });
-            }
+                quote! { #concrete_path }
+            };
+            // duplicates are reported with every other attribute adding the plugin
+            tokens.extend(quote! {
+                ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies::add_plugin::<#plugin, #concrete_path>(
+                    #app_param,
+                    #value,
+                    ::bevy_auto_plugin::__private::shared::registry_order!(),
+                );
+            });
         }
         Ok(())
     }
+
+    fn to_registry_items(&self, unique_ident: &syn::Ident) -> syn::Result<TokenStream> {
+        let deps = quote! { ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies };
+        let registry =
+            quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry };
+        let plugin = self.args.plugin();
+        let mut tokens = TokenStream::new();
+        for (ix, concrete_path) in self.args.concrete_paths()?.into_iter().enumerate() {
+            let static_ident = format_ident!("{unique_ident}_dependency_{ix}");
+            tokens.extend(quote! {
+                ::bevy_auto_plugin::__private::shared::_plugin_dependency!(
+                    #static_ident,
+                    #deps::AutoPluginDependency::new(
+                        || <#plugin as #registry::AutoPluginTypeId>::type_id(),
+                        || ::core::any::type_name::<#plugin>(),
+                        || <#concrete_path as #registry::AutoPluginTypeId>::type_id(),
+                        || ::core::any::type_name::<#concrete_path>(),
+                        #deps::DependencyKind::Adds,
+                        ::bevy_auto_plugin::__private::shared::registry_order!()
+                    )
+                );
+            });
+        }
+        Ok(tokens)
+    }
 }
 
 impl ToTokens for AddPluginAttrEmitter {
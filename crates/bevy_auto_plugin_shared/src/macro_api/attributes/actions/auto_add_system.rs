@@ -7,6 +7,7 @@ use crate::{
         any_expr::AnyExprCallClosureMacroPath,
         any_expr_list::AnyExprList,
     },
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -28,6 +29,17 @@ impl AttributeIdent for AddSystemArgs {
     const IDENT: &'static str = "auto_add_system";
 }
 
+impl_has_keys!(AddSystemArgs => [
+    "schedule",
+    "fixed",
+    "startup",
+    "update",
+    "post_update",
+    "in_state",
+    "config",
+    "pipe_in",
+]);
+
 pub type IaAddSystem =
     ItemAttribute<Composed<AddSystemArgs, WithPlugin, WithZeroOrManyGenerics>, AllowFnOrUse>;
 pub type AddSystemAppMutEmitter = AppMutationEmitter<IaAddSystem>;
@@ -39,8 +51,9 @@ impl EmitAppMutationTokens for AddSystemAppMutEmitter {
         tokens: &mut TokenStream,
         app_param: &syn::Ident,
     ) -> syn::Result<()> {
-        let schedule = &self.args.args.base.schedule_config.schedule;
-        let config_tokens = self.args.args.base.schedule_config.config.to_token_stream();
+        let plugin = self.args.args.plugin();
+        let schedule_config = &self.args.args.base.schedule_config;
+        let schedule = schedule_config.schedule_tokens(plugin);
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
             let system_tokens = match &self.args.args.base.pipe_in {
@@ -57,8 +70,9 @@ impl EmitAppMutationTokens for AddSystemAppMutEmitter {
                 }
                 None => quote! { #concrete_path },
             };
+            let system_tokens = schedule_config.system_tokens(plugin, &system_tokens);
             tokens.extend(quote! {
-                #app_param . add_systems(#schedule, #system_tokens #config_tokens);
+                #app_param . add_systems(#schedule, #system_tokens);
             });
         }
         Ok(())
@@ -68,8 +82,9 @@ impl EmitAppMutationTokens for AddSystemAppMutEmitter {
 impl ToTokens for AddSystemAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let mut args = self.args.args.extra_args();
-        let schedule = &self.args.args.base.schedule_config.schedule;
-        args.push(quote! { schedule = #schedule });
+        if let Some(schedule) = &self.args.args.base.schedule_config.schedule {
+            args.push(quote! { schedule = #schedule });
+        }
         if let Some(pipe_in) = &self.args.args.base.pipe_in {
             args.push(quote! { pipe_in = [#pipe_in] });
         }
@@ -46,11 +46,12 @@ use syn::{
 pub fn output(
     args: &ConfigureSystemSetArgs,
     app_param: &Ident,
+    plugin: &Path,
     concrete_path: &Path,
     has_generics: bool,
 ) -> TokenStream {
     let mut tokens = TokenStream::new();
-    let schedule = &args.schedule_config.schedule;
+    let schedule = args.schedule_config.schedule_tokens(plugin);
     let config_tokens = args.schedule_config.config.to_token_stream();
     if let Some(inner) = &args.inner {
         // enum
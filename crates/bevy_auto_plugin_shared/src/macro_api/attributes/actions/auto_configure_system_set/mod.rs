@@ -9,6 +9,7 @@ use crate::{
         },
     },
     syntax::ast::flag::Flag,
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::{
@@ -99,6 +100,19 @@ impl AttributeIdent for ConfigureSystemSetArgs {
     const IDENT: &'static str = "auto_configure_system_set";
 }
 
+impl_has_keys!(ConfigureSystemSetArgs => [
+    "group",
+    "schedule",
+    "fixed",
+    "startup",
+    "update",
+    "post_update",
+    "in_state",
+    "config",
+    "chain",
+    "chain_ignore_deferred",
+]);
+
 pub type IaConfigureSystemSet = ItemAttribute<
     Composed<ConfigureSystemSetArgs, WithPlugin, WithZeroOrManyGenerics>,
     AllowStructOrEnum,
@@ -144,6 +158,7 @@ impl EmitAppMutationTokens for ConfigureSystemSetAppMutEmitter {
             tokens.extend(inflate::output(
                 &inflated_args,
                 app_param,
+                self.args.args.plugin(),
                 &concrete_path,
                 !generics.is_empty(),
             ));
@@ -199,7 +214,7 @@ mod tests {
             let args = parse2::<ConfigureSystemSetArgs>(quote!(schedule = Update))?;
             let path: Path = parse_quote!(FooTarget);
             let app_param = parse_quote!(app);
-            let tokens = output(&args, &app_param, &path, false);
+            let tokens = output(&args, &app_param, &parse_quote!(Test), &path, false);
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -214,7 +229,13 @@ mod tests {
         fn test_to_tokens_single() -> syn::Result<()> {
             let args = parse2::<ConfigureSystemSetArgs>(quote!(schedule = Update))?;
             let app_param = parse_quote!(app);
-            let tokens = output(&args, &app_param, &parse_quote!(FooTarget::<u8, bool>), true);
+            let tokens = output(
+                &args,
+                &app_param,
+                &parse_quote!(Test),
+                &parse_quote!(FooTarget::<u8, bool>),
+                true,
+            );
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -229,7 +250,13 @@ mod tests {
         fn test_to_tokens_multiple() -> syn::Result<()> {
             let args = parse2::<ConfigureSystemSetArgs>(quote!(schedule = Update))?;
             let app_param = parse_quote!(app);
-            let tokens = output(&args, &app_param, &parse_quote!(FooTarget::<u8, bool>), true);
+            let tokens = output(
+                &args,
+                &app_param,
+                &parse_quote!(Test),
+                &parse_quote!(FooTarget::<u8, bool>),
+                true,
+            );
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -237,7 +264,13 @@ mod tests {
                 }
                 .to_string()
             );
-            let tokens = output(&args, &app_param, &parse_quote!(FooTarget::<bool, bool>), true);
+            let tokens = output(
+                &args,
+                &app_param,
+                &parse_quote!(Test),
+                &parse_quote!(FooTarget::<bool, bool>),
+                true,
+            );
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -273,7 +306,7 @@ mod tests {
                 },
             )?;
             let app_param = parse_quote!(app);
-            let output = output(&args, &app_param, &(ident.into()), false);
+            let output = output(&args, &app_param, &parse_quote!(Test), &(ident.into()), false);
             assert_eq!(
                 output.to_string(),
                 quote! {
@@ -296,7 +329,7 @@ mod tests {
                 },
             )?;
             let app_param = parse_quote!(app);
-            let tokens = output(&args, &app_param, &(ident.into()), false);
+            let tokens = output(&args, &app_param, &parse_quote!(Test), &(ident.into()), false);
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -321,7 +354,7 @@ mod tests {
                 },
             )?;
             let app_param = parse_quote!(app);
-            let tokens = output(&args, &app_param, &(ident.into()), false);
+            let tokens = output(&args, &app_param, &parse_quote!(Test), &(ident.into()), false);
             assert_eq!(
                 tokens.to_string(),
                 quote! {
@@ -355,8 +388,9 @@ mod tests {
                 inflated_args,
                 ConfigureSystemSetArgs {
                     schedule_config: ScheduleWithScheduleConfigArgs {
-                        schedule: parse_quote!(Update),
+                        schedule: Some(parse_quote!(Update)),
                         config: ScheduleConfigArgs::default(),
+                        ..Default::default()
                     },
                     inner: Some(ConfigureSystemSetArgsInner {
                         entries: vec![
@@ -1,9 +1,12 @@
-use crate::macro_api::{
-    emitters::app_mutation::{
-        AppMutationEmitter,
-        EmitAppMutationTokens,
+use crate::{
+    macro_api::{
+        emitters::app_mutation::{
+            AppMutationEmitter,
+            EmitAppMutationTokens,
+        },
+        prelude::*,
     },
-    prelude::*,
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -20,9 +23,11 @@ impl AttributeIdent for InitResourceArgs {
     const IDENT: &'static str = "auto_init_resource";
 }
 
+impl_has_keys!(InitResourceArgs => []);
+
 pub type IaInitResource = ItemAttribute<
     Composed<InitResourceArgs, WithPlugin, WithZeroOrManyGenerics>,
-    AllowStructOrEnumOrUse,
+    AllowResourceOrUse,
 >;
 pub type InitResourceAppMutEmitter = AppMutationEmitter<IaInitResource>;
 pub type InitResourceAttrEmitter = AttrEmitter<IaInitResource>;
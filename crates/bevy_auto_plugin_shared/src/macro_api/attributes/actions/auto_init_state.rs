@@ -1,4 +1,10 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::{
+        prelude::*,
+        state_transitions::StateTransitionArgs,
+    },
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -8,14 +14,20 @@ use quote::{
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
-pub struct InitStateArgs {}
+pub struct InitStateArgs {
+    pub initial: Option<syn::Expr>,
+    #[darling(flatten)]
+    pub transitions: StateTransitionArgs,
+}
 
 impl AttributeIdent for InitStateArgs {
     const IDENT: &'static str = "auto_init_state";
 }
 
+impl_has_keys!(InitStateArgs => ["initial", "log_transitions", "allow_same_state_transitions"]);
+
 pub type IaInitState =
-    ItemAttribute<Composed<InitStateArgs, WithPlugin, WithNoGenerics>, AllowStructOrEnumOrUse>;
+    ItemAttribute<Composed<InitStateArgs, WithPlugin, WithNoGenerics>, AllowStatesOrUse>;
 pub type InitStateAppMutEmitter = AppMutationEmitter<IaInitState>;
 pub type InitStateAttrEmitter = AttrEmitter<IaInitState>;
 
@@ -27,9 +39,17 @@ impl EmitAppMutationTokens for InitStateAppMutEmitter {
     ) -> syn::Result<()> {
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
-            tokens.extend(quote! {
-                #app_param.init_state::<#concrete_path>();
-            });
+            match &self.args.args.base.initial {
+                Some(initial) => tokens.extend(quote! {
+                    #app_param.insert_state::<#concrete_path>(#initial);
+                }),
+                None => tokens.extend(quote! {
+                    #app_param.init_state::<#concrete_path>();
+                }),
+            }
+            tokens.extend(
+                self.args.args.base.transitions.to_app_mutation_tokens(app_param, &concrete_path),
+            );
         }
         Ok(())
     }
@@ -37,7 +57,12 @@ impl EmitAppMutationTokens for InitStateAppMutEmitter {
 
 impl ToTokens for InitStateAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let args = self.args.args.extra_args();
+        let mut args = self.args.args.extra_args();
+        let base = &self.args.args.base;
+        if let Some(initial) = &base.initial {
+            args.push(quote! { initial = #initial });
+        }
+        args.extend(base.transitions.to_inner_arg_tokens_vec());
         tokens.extend(quote! {
             #(#args),*
         });
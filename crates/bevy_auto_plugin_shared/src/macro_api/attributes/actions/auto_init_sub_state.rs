@@ -1,4 +1,10 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::{
+        prelude::*,
+        state_transitions::StateTransitionArgs,
+    },
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -8,14 +14,19 @@ use quote::{
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
-pub struct InitSubStateArgs {}
+pub struct InitSubStateArgs {
+    #[darling(flatten)]
+    pub transitions: StateTransitionArgs,
+}
 
 impl AttributeIdent for InitSubStateArgs {
     const IDENT: &'static str = "auto_init_sub_state";
 }
 
+impl_has_keys!(InitSubStateArgs => ["log_transitions", "allow_same_state_transitions"]);
+
 pub type IaInitSubState =
-    ItemAttribute<Composed<InitSubStateArgs, WithPlugin, WithNoGenerics>, AllowStructOrEnumOrUse>;
+    ItemAttribute<Composed<InitSubStateArgs, WithPlugin, WithNoGenerics>, AllowSubStatesOrUse>;
 pub type InitSubStateAppMutEmitter = AppMutationEmitter<IaInitSubState>;
 pub type InitSubStateAttrEmitter = AttrEmitter<IaInitSubState>;
 
@@ -30,6 +41,9 @@ impl EmitAppMutationTokens for InitSubStateAppMutEmitter {
             tokens.extend(quote! {
                 #app_param.add_sub_state::<#concrete_path>();
             });
+            tokens.extend(
+                self.args.args.base.transitions.to_app_mutation_tokens(app_param, &concrete_path),
+            );
         }
         Ok(())
     }
@@ -37,7 +51,8 @@ impl EmitAppMutationTokens for InitSubStateAppMutEmitter {
 
 impl ToTokens for InitSubStateAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let args = self.args.args.extra_args();
+        let mut args = self.args.args.extra_args();
+        args.extend(self.args.args.base.transitions.to_inner_arg_tokens_vec());
         tokens.extend(quote! {
             #(#args),*
         });
@@ -1,6 +1,7 @@
 use crate::{
     macro_api::prelude::*,
     syntax::ast::any_expr::AnyExprCallClosureMacroPath,
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -86,6 +87,8 @@ impl AttributeIdent for InsertResourceArgs {
     const IDENT: &'static str = "auto_insert_resource";
 }
 
+impl_has_keys!(InsertResourceArgs => ["insert"]);
+
 pub type IaInsertResource = ItemAttribute<
     Composed<InsertResourceArgs, WithPlugin, WithZeroOrOneGenerics>,
     AllowStructOrEnumOrUse,
@@ -102,7 +105,7 @@ impl EmitAppMutationTokens for InsertResourceAppMutEmitter {
         let resource = self.args.args.base.resolve_resource().map_err(syn::Error::from)?;
         let concrete_paths = self.args.concrete_paths()?;
         let placeholder_path = if self.args.args.generics().is_empty() {
-            let type_params = self.args.input_item.type_param_idents()?;
+            let type_params = self.args.input_item.generic_params()?;
             if type_params.is_empty() {
                 None
             } else {
@@ -1,6 +1,7 @@
 use crate::{
     macro_api::prelude::*,
     syntax::extensions::lit::LitExt,
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream;
@@ -19,8 +20,10 @@ impl AttributeIdent for NameArgs {
     const IDENT: &'static str = "auto_name";
 }
 
+impl_has_keys!(NameArgs => ["name"]);
+
 pub type IaName =
-    ItemAttribute<Composed<NameArgs, WithPlugin, WithZeroOrManyGenerics>, AllowStructOrEnumOrUse>;
+    ItemAttribute<Composed<NameArgs, WithPlugin, WithZeroOrManyGenerics>, AllowComponentOrUse>;
 pub type NameAppMutEmitter = AppMutationEmitter<IaName>;
 pub type NameAttrEmitter = AttrEmitter<IaName>;
 
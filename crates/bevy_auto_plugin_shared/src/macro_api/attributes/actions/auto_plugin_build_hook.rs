@@ -1,4 +1,7 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::prelude::*,
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -14,9 +17,11 @@ pub struct AutoPluginBuildHookArgs {
 }
 
 impl AttributeIdent for AutoPluginBuildHookArgs {
-    const IDENT: &'static str = "auto_plugin_hook";
+    const IDENT: &'static str = "auto_plugin_build_hook";
 }
 
+impl_has_keys!(AutoPluginBuildHookArgs => ["hook"]);
+
 pub type IaAutoPluginBuildHook = ItemAttribute<
     Composed<AutoPluginBuildHookArgs, WithPlugin, WithZeroOrManyGenerics>,
     AllowStructOrEnumOrUse,
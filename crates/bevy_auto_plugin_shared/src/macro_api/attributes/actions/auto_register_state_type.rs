@@ -1,4 +1,7 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::prelude::*,
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -14,9 +17,11 @@ impl AttributeIdent for RegisterStateTypeArgs {
     const IDENT: &'static str = "auto_register_state_type";
 }
 
+impl_has_keys!(RegisterStateTypeArgs => []);
+
 pub type IaRegisterStateType = ItemAttribute<
     Composed<RegisterStateTypeArgs, WithPlugin, WithZeroOrManyGenerics>,
-    AllowStructOrEnumOrUse,
+    AllowStateTypesOrUse,
 >;
 pub type RegisterStateTypeAppMutEmitter = AppMutationEmitter<IaRegisterStateType>;
 pub type RegisterStateTypeAttrEmitter = AttrEmitter<IaRegisterStateType>;
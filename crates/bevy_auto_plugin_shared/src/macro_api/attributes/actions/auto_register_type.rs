@@ -1,6 +1,15 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::prelude::*,
+    syntax::analysis::item::has_derive,
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
-use proc_macro2::TokenStream;
+use proc_macro2::{
+    Group,
+    Span,
+    TokenStream,
+    TokenTree,
+};
 use quote::{
     ToTokens,
     quote,
@@ -14,9 +23,11 @@ impl AttributeIdent for RegisterTypeArgs {
     const IDENT: &'static str = "auto_register_type";
 }
 
+impl_has_keys!(RegisterTypeArgs => []);
+
 pub type IaRegisterType = ItemAttribute<
     Composed<RegisterTypeArgs, WithPlugin, WithZeroOrManyGenerics>,
-    AllowStructOrEnumOrUse,
+    AllowReflectOrUse,
 >;
 pub type RegisterTypeAppMutEmitter = AppMutationEmitter<IaRegisterType>;
 pub type RegisterTypeAttrEmitter = AttrEmitter<IaRegisterType>;
@@ -27,8 +38,22 @@ impl EmitAppMutationTokens for RegisterTypeAppMutEmitter {
         tokens: &mut TokenStream,
         app_param: &syn::Ident,
     ) -> syn::Result<()> {
+        let visible_reflect = visible_reflect(self.args.input_item());
+        if let VisibleReflect::Missing(ident) = visible_reflect {
+            return Err(syn::Error::new(
+                ident.span(),
+                format!(
+                    "`{ident}` is registered with the type registry but doesn't derive `Reflect`\n\
+                     add `Reflect` to its `#[derive(..)]`, or `derive, reflect` to its `auto_*` \
+                     attribute"
+                ),
+            ));
+        }
         let concrete_paths = self.args.concrete_paths()?;
         for concrete_path in concrete_paths {
+            if visible_reflect == VisibleReflect::Unknown {
+                tokens.extend(reflect_assertion_tokens(&concrete_path));
+            }
             tokens.extend(quote! {
                 #app_param.register_type::<#concrete_path>();
             });
@@ -36,6 +61,62 @@ impl EmitAppMutationTokens for RegisterTypeAppMutEmitter {
         Ok(())
     }
 }
+
+/// What the attributes left on the item say about its `Reflect` impl
+#[derive(Debug, PartialEq)]
+enum VisibleReflect<'a> {
+    Derived,
+    /// derives are listed on the struct or enum, none of them `Reflect`
+    Missing(&'a syn::Ident),
+    /// a `use` item, or the derives were above the attribute and are already expanded
+    Unknown,
+}
+
+fn visible_reflect(input_item: &InputItem) -> VisibleReflect<'_> {
+    let InputItem::Item(item) = input_item else {
+        return VisibleReflect::Unknown;
+    };
+    let (ident, attrs) = match item.as_ref() {
+        syn::Item::Struct(item) => (&item.ident, &item.attrs),
+        syn::Item::Enum(item) => (&item.ident, &item.attrs),
+        _ => return VisibleReflect::Unknown,
+    };
+    let has_attr = |ident: &str| attrs.iter().any(|attr| attr.path().is_ident(ident));
+    if has_derive(attrs, "Reflect") {
+        VisibleReflect::Derived
+    } else if has_attr("derive") && !has_attr("reflect") {
+        VisibleReflect::Missing(ident)
+    } else {
+        VisibleReflect::Unknown
+    }
+}
+
+/// Asserts `concrete_path` implements `Reflect`, reporting a missing impl at the attribute
+fn reflect_assertion_tokens(concrete_path: &impl ToTokens) -> TokenStream {
+    let concrete_path = respan(concrete_path.to_token_stream(), Span::call_site());
+    quote! {
+        const _: fn() = || {
+            ::bevy_auto_plugin::__private::shared::__private::reflect::assert_reflect::<#concrete_path>();
+        };
+    }
+}
+
+fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|mut tt| {
+            if let TokenTree::Group(group) = &tt {
+                let mut respanned = Group::new(group.delimiter(), respan(group.stream(), span));
+                respanned.set_span(span);
+                tt = respanned.into();
+            } else {
+                tt.set_span(span);
+            }
+            tt
+        })
+        .collect()
+}
+
 impl ToTokens for RegisterTypeAttrEmitter {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let args = self.args.args.extra_args();
@@ -1,4 +1,7 @@
-use crate::macro_api::prelude::*;
+use crate::{
+    macro_api::prelude::*,
+    util::macros::impl_has_keys,
+};
 use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
@@ -6,7 +9,7 @@ use quote::{
     quote,
 };
 
-#[derive(FromMeta, Debug, Clone, PartialEq, Hash)]
+#[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse)]
 pub struct RunOnBuildArgs {}
 
@@ -14,6 +17,8 @@ impl AttributeIdent for RunOnBuildArgs {
     const IDENT: &'static str = "auto_run_on_build";
 }
 
+impl_has_keys!(RunOnBuildArgs => []);
+
 pub type IaRunOnBuild =
     ItemAttribute<Composed<RunOnBuildArgs, WithPlugin, WithZeroOrManyGenerics>, AllowFnOrUse>;
 pub type RunOnBuildAppMutEmitter = AppMutationEmitter<IaRunOnBuild>;
@@ -41,5 +46,6 @@ impl ToTokens for RunOnBuildAttrEmitter {
         tokens.extend(quote! {
             #(#args),*
         });
+        *tokens = self.wrap_as_attr(tokens);
     }
 }
@@ -0,0 +1,11 @@
+use darling::{
+    FromMeta,
+    util::PathList,
+};
+
+#[derive(FromMeta, Debug, Default, Clone, PartialEq)]
+#[darling(derive_syn_parse, default)]
+pub struct AutoExportArgs {
+    /// items that should not be picked up by glob or `self` imports
+    pub exclude: PathList,
+}
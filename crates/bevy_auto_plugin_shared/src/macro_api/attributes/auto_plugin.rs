@@ -1,17 +1,38 @@
-use crate::syntax::{
-    analysis::fn_param::require_fn_param_mutable_reference,
-    ast::type_list::TypeList,
+use crate::{
+    macro_api::plugin_defaults::PluginDefaultsArgs,
+    syntax::{
+        analysis::fn_param::require_fn_param_mutable_reference,
+        ast::{
+            any_expr::AnyExprCallMacroPath,
+            type_list::TypeList,
+        },
+    },
 };
 use darling::{
     FromMeta,
     util::Flag,
 };
-use proc_macro2::Ident;
+use proc_macro2::{
+    Ident,
+    TokenStream as MacroStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::{
     FnArg,
     ItemFn,
+    Meta,
     Pat,
     Path,
+    Token,
+    Type,
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    punctuated::Punctuated,
     spanned::Spanned,
 };
 
@@ -21,6 +42,146 @@ pub struct AutoPluginStructOrEnumArgs {
     pub impl_plugin_trait: Flag,
     #[cfg(feature = "default_plugin")]
     pub default_plugin: Flag,
+    pub default_schedule: Option<AnyExprCallMacroPath>,
+    pub defaults: PluginDefaultsArgs,
+    #[darling(multiple)]
+    pub requires: Vec<RequiresArgs>,
+}
+
+/// `requires(PhysicsPlugin, InputPlugin)` or `requires(PhysicsPlugin, mode = assert)`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct RequiresArgs {
+    pub plugins: Vec<Type>,
+    pub mode: RequiresMode,
+}
+
+/// What building a plugin does about a required plugin that isn't in the app yet
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum RequiresMode {
+    /// adds `Plugin::default()`
+    #[default]
+    Add,
+    /// panics, the plugin has to be added before the one requiring it
+    Assert,
+}
+
+enum RequiresItem {
+    Mode(RequiresMode),
+    Plugin(Box<Type>),
+}
+
+impl Parse for RequiresItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Ident) && input.peek2(Token![=]) {
+            let key = input.parse::<Ident>()?;
+            if key != "mode" {
+                return Err(syn::Error::new(
+                    key.span(),
+                    format!("unknown key `{key}` for `requires` - expected `mode`"),
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            let mode = input.parse::<Ident>()?;
+            return match mode.to_string().as_str() {
+                "add" => Ok(RequiresItem::Mode(RequiresMode::Add)),
+                "assert" => Ok(RequiresItem::Mode(RequiresMode::Assert)),
+                _ => Err(syn::Error::new(
+                    mode.span(),
+                    format!("unknown mode `{mode}` - expected `add` or `assert`"),
+                )),
+            };
+        }
+        input.parse().map(RequiresItem::Plugin)
+    }
+}
+
+impl FromMeta for RequiresArgs {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        let list = meta.require_list()?;
+        let items = list
+            .parse_args_with(Punctuated::<RequiresItem, Token![,]>::parse_terminated)
+            .map_err(darling::Error::from)?;
+        let mut args = RequiresArgs::default();
+        let mut mode_set = false;
+        for item in items {
+            match item {
+                RequiresItem::Plugin(plugin) => args.plugins.push(*plugin),
+                RequiresItem::Mode(_) if mode_set => {
+                    return Err(darling::Error::duplicate_field("mode").with_span(list));
+                }
+                RequiresItem::Mode(mode) => {
+                    args.mode = mode;
+                    mode_set = true;
+                }
+            }
+        }
+        if args.plugins.is_empty() {
+            return Err(
+                darling::Error::custom("`requires` needs at least one plugin").with_span(list)
+            );
+        }
+        Ok(args)
+    }
+}
+
+/// Override of `AutoPlugin::require_plugins` for every `requires(...)` of a plugin
+pub fn requires_auto_plugin_items(requires: &[RequiresArgs]) -> MacroStream {
+    if requires.is_empty() {
+        return MacroStream::new();
+    }
+    let deps = quote! { ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies };
+    let app = quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_app::App };
+    let deps = &deps;
+    let calls = requires.iter().flat_map(|requires| {
+        requires.plugins.iter().map(move |plugin| match requires.mode {
+            RequiresMode::Add => quote! {
+                #deps::require_plugin::<#plugin>(app);
+            },
+            RequiresMode::Assert => quote! {
+                #deps::assert_plugin::<Self, #plugin>(
+                    app,
+                    ::bevy_auto_plugin::__private::shared::registry_order!(),
+                );
+            },
+        })
+    });
+    quote! {
+        fn require_plugins(app: &mut #app) {
+            #(#calls)*
+        }
+    }
+}
+
+/// Dependency graph edges for the `requires(...)` of a non generic plugin
+pub fn requires_dependency_entries(plugin: &Ident, requires: &[RequiresArgs]) -> MacroStream {
+    let deps = quote! { ::bevy_auto_plugin::__private::shared::__private::plugin_dependencies };
+    let registry =
+        quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry };
+    let entries = requires
+        .iter()
+        .flat_map(|requires| requires.plugins.iter().map(move |dep| (requires.mode, dep)))
+        .enumerate()
+        .map(|(ix, (mode, dep))| {
+            let static_ident = format_ident!("__auto_plugin_requires_{plugin}_{ix}");
+            let kind = match mode {
+                RequiresMode::Add => quote! { Requires },
+                RequiresMode::Assert => quote! { Asserts },
+            };
+            quote! {
+                ::bevy_auto_plugin::__private::shared::_plugin_dependency!(
+                    #static_ident,
+                    #deps::AutoPluginDependency::new(
+                        || <#plugin as #registry::AutoPluginTypeId>::type_id(),
+                        || ::core::any::type_name::<#plugin>(),
+                        || <#dep as #registry::AutoPluginTypeId>::type_id(),
+                        || ::core::any::type_name::<#dep>(),
+                        #deps::DependencyKind::#kind,
+                        ::bevy_auto_plugin::__private::shared::registry_order!()
+                    )
+                );
+            }
+        });
+    quote! { #(#entries)* }
 }
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq)]
@@ -93,8 +254,47 @@ pub fn resolve_app_param_name(input: &ItemFn) -> syn::Result<&Ident> {
 
 #[cfg(test)]
 mod tests {
-    use crate::macro_api::attributes::auto_plugin::resolve_app_param_name;
+    use crate::macro_api::attributes::auto_plugin::{
+        AutoPluginStructOrEnumArgs,
+        RequiresMode,
+        resolve_app_param_name,
+    };
     use internal_test_proc_macro::xtest;
+    use quote::ToTokens;
+
+    #[xtest]
+    fn test_requires() -> syn::Result<()> {
+        let args: AutoPluginStructOrEnumArgs = syn::parse_quote! {
+            requires(PhysicsPlugin, InputPlugin), requires(StatPlugin<Health>, mode = assert)
+        };
+        let [add, assert] = args.requires.as_slice() else {
+            panic!("expected two `requires`, got {:?}", args.requires);
+        };
+        let plugins = |plugins: &[syn::Type]| {
+            plugins.iter().map(|plugin| plugin.to_token_stream().to_string()).collect::<Vec<_>>()
+        };
+        assert_eq!(plugins(&add.plugins), vec!["PhysicsPlugin", "InputPlugin"]);
+        assert_eq!(add.mode, RequiresMode::Add);
+        assert_eq!(plugins(&assert.plugins), vec!["StatPlugin < Health >"]);
+        assert_eq!(assert.mode, RequiresMode::Assert);
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_requires_errors() {
+        let parse = |tokens: proc_macro2::TokenStream| {
+            syn::parse2::<AutoPluginStructOrEnumArgs>(tokens).unwrap_err().to_string()
+        };
+        assert!(parse(quote::quote! { requires(mode = add) }).contains("at least one plugin"));
+        assert!(
+            parse(quote::quote! { requires(PhysicsPlugin, mode = maybe) })
+                .contains("expected `add` or `assert`")
+        );
+        assert!(
+            parse(quote::quote! { requires(PhysicsPlugin, mode = add, mode = assert) })
+                .contains("Duplicate field `mode`")
+        );
+    }
 
     #[xtest]
     fn test_resolve_app_param_name_default() {
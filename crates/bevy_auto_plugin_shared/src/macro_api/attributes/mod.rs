@@ -1,7 +1,11 @@
 use crate::{
     macro_api::prelude::*,
     syntax::{
-        parse::rewrite::maybe_rewrite_generics_angles,
+        ast::flag::Flag,
+        parse::rewrite::{
+            maybe_rewrite_generics_angles,
+            rewrite_type_value,
+        },
         validated::non_empty_path::NonEmptyPath,
     },
     util::macros::impl_from_default,
@@ -15,7 +19,10 @@ use proc_macro2::{
     Span,
     TokenStream,
 };
-use quote::format_ident;
+use quote::{
+    ToTokens,
+    format_ident,
+};
 use std::{
     hash::Hash,
     marker::PhantomData,
@@ -30,20 +37,29 @@ use syn::{
 };
 
 mod actions;
+mod auto_export;
 mod auto_plugin;
 mod rewrites;
 
 pub mod prelude {
     pub use super::{
         AllowAny,
+        AllowComponentOrUse,
         AllowFn,
         AllowFnOrUse,
+        AllowMessageOrUse,
+        AllowReflectOrUse,
+        AllowResourceOrUse,
+        AllowStateTypesOrUse,
+        AllowStatesOrUse,
         AllowStructOrEnum,
         AllowStructOrEnumOrUse,
+        AllowSubStatesOrUse,
         AttributeIdent,
         GenericsCap,
         ItemAttribute,
         ItemAttributeArgs,
+        ItemAttributeArgsDebug,
         ItemAttributeContext,
         ItemAttributeInput,
         ItemAttributeParse,
@@ -51,12 +67,20 @@ pub mod prelude {
         ItemAttributeTarget,
         ItemAttributeTargetMut,
         ItemAttributeUniqueIdent,
+        ItemAttributeUseExportKind,
+        UseExportKind,
+        attribute_keys,
+        auto_export::AutoExportArgs,
         auto_plugin::{
             AutoPluginFnArgs,
             AutoPluginStructOrEnumArgs,
+            requires_auto_plugin_items,
+            requires_dependency_entries,
             resolve_app_param_name,
         },
     };
+    #[cfg(feature = "tooling")]
+    pub use crate::macro_api::attributes::auto_plugin::RequiresMode;
     pub use crate::macro_api::attributes::{
         actions::prelude::*,
         rewrites::prelude::*,
@@ -65,7 +89,6 @@ pub mod prelude {
 
 pub trait AttributeIdent {
     const IDENT: &'static str;
-    #[allow(dead_code)]
     // TODO: should we use this over the context macro_paths?
     //  context macro paths would allow us to resolve aliased versions of this crate
     fn full_attribute_path() -> NonEmptyPath {
@@ -74,6 +97,72 @@ pub trait AttributeIdent {
     }
 }
 
+macro_rules! attribute_keys {
+    ($($args:ty),* $(,)?) => {
+        /// Keys of every attribute, used to point at the attribute an unknown key belongs to
+        pub fn attribute_keys() -> Vec<(&'static str, &'static [&'static str])> {
+            vec![$((<$args as AttributeIdent>::IDENT, <$args as HasKeys>::keys())),*]
+        }
+
+        /// `(attribute, key, listed)` for every key of any attribute where [`HasKeys`] and darling
+        /// disagree on whether the attribute takes it
+        #[cfg(test)]
+        fn keys_darling_disagrees_on() -> Vec<(&'static str, &'static str, bool)> {
+            let mut all_keys = attribute_keys()
+                .into_iter()
+                .flat_map(|(_, keys)| keys.iter().copied())
+                .collect::<Vec<_>>();
+            all_keys.sort_unstable();
+            all_keys.dedup();
+            let mut mismatches = vec![];
+            $(
+                for key in all_keys.iter().copied() {
+                    let ident = format_ident!("{}", key);
+                    let items = [NestedMeta::Meta(parse_quote!(#ident))];
+                    // only the test reads darling's wording, a rename shows up as a failure here
+                    let known_to_darling = <$args as FromMeta>::from_list(&items)
+                        .err()
+                        .is_none_or(|err| {
+                            !err.to_string().contains(&format!("Unknown field: `{key}`"))
+                        });
+                    let listed = <$args as HasKeys>::keys().contains(&key);
+                    if known_to_darling != listed {
+                        mismatches.push((<$args as AttributeIdent>::IDENT, key, listed));
+                    }
+                }
+            )*
+            mismatches
+        }
+    };
+}
+
+attribute_keys!(
+    AddMessageArgs,
+    AddObserverArgs,
+    AddPluginArgs,
+    AddSystemArgs,
+    AutoPluginBuildHookArgs,
+    ConfigureSystemSetArgs,
+    InitResourceArgs,
+    InitStateArgs,
+    InitSubStateArgs,
+    InsertResourceArgs,
+    NameArgs,
+    RegisterStateTypeArgs,
+    RegisterTypeArgs,
+    RunOnBuildArgs,
+    BundleArgs,
+    ComponentArgs,
+    EventArgs,
+    MessageArgs,
+    ObserverArgs,
+    RelationshipArgs,
+    ResourceArgs,
+    StatesArgs,
+    SubStatesArgs,
+    SystemArgs,
+);
+
 pub trait ItemAttributeArgs: AttributeIdent + Clone {
     fn global_build_prefix() -> Ident {
         format_ident!("_auto_plugin_{}_", Self::IDENT)
@@ -95,8 +184,66 @@ where
 {
 }
 
+/// Which list of an `#[auto_export]` module a glob or `self` import expands to
+///
+/// Type lists only hold the structs and enums with a visible derive of the trait the action needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UseExportKind {
+    Reflect,
+    Resource,
+    Message,
+    Component,
+    States,
+    SubStates,
+    /// `States` or `SubStates`
+    StateTypes,
+    Fns,
+}
+
+impl UseExportKind {
+    pub const ALL: &[Self] = &[
+        Self::Reflect,
+        Self::Resource,
+        Self::Message,
+        Self::Component,
+        Self::States,
+        Self::SubStates,
+        Self::StateTypes,
+        Self::Fns,
+    ];
+
+    pub fn ident(&self) -> Ident {
+        match self {
+            Self::Reflect => format_ident!("reflect"),
+            Self::Resource => format_ident!("resource"),
+            Self::Message => format_ident!("message"),
+            Self::Component => format_ident!("component"),
+            Self::States => format_ident!("states"),
+            Self::SubStates => format_ident!("sub_states"),
+            Self::StateTypes => format_ident!("state_types"),
+            Self::Fns => format_ident!("fns"),
+        }
+    }
+
+    /// A type is in the list when it visibly derives one of these, empty for [`Self::Fns`]
+    pub fn derives(&self) -> &'static [&'static str] {
+        match self {
+            Self::Reflect => &["Reflect"],
+            Self::Resource => &["Resource"],
+            Self::Message => &["Message"],
+            Self::Component => &["Component"],
+            Self::States => &["States"],
+            Self::SubStates => &["SubStates"],
+            Self::StateTypes => &["States", "SubStates"],
+            Self::Fns => &[],
+        }
+    }
+}
+
 pub trait IdentPathResolver {
     const NOT_ALLOWED_MESSAGE: &'static str = "Unable to resolve ident path";
+    /// `None` when glob and `self` imports of `#[auto_export]` modules aren't supported
+    const USE_EXPORT_KIND: Option<UseExportKind> = None;
     fn resolve_ident_path(item: &Item) -> Option<syn::Path>;
 }
 
@@ -130,14 +277,29 @@ impl IdentPathResolver for AllowStructOrEnum {
     }
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AllowTypeAlias;
+impl IdentPathResolver for AllowTypeAlias {
+    const NOT_ALLOWED_MESSAGE: &'static str = "Only allowed on type alias items";
+    fn resolve_ident_path(item: &Item) -> Option<syn::Path> {
+        Some(match item {
+            // the alias already names the concrete instantiation (e.g. `type Foo = Bar<u32>;`)
+            Item::Type(item) => item.ident.clone().into(),
+            _ => return None,
+        })
+    }
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AllowStructOrEnumOrUse;
 impl IdentPathResolver for AllowStructOrEnumOrUse {
-    const NOT_ALLOWED_MESSAGE: &'static str = "Only allowed on Struct, Enum, or use items";
+    const NOT_ALLOWED_MESSAGE: &'static str =
+        "Only allowed on Struct, Enum, type alias, or use items";
     fn resolve_ident_path(item: &Item) -> Option<syn::Path> {
         Some(match item {
             Item::Struct(item) => item.ident.clone().into(),
             Item::Enum(item) => item.ident.clone().into(),
+            Item::Type(_) => return AllowTypeAlias::resolve_ident_path(item),
             Item::Use(item) => first_use_local_ident(&item.tree)
                 .unwrap_or_else(|| Ident::new("_auto_plugin_use_item", Span::call_site()))
                 .into(),
@@ -146,6 +308,38 @@ impl IdentPathResolver for AllowStructOrEnumOrUse {
     }
 }
 
+macro_rules! derive_or_use_resolvers {
+    ($($resolver:ident => $kind:ident),+ $(,)?) => {
+        $(
+            #[doc = concat!(
+                "[`AllowStructOrEnumOrUse`], globs only pick up the types in the `",
+                stringify!($kind),
+                "` export list"
+            )]
+            #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+            pub struct $resolver;
+            impl IdentPathResolver for $resolver {
+                const NOT_ALLOWED_MESSAGE: &'static str =
+                    AllowStructOrEnumOrUse::NOT_ALLOWED_MESSAGE;
+                const USE_EXPORT_KIND: Option<UseExportKind> = Some(UseExportKind::$kind);
+                fn resolve_ident_path(item: &Item) -> Option<syn::Path> {
+                    AllowStructOrEnumOrUse::resolve_ident_path(item)
+                }
+            }
+        )+
+    };
+}
+
+derive_or_use_resolvers!(
+    AllowReflectOrUse => Reflect,
+    AllowResourceOrUse => Resource,
+    AllowMessageOrUse => Message,
+    AllowComponentOrUse => Component,
+    AllowStatesOrUse => States,
+    AllowSubStatesOrUse => SubStates,
+    AllowStateTypesOrUse => StateTypes,
+);
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct AllowFn;
 impl IdentPathResolver for AllowFn {
@@ -162,6 +356,7 @@ impl IdentPathResolver for AllowFn {
 pub struct AllowFnOrUse;
 impl IdentPathResolver for AllowFnOrUse {
     const NOT_ALLOWED_MESSAGE: &'static str = "Only allowed on Fn or use items";
+    const USE_EXPORT_KIND: Option<UseExportKind> = Some(UseExportKind::Fns);
     fn resolve_ident_path(item: &Item) -> Option<syn::Path> {
         Some(match item {
             Item::Fn(item) => item.sig.ident.clone().into(),
@@ -200,7 +395,19 @@ impl IdentPathResolver for AllowAny {
     }
 }
 impl_from_default!(AllowAny => (AllowStructOrEnum, AllowFn));
-impl_from_default!(AllowStructOrEnum => (AllowStructOrEnumOrUse));
+impl_from_default!(
+    AllowStructOrEnum => (
+        AllowStructOrEnumOrUse,
+        AllowReflectOrUse,
+        AllowResourceOrUse,
+        AllowMessageOrUse,
+        AllowComponentOrUse,
+        AllowStatesOrUse,
+        AllowSubStatesOrUse,
+        AllowStateTypesOrUse,
+    )
+);
+impl_from_default!(AllowTypeAlias => (AllowStructOrEnumOrUse));
 impl_from_default!(AllowFn => (AllowFnOrUse));
 
 pub trait GenericsCap {
@@ -268,8 +475,9 @@ impl<T, R> ItemAttribute<Composed<T, WithPlugin, WithZeroOrManyGenerics>, R> {
 
 pub trait ItemAttributePlugin {
     fn plugin(&self) -> &syn::Path;
-    fn plugin_after_build(&self) -> bool {
-        false
+    /// `None` follows the plugin's `defaults(after_build)`
+    fn plugin_after_build(&self) -> Option<bool> {
+        None
     }
 }
 
@@ -277,8 +485,8 @@ impl<T, G, Resolver> ItemAttributePlugin for ItemAttribute<Composed<T, WithPlugi
     fn plugin(&self) -> &Path {
         self.args.plugin()
     }
-    fn plugin_after_build(&self) -> bool {
-        self.args.plugin.after_build.is_present()
+    fn plugin_after_build(&self) -> Option<bool> {
+        self.args.plugin.after_build.as_ref().map(Flag::is_present)
     }
 }
 
@@ -331,10 +539,30 @@ where
     Resolver: Clone,
 {
     fn get_unique_ident(&self) -> Ident {
-        self._get_unique_ident(
-            ItemAttribute::<T, Resolver>::global_build_prefix(),
-            self.target.get_ident().unwrap(), // infallible
-        )
+        let prefix = ItemAttribute::<T, Resolver>::global_build_prefix();
+        if let Some(ident) = self.target.get_ident() {
+            return self._get_unique_ident(prefix, ident);
+        }
+        // targets resolved from `use` items or impl blocks can be full paths (with generics)
+        let path = self.target.to_token_stream().to_string();
+        let joined = path
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        self._get_unique_ident(prefix, &format_ident!("_{joined}"))
+    }
+}
+
+pub trait ItemAttributeUseExportKind {
+    fn use_export_kind(&self) -> Option<UseExportKind>;
+}
+
+impl<T, Resolver> ItemAttributeUseExportKind for ItemAttribute<T, Resolver>
+where
+    Resolver: IdentPathResolver,
+{
+    fn use_export_kind(&self) -> Option<UseExportKind> {
+        Resolver::USE_EXPORT_KIND
     }
 }
 
@@ -352,6 +580,19 @@ impl<T, Resolver> ItemAttributeInput for ItemAttribute<T, Resolver> {
     }
 }
 
+pub trait ItemAttributeArgsDebug {
+    fn args_debug(&self) -> &dyn std::fmt::Debug;
+}
+
+impl<T, Resolver> ItemAttributeArgsDebug for ItemAttribute<T, Resolver>
+where
+    T: std::fmt::Debug,
+{
+    fn args_debug(&self) -> &dyn std::fmt::Debug {
+        &self.args
+    }
+}
+
 pub trait ItemAttributeParse {
     fn from_attr_input_with_context(
         attr: TokenStream,
@@ -394,6 +635,8 @@ where
         };
         // Parse the attribute’s inner tokens as: Meta, Meta, ...
         let attr = maybe_rewrite_generics_angles(attr, "generics");
+        // `auto_event(traversal = &'static ChildOf)`
+        let attr = rewrite_type_value(attr, "traversal");
         let metas: Punctuated<NestedMeta, Token![,]> =
             Punctuated::<NestedMeta, Token![,]>::parse_terminated.parse2(attr)?;
 
@@ -416,22 +659,139 @@ where
 {
     fn concrete_paths(&self) -> syn::Result<Vec<syn::Path>> {
         let target = &self.target;
-        let target_params = self.input_item.type_param_idents()?;
-        // TODO: check if generics counts match?
+        let target_params = self.input_item.generic_params()?;
         if self.args.generics.generics().is_empty() {
             Ok(vec![target.clone()])
         } else {
+            let target_idents = target_params
+                .iter()
+                .filter_map(|param| match param {
+                    syn::GenericParam::Type(param) => Some(param.ident.clone()),
+                    syn::GenericParam::Const(param) => Some(param.ident.clone()),
+                    syn::GenericParam::Lifetime(_) => None,
+                })
+                .collect::<Vec<_>>();
             let mut paths = Vec::new();
             for generics in self.args.generics.generics() {
-                let resolved = generics.resolve_types(&target_params)?;
-                let path = if resolved.is_empty() {
-                    target.clone()
-                } else {
-                    syn::parse_quote!(#target::<#(#resolved),*>)
-                };
-                paths.push(path);
+                let resolved = generics.resolve_args(&target_params)?;
+                paths.push(apply_target_generics(target, &target_idents, resolved));
             }
             Ok(paths)
         }
     }
 }
+
+/// Substitutes params already referenced by `target` (e.g. `T` in `Foo::<T>::bar` for associated fns)
+/// and appends the remaining ones to the last segment
+fn apply_target_generics(
+    target: &syn::Path,
+    target_params: &[Ident],
+    resolved: Vec<syn::GenericArgument>,
+) -> syn::Path {
+    use std::collections::HashMap;
+    use syn::visit_mut::VisitMut;
+
+    struct Substitute<'a> {
+        args: &'a HashMap<&'a Ident, syn::GenericArgument>,
+        used: Vec<&'a Ident>,
+    }
+    impl<'a> Substitute<'a> {
+        fn param_of(&self, ty: &syn::Type) -> Option<(&'a Ident, &'a syn::GenericArgument)> {
+            match ty {
+                syn::Type::Path(type_path) if type_path.qself.is_none() => {
+                    let ident = type_path.path.get_ident()?;
+                    self.args.get_key_value(ident).map(|(param, arg)| (*param, arg))
+                }
+                _ => None,
+            }
+        }
+    }
+    impl VisitMut for Substitute<'_> {
+        fn visit_generic_argument_mut(&mut self, arg: &mut syn::GenericArgument) {
+            // const params are referenced like types (`Foo::<N>`) and may resolve to a value
+            let param = match arg {
+                syn::GenericArgument::Type(ty) => self.param_of(ty),
+                _ => None,
+            };
+            match param {
+                Some((param, replacement)) => {
+                    self.used.push(param);
+                    *arg = replacement.clone();
+                }
+                None => syn::visit_mut::visit_generic_argument_mut(self, arg),
+            }
+        }
+        fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+            match self.param_of(ty) {
+                Some((param, syn::GenericArgument::Type(replacement))) => {
+                    self.used.push(param);
+                    *ty = replacement.clone();
+                }
+                _ => syn::visit_mut::visit_type_mut(self, ty),
+            }
+        }
+    }
+
+    let args = target_params.iter().zip(resolved).collect::<HashMap<_, _>>();
+    let mut target = target.clone();
+    let mut substitute = Substitute { args: &args, used: vec![] };
+    substitute.visit_path_mut(&mut target);
+    let used = substitute.used;
+    let rest = target_params
+        .iter()
+        .filter(|param| !used.contains(param))
+        .map(|param| &args[param])
+        .collect::<Vec<_>>();
+    if rest.is_empty() { target } else { syn::parse_quote!(#target::<#(#rest),*>) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use quote::quote;
+    use syn::parse2;
+
+    #[xtest]
+    fn test_attribute_keys_match_darling() {
+        assert_eq!(keys_darling_disagrees_on(), vec![]);
+    }
+
+    #[xtest]
+    fn test_unknown_key_did_you_mean() {
+        let err = parse2::<Composed<ComponentArgs, WithPlugin, WithZeroOrManyGenerics>>(quote!(
+            plugin = Test,
+            reflects(Debug)
+        ))
+        .unwrap_err();
+        assert!(
+            err.to_string().starts_with(
+                "unknown key `reflects` for `auto_component` - did you mean `reflect`?"
+            ),
+            "{err}"
+        );
+    }
+
+    #[xtest]
+    fn test_unknown_key_of_other_attribute() {
+        let err = parse2::<Composed<ComponentArgs, WithPlugin, WithZeroOrManyGenerics>>(quote!(
+            plugin = Test,
+            init
+        ))
+        .unwrap_err();
+        assert!(err.to_string().contains("`init` is valid on"), "{err}");
+        assert!(err.to_string().contains("`auto_resource`"), "{err}");
+        assert!(err.to_string().contains("not `auto_component`"), "{err}");
+    }
+
+    #[xtest]
+    fn test_nested_unknown_key_is_kept() {
+        let err = parse2::<Composed<SystemArgs, WithPlugin, WithZeroOrManyGenerics>>(quote!(
+            plugin = Test,
+            schedule = Update,
+            config(in_sett = Foo)
+        ))
+        .unwrap_err();
+        assert!(err.to_string().starts_with("Unknown field: `in_sett`"), "{err}");
+    }
+}
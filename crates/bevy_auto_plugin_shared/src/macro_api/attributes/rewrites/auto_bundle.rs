@@ -0,0 +1,213 @@
+use crate::{
+    codegen::{
+        ExpandAttrs,
+        tokens,
+    },
+    macro_api::prelude::*,
+    syntax::{
+        ast::{
+            flag_or_list::FlagOrList,
+            flag_or_lit::FlagOrLit,
+        },
+        validated::non_empty_path::NonEmptyPath,
+    },
+    util::macros::impl_has_keys,
+};
+use darling::FromMeta;
+use proc_macro2::{
+    Ident,
+    TokenStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use std::marker::PhantomData;
+use syn::{
+    Lit,
+    spanned::Spanned,
+};
+
+#[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct BundleArgs {
+    pub derive: FlagOrList<NonEmptyPath>,
+    pub register: bool,
+    pub spawn: FlagOrLit,
+}
+
+impl AttributeIdent for BundleArgs {
+    const IDENT: &'static str = "auto_bundle";
+}
+
+impl_has_keys!(BundleArgs => ["derive", "register", "spawn"]);
+
+pub type IaBundle =
+    ItemAttribute<Composed<BundleArgs, WithPlugin, WithNoGenerics>, AllowStructOrEnum>;
+pub type BundleAttrExpandEmitter = AttrExpansionEmitter<IaBundle>;
+
+/// `#[bundle(ignore)]` fields aren't part of the bundle
+fn is_ignored(field: &syn::Field) -> bool {
+    field.attrs.iter().any(|attr| {
+        attr.path().is_ident("bundle")
+            && attr.parse_args::<Ident>().is_ok_and(|ident| ident == "ignore")
+    })
+}
+
+/// `HTTPBundle` -> `http_bundle`, a run of capitals is one word
+fn to_snake_case(ident: &Ident) -> String {
+    let chars = ident.to_string().chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+    for (ix, char) in chars.iter().enumerate() {
+        if char.is_uppercase() && ix > 0 {
+            let prev = chars[ix - 1];
+            let next_is_lower = chars.get(ix + 1).is_some_and(|next| next.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                snake.push('_');
+            }
+        }
+        snake.extend(char.to_lowercase());
+    }
+    snake
+}
+
+impl BundleAttrExpandEmitter {
+    fn item_struct(&self) -> syn::Result<syn::ItemStruct> {
+        let mut input_item = self.args.input_item.clone();
+        match input_item.ensure_ast()? {
+            syn::Item::Struct(item) => Ok(item.clone()),
+            item => Err(syn::Error::new(item.span(), "`auto_bundle` only supports structs")),
+        }
+    }
+
+    fn spawn_fn_ident(&self, ident: &Ident) -> syn::Result<Ident> {
+        match &self.args.args.base.spawn.lit {
+            None => Ok(format_ident!("spawn_{}", to_snake_case(ident))),
+            Some(Lit::Str(lit)) => lit.parse(),
+            Some(lit) => Err(syn::Error::new(lit.span(), "expected `spawn = \"fn_name\"`")),
+        }
+    }
+
+    /// Registers the fields that are reflected components on build, anything else (e.g. a nested
+    /// bundle) is skipped. `register_type` leaves types already registered as is.
+    fn register_fields(&self, item: &syn::ItemStruct) -> TokenStream {
+        let fn_ident = format_ident!("__auto_bundle_register_{}", to_snake_case(&item.ident));
+        let app = quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_app::App };
+        let ecs_root = crate::__private::paths::ecs::ecs_root_path();
+        let reflect_root = crate::__private::paths::reflect::reflect_root_path();
+        let field_tys =
+            item.fields.iter().filter(|field| !is_ignored(field)).map(|field| &field.ty);
+        let ItemAttribute { args, context, input_item, target, _resolver } = self.args.clone();
+        let run_on_build = tokens::auto_run_on_build(AttrEmitter::from_args(ItemAttribute {
+            args: Composed {
+                base: RunOnBuildArgs::default(),
+                plugin: args.plugin,
+                generics: WithZeroOrManyGenerics::default(),
+            },
+            context,
+            input_item,
+            target,
+            _resolver: PhantomData,
+        }));
+        quote! {
+            #[doc(hidden)]
+            #run_on_build
+            fn #fn_ident(app: &mut #app) {
+                // autoref specialization: `RegisterField` applies to reflected components,
+                // every other field type falls through to `SkipField`
+                struct Field<T>(::core::marker::PhantomData<T>);
+                trait RegisterField {
+                    fn register(&self, app: &mut #app);
+                }
+                impl<T: #ecs_root::component::Component + #reflect_root::GetTypeRegistration>
+                    RegisterField for Field<T>
+                {
+                    fn register(&self, app: &mut #app) {
+                        app.register_type::<T>();
+                    }
+                }
+                trait SkipField {
+                    fn register(&self, app: &mut #app);
+                }
+                impl<T> SkipField for &Field<T> {
+                    fn register(&self, _: &mut #app) {}
+                }
+                #((&Field::<#field_tys>(::core::marker::PhantomData)).register(app);)*
+            }
+        }
+    }
+
+    /// `Commands` extension trait with a `spawn_<name>(bundle)` method
+    fn spawn_helper(&self, item: &syn::ItemStruct) -> syn::Result<TokenStream> {
+        let ecs_root = crate::__private::paths::ecs::ecs_root_path();
+        let ident = &item.ident;
+        let vis = &item.vis;
+        let trait_ident = format_ident!("{ident}SpawnExt");
+        let fn_ident = self.spawn_fn_ident(ident)?;
+        let trait_doc = format!("`Commands` extension generated by `auto_bundle` for [`{ident}`]");
+        let fn_doc = format!("Spawns a [`{ident}`]");
+        Ok(quote! {
+            #[doc = #trait_doc]
+            #vis trait #trait_ident {
+                #[doc = #fn_doc]
+                fn #fn_ident(&mut self, bundle: #ident) -> #ecs_root::system::EntityCommands<'_>;
+            }
+            impl #trait_ident for #ecs_root::system::Commands<'_, '_> {
+                fn #fn_ident(&mut self, bundle: #ident) -> #ecs_root::system::EntityCommands<'_> {
+                    self.spawn(bundle)
+                }
+            }
+        })
+    }
+}
+
+impl AttrExpansionEmitterToExpandAttr for BundleAttrExpandEmitter {
+    fn validate(&self) -> syn::Result<()> {
+        let args = &self.args.args.base;
+        let item = self.item_struct()?;
+        if (args.register || args.spawn.present) && !item.generics.params.is_empty() {
+            return Err(syn::Error::new(
+                item.generics.span(),
+                "`register` and `spawn` are not supported for generic bundles",
+            ));
+        }
+        self.spawn_fn_ident(&item.ident)?;
+        Ok(())
+    }
+    fn to_expand_attrs(&self, expand_attrs: &mut ExpandAttrs) {
+        let args = &self.args.args.base;
+        if args.derive.present {
+            expand_attrs.attrs.push(tokens::derive_bundle(&args.derive.items));
+        }
+        // `validate` already rejected anything that isn't a struct
+        let Ok(item) = self.item_struct() else {
+            return;
+        };
+        if args.register {
+            expand_attrs.items.push(self.register_fields(&item));
+        }
+        if args.spawn.present {
+            match self.spawn_helper(&item) {
+                Ok(tokens) => expand_attrs.items.push(tokens),
+                Err(err) => expand_attrs.items.push(err.to_compile_error()),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_to_snake_case() {
+        assert_eq!(to_snake_case(&format_ident!("PlayerBundle")), "player_bundle");
+        assert_eq!(to_snake_case(&format_ident!("Enemy")), "enemy");
+        assert_eq!(to_snake_case(&format_ident!("HTTPBundle")), "http_bundle");
+        assert_eq!(to_snake_case(&format_ident!("Player2Bundle")), "player2_bundle");
+    }
+}
@@ -6,16 +6,49 @@ use crate::{
     macro_api::prelude::*,
     syntax::{
         ast::{
+            expr_path_or_call::ExprPathOrCall,
             flag_or_list::FlagOrList,
             flag_or_lit::FlagOrLit,
+            with_span::WithSpan,
         },
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
-use proc_macro2::Ident;
-use syn::parse_quote;
+use proc_macro2::{
+    Ident,
+    TokenStream,
+};
+use quote::{
+    ToTokens,
+    format_ident,
+    quote,
+};
+use std::{
+    collections::HashSet,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    marker::PhantomData,
+};
+use syn::{
+    Expr,
+    Meta,
+    Token,
+    parse::{
+        Parse,
+        ParseStream,
+        Parser,
+    },
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
@@ -24,12 +57,201 @@ pub struct ComponentArgs {
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
     pub auto_name: FlagOrLit,
+    pub storage: Option<ComponentStorage>,
+    pub immutable: bool,
+    pub require: Option<ComponentRequires>,
+    pub on_add: Option<ComponentHook>,
+    pub on_insert: Option<ComponentHook>,
+    pub on_replace: Option<ComponentHook>,
+    pub on_remove: Option<ComponentHook>,
+    pub on_despawn: Option<ComponentHook>,
+    pub relationship: Option<WithSpan<ComponentRelationship>>,
+    pub relationship_target: Option<WithSpan<ComponentRelationshipTarget>>,
+    pub clone_behavior: Option<WithSpan<ComponentCloneBehavior>>,
+}
+
+/// `storage = "Table" | "SparseSet"`
+#[derive(FromMeta, Debug, Copy, Clone, PartialEq, Hash)]
+pub enum ComponentStorage {
+    #[darling(rename = "Table")]
+    Table,
+    #[darling(rename = "SparseSet")]
+    SparseSet,
+}
+
+impl ToTokens for ComponentStorage {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            ComponentStorage::Table => quote!("Table"),
+            ComponentStorage::SparseSet => quote!("SparseSet"),
+        });
+    }
+}
+
+/// `on_add` (defaults to `Self::on_add`) or `on_add = path | call(..)`
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ComponentHook(pub Option<ExprPathOrCall>);
+
+impl FromMeta for ComponentHook {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(Self(None)),
+            _ => Ok(Self(Some(ExprPathOrCall::from_meta(meta)?))),
+        }
+    }
+}
+
+/// `clone_behavior = <expr>`
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ComponentCloneBehavior(pub Expr);
+
+impl FromMeta for ComponentCloneBehavior {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        Ok(Self(expr.clone()))
+    }
+}
+
+/// `relationship(relationship_target = Path)`
+#[derive(FromMeta, Debug, Clone, PartialEq, Hash)]
+pub struct ComponentRelationship {
+    pub relationship_target: syn::Path,
+}
+
+/// `relationship_target(relationship = Path, linked_spawn)`
+#[derive(FromMeta, Debug, Clone, PartialEq, Hash)]
+pub struct ComponentRelationshipTarget {
+    pub relationship: syn::Path,
+    #[darling(default)]
+    pub linked_spawn: bool,
+}
+
+/// A single `require(...)` entry, using the same forms as Bevy's `#[require(...)]`:
+/// `A`, `A(..)`, `A { .. }`, or `A = expr`
+#[derive(Debug, Clone)]
+pub struct ComponentRequire {
+    pub path: syn::Path,
+    pub init: TokenStream,
+}
+
+impl PartialEq for ComponentRequire {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_token_stream().to_string() == other.to_token_stream().to_string()
+    }
+}
+
+impl Hash for ComponentRequire {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.to_token_stream().to_string().hash(state);
+    }
+}
+
+impl Parse for ComponentRequire {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let init = if input.peek(Token![=]) {
+            let eq: Token![=] = input.parse()?;
+            let expr: Expr = input.parse()?;
+            quote! { #eq #expr }
+        } else if input.peek(syn::token::Paren) || input.peek(syn::token::Brace) {
+            let group: proc_macro2::Group = input.parse()?;
+            group.to_token_stream()
+        } else {
+            TokenStream::new()
+        };
+        Ok(Self { path, init })
+    }
+}
+
+impl ToTokens for ComponentRequire {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+        self.init.to_tokens(tokens);
+    }
+}
+
+/// `require(A, B(..), C = expr)`
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct ComponentRequires(pub Vec<ComponentRequire>);
+
+impl FromMeta for ComponentRequires {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        let list = meta.require_list()?;
+        let parser = Punctuated::<ComponentRequire, Token![,]>::parse_terminated;
+        let requires = parser.parse2(list.tokens.clone())?;
+        if requires.is_empty() {
+            return Err(darling::Error::too_few_items(1).with_span(meta));
+        }
+        let mut seen = HashSet::new();
+        for require in requires.iter() {
+            if !seen.insert(require.path.to_token_stream().to_string()) {
+                return Err(darling::Error::custom(format!(
+                    "duplicate required component `{}`",
+                    require.path.to_token_stream()
+                ))
+                .with_span(&require.path));
+            }
+        }
+        Ok(Self(requires.into_iter().collect()))
+    }
+}
+
+impl ComponentArgs {
+    /// Entries for Bevy's `#[component(...)]` helper attribute
+    fn component_items(&self) -> Vec<TokenStream> {
+        let mut items = Vec::new();
+        if let Some(storage) = &self.storage {
+            items.push(quote! { storage = #storage });
+        }
+        if self.immutable {
+            items.push(quote! { immutable });
+        }
+        let hooks = [
+            ("on_add", &self.on_add),
+            ("on_insert", &self.on_insert),
+            ("on_replace", &self.on_replace),
+            ("on_remove", &self.on_remove),
+            ("on_despawn", &self.on_despawn),
+        ];
+        for (name, hook) in hooks {
+            let Some(ComponentHook(hook)) = hook else {
+                continue;
+            };
+            let name = Ident::new(name, proc_macro2::Span::call_site());
+            items.push(match hook {
+                Some(hook) => quote! { #name = #hook },
+                None => quote! { #name },
+            });
+        }
+        if let Some(clone_behavior) = &self.clone_behavior {
+            let expr = &clone_behavior.0;
+            items.push(quote! { clone_behavior = #expr });
+        }
+        items
+    }
 }
 
 impl AttributeIdent for ComponentArgs {
     const IDENT: &'static str = "auto_component";
 }
 
+impl_has_keys!(ComponentArgs => [
+    "derive",
+    "reflect",
+    "register",
+    "auto_name",
+    "storage",
+    "immutable",
+    "require",
+    "on_add",
+    "on_insert",
+    "on_replace",
+    "on_remove",
+    "on_despawn",
+    "relationship",
+    "relationship_target",
+    "clone_behavior",
+]);
+
 impl From<&ComponentArgs> for RegisterTypeArgs {
     fn from(_: &ComponentArgs) -> Self {
         Self {}
@@ -46,7 +268,84 @@ pub type IaComponent =
     ItemAttribute<Composed<ComponentArgs, WithPlugin, WithZeroOrManyGenerics>, AllowStructOrEnum>;
 pub type ComponentAttrExpandEmitter = AttrExpansionEmitter<IaComponent>;
 
+/// `relationship` and `relationship_target` store the related entities in a field of the struct
+fn has_fields(item: &syn::Item) -> bool {
+    matches!(item, syn::Item::Struct(item) if !item.fields.is_empty())
+}
+
+/// Prefix of the build fn a bare `reflect` registers the plugin's reflect defaults with
+pub const REFLECT_DEFAULTS_FN_PREFIX: &str = "__auto_component_reflect_defaults_";
+
+impl ComponentAttrExpandEmitter {
+    /// A bare `reflect` registers the plugin's `defaults(component(reflect(...)))` on build
+    fn reflect_defaults(&self) -> syn::Result<TokenStream> {
+        let mut input_item = self.args.input_item.clone();
+        let (ident, generics) = match input_item.ensure_ast()? {
+            syn::Item::Struct(item) => (item.ident.clone(), item.generics.clone()),
+            syn::Item::Enum(item) => (item.ident.clone(), item.generics.clone()),
+            item => return Err(syn::Error::new(item.span(), "expected a struct or enum")),
+        };
+        let fn_ident = format_ident!("{REFLECT_DEFAULTS_FN_PREFIX}{ident}");
+        let plugin = self.args.args.plugin();
+        let registry =
+            quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+        where_clause.predicates.push(parse_quote! {
+            #plugin: #registry::AutoPluginComponentReflect<#ident #ty_generics>
+        });
+        let ItemAttribute { args, context, input_item, target, _resolver } = self.args.clone();
+        let run_on_build = tokens::auto_run_on_build(AttrEmitter::from_args(ItemAttribute {
+            args: Composed {
+                base: RunOnBuildArgs::default(),
+                plugin: args.plugin,
+                generics: args.generics,
+            },
+            context,
+            input_item,
+            target,
+            _resolver: PhantomData,
+        }));
+        Ok(quote! {
+            #[doc(hidden)]
+            #[allow(non_snake_case)]
+            #run_on_build
+            fn #fn_ident #impl_generics (app: &mut #registry::bevy_app::App) #where_clause {
+                <#plugin as #registry::AutoPluginComponentReflect<#ident #ty_generics>>::register_component_reflect(app);
+            }
+        })
+    }
+}
+
 impl AttrExpansionEmitterToExpandAttr for ComponentAttrExpandEmitter {
+    fn validate(&self) -> syn::Result<()> {
+        let args = &self.args.args.base;
+        let mut input_item = self.args.input_item.clone();
+        let item = input_item.ensure_ast()?;
+        if let Some(relationship) = &args.relationship
+            && !has_fields(item)
+        {
+            return Err(syn::Error::new(
+                relationship.span(),
+                "`relationship` requires a struct with a field holding the related entity",
+            ));
+        }
+        if let Some(relationship_target) = &args.relationship_target {
+            if !has_fields(item) {
+                return Err(syn::Error::new(
+                    relationship_target.span(),
+                    "`relationship_target` requires a struct with a field holding the related entities",
+                ));
+            }
+            if let Some(clone_behavior) = &args.clone_behavior {
+                return Err(syn::Error::new(
+                    clone_behavior.span(),
+                    "`clone_behavior` can't be combined with `relationship_target`, relationship targets define their own clone behavior",
+                ));
+            }
+        }
+        Ok(())
+    }
     fn to_expand_attrs(&self, expand_attrs: &mut ExpandAttrs) {
         if self.args.args.base.derive.present {
             expand_attrs.attrs.push(tokens::derive_component(&self.args.args.base.derive.items));
@@ -58,7 +357,13 @@ impl AttrExpansionEmitterToExpandAttr for ComponentAttrExpandEmitter {
             let component_ident: Ident = parse_quote!(Component);
             let items =
                 std::iter::once(&component_ident).chain(self.args.args.base.reflect.items.iter());
-            expand_attrs.append(tokens::reflect(items))
+            expand_attrs.append(tokens::reflect(items));
+            if self.args.args.base.reflect.items.is_empty() {
+                match self.reflect_defaults() {
+                    Ok(tokens) => expand_attrs.items.push(tokens),
+                    Err(err) => expand_attrs.items.push(err.to_compile_error()),
+                }
+            }
         }
         if self.args.args.base.register {
             expand_attrs.attrs.push(tokens::auto_register_type(self.into()));
@@ -66,6 +371,21 @@ impl AttrExpansionEmitterToExpandAttr for ComponentAttrExpandEmitter {
         if self.args.args.base.auto_name.present {
             expand_attrs.attrs.push(tokens::auto_name(self.into()));
         }
+        let component_items = self.args.args.base.component_items();
+        if !component_items.is_empty() {
+            expand_attrs.attrs.push(tokens::component(component_items));
+        }
+        if let Some(require) = &self.args.args.base.require {
+            expand_attrs.attrs.push(tokens::require(&require.0));
+        }
+        if let Some(relationship) = &self.args.args.base.relationship {
+            expand_attrs.attrs.push(tokens::relationship(&relationship.relationship_target));
+        }
+        if let Some(target) = &self.args.args.base.relationship_target {
+            expand_attrs
+                .attrs
+                .push(tokens::relationship_target(&target.relationship, target.linked_spawn));
+        }
     }
 }
 
@@ -5,10 +5,16 @@ use crate::{
     },
     macro_api::prelude::*,
     syntax::{
-        ast::flag_or_list::FlagOrList,
+        ast::{
+            flag_or_list::FlagOrList,
+            with_span::WithSpan,
+        },
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
 use proc_macro2::{
@@ -19,6 +25,8 @@ use quote::{
     ToTokens,
     quote,
 };
+use std::marker::PhantomData;
+use syn::parse_quote;
 
 #[derive(FromMeta, Default, Debug, Copy, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
@@ -50,12 +58,64 @@ pub struct EventArgs {
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
     pub target: EventTarget,
+    pub propagate: WithSpan<bool>,
+    pub auto_propagate: WithSpan<bool>,
+    pub traversal: Option<WithSpan<EventTraversal>>,
+    pub observer: Option<syn::Path>,
+}
+
+/// `traversal = &'static ChildOf` (rewritten to `traversal(&'static ChildOf)` before parsing)
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct EventTraversal(pub syn::Type);
+
+impl FromMeta for EventTraversal {
+    fn from_list(items: &[darling::ast::NestedMeta]) -> darling::Result<Self> {
+        let tokens = quote! { #(#items)* };
+        Ok(Self(syn::parse2(tokens)?))
+    }
+    fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+        match item {
+            syn::Meta::List(list) => Ok(Self(list.parse_args()?)),
+            _ => {
+                Err(darling::Error::unsupported_format("expected `traversal = Type`")
+                    .with_span(item))
+            }
+        }
+    }
+}
+
+impl EventArgs {
+    /// Entries for Bevy's `#[entity_event(...)]` helper attribute
+    fn entity_event_items(&self) -> Vec<TokenStream> {
+        let mut items = Vec::new();
+        if let Some(traversal) = &self.traversal {
+            let traversal = &traversal.0;
+            items.push(quote! { propagate = #traversal });
+        } else if *self.propagate {
+            items.push(quote! { propagate });
+        }
+        if *self.auto_propagate {
+            items.push(quote! { auto_propagate });
+        }
+        items
+    }
 }
 
 impl AttributeIdent for EventArgs {
     const IDENT: &'static str = "auto_event";
 }
 
+impl_has_keys!(EventArgs => [
+    "derive",
+    "reflect",
+    "register",
+    "target",
+    "propagate",
+    "auto_propagate",
+    "traversal",
+    "observer",
+]);
+
 impl<'a> From<&'a EventArgs> for RegisterTypeArgs {
     fn from(_: &'a EventArgs) -> Self {
         Self {}
@@ -65,7 +125,41 @@ impl<'a> From<&'a EventArgs> for RegisterTypeArgs {
 pub type IaEvent =
     ItemAttribute<Composed<EventArgs, WithPlugin, WithZeroOrManyGenerics>, AllowStructOrEnum>;
 pub type EventAttrExpandEmitter = AttrExpansionEmitter<IaEvent>;
+impl EventAttrExpandEmitter {
+    fn add_observer_emitter(&self) -> AddObserverAttrEmitter {
+        let ItemAttribute { args, context, input_item, target, _resolver } = self.args.clone();
+        AddObserverAttrEmitter::from_args(ItemAttribute {
+            args: Composed {
+                base: AddObserverArgs::default(),
+                plugin: args.plugin,
+                generics: WithZeroOrManyGenerics::default(),
+            },
+            context,
+            input_item,
+            target,
+            _resolver: PhantomData,
+        })
+    }
+}
+
 impl AttrExpansionEmitterToExpandAttr for EventAttrExpandEmitter {
+    fn validate(&self) -> syn::Result<()> {
+        let args = &self.args.args.base;
+        if matches!(args.target, EventTarget::Entity) {
+            return Ok(());
+        }
+        let entity_only = [
+            ("propagate", (*args.propagate).then(|| args.propagate.span())),
+            ("auto_propagate", (*args.auto_propagate).then(|| args.auto_propagate.span())),
+            ("traversal", args.traversal.as_ref().map(WithSpan::span)),
+        ];
+        for (name, span) in entity_only {
+            if let Some(span) = span {
+                return Err(syn::Error::new(span, format!("`{name}` requires `target(entity)`")));
+            }
+        }
+        Ok(())
+    }
     fn to_expand_attrs(&self, expand_attrs: &mut ExpandAttrs) {
         if self.args.args.base.derive.present {
             if matches!(self.args.args.base.target, EventTarget::Global) {
@@ -81,11 +175,25 @@ impl AttrExpansionEmitterToExpandAttr for EventAttrExpandEmitter {
             if self.args.args.base.derive.present {
                 expand_attrs.attrs.push(tokens::derive_reflect());
             }
-            expand_attrs.append(tokens::reflect(&self.args.args.base.reflect.items))
+            let event_ident: Ident = parse_quote!(Event);
+            let items =
+                std::iter::once(&event_ident).chain(self.args.args.base.reflect.items.iter());
+            expand_attrs.append(tokens::reflect(items))
         }
         if self.args.args.base.register {
             expand_attrs.attrs.push(tokens::auto_register_type(self.into()));
         }
+        let entity_event_items = self.args.args.base.entity_event_items();
+        if !entity_event_items.is_empty() {
+            expand_attrs.attrs.push(tokens::entity_event(entity_event_items));
+        }
+        if let Some(observer) = &self.args.args.base.observer {
+            let add_observer = tokens::auto_add_observer(self.add_observer_emitter());
+            expand_attrs.items.push(quote! {
+                #add_observer
+                use #observer as _;
+            });
+        }
     }
 }
 
@@ -3,12 +3,18 @@ use crate::{
         ExpandAttrs,
         tokens,
     },
-    macro_api::prelude::*,
+    macro_api::{
+        message_update::MessageUpdateArgs,
+        prelude::*,
+    },
     syntax::{
         ast::flag_or_list::FlagOrList,
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
 use proc_macro2::Ident;
@@ -19,12 +25,16 @@ pub struct MessageArgs {
     pub derive: FlagOrList<NonEmptyPath>,
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
+    #[darling(flatten)]
+    pub update: MessageUpdateArgs,
 }
 
 impl AttributeIdent for MessageArgs {
     const IDENT: &'static str = "auto_message";
 }
 
+impl_has_keys!(MessageArgs => ["derive", "reflect", "register", "update_in", "manual_update"]);
+
 impl<'a> From<&'a MessageArgs> for RegisterTypeArgs {
     fn from(_: &'a MessageArgs) -> Self {
         Self {}
@@ -32,8 +42,14 @@ impl<'a> From<&'a MessageArgs> for RegisterTypeArgs {
 }
 
 impl<'a> From<&'a MessageArgs> for AddMessageArgs {
-    fn from(_: &'a MessageArgs) -> Self {
-        Self {}
+    fn from(value: &'a MessageArgs) -> Self {
+        Self { update: value.update.clone() }
+    }
+}
+
+impl From<MessageArgs> for AddMessageArgs {
+    fn from(value: MessageArgs) -> Self {
+        Self::from(&value)
     }
 }
 
@@ -61,4 +77,4 @@ impl AttrExpansionEmitterToExpandAttr for MessageAttrExpandEmitter {
     }
 }
 
-impl_from_default!(MessageArgs => (RegisterTypeArgs, AddMessageArgs));
+impl_from_default!(MessageArgs => (RegisterTypeArgs));
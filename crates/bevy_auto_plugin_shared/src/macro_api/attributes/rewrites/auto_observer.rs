@@ -3,19 +3,27 @@ use crate::{
         ExpandAttrs,
         tokens,
     },
-    macro_api::prelude::*,
-    util::macros::impl_from_default,
+    macro_api::{
+        observer_config::ObserverConfigArgs,
+        prelude::*,
+    },
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
-pub struct ObserverArgs {}
+pub struct ObserverArgs {
+    #[darling(flatten)]
+    pub config: ObserverConfigArgs,
+}
 
 impl AttributeIdent for ObserverArgs {
     const IDENT: &'static str = "auto_observer";
 }
 
+impl_has_keys!(ObserverArgs => ["on_entity_with", "name", "run_if"]);
+
 impl<'a> From<&'a ObserverArgs> for RegisterTypeArgs {
     fn from(_: &'a ObserverArgs) -> Self {
         Self {}
@@ -23,8 +31,14 @@ impl<'a> From<&'a ObserverArgs> for RegisterTypeArgs {
 }
 
 impl<'a> From<&'a ObserverArgs> for AddObserverArgs {
-    fn from(_: &'a ObserverArgs) -> Self {
-        AddObserverArgs {}
+    fn from(value: &'a ObserverArgs) -> Self {
+        AddObserverArgs { config: value.config.clone() }
+    }
+}
+
+impl From<ObserverArgs> for AddObserverArgs {
+    fn from(value: ObserverArgs) -> Self {
+        Self::from(&value)
     }
 }
 
@@ -37,5 +51,3 @@ impl AttrExpansionEmitterToExpandAttr for ObserverAttrExpandEmitter {
         expand_attrs.attrs.push(tokens::auto_add_observer(self.into()));
     }
 }
-
-impl_from_default!(ObserverArgs => (AddObserverArgs));
@@ -0,0 +1,193 @@
+use crate::{
+    codegen::{
+        ExpandAttrs,
+        tokens,
+    },
+    macro_api::prelude::*,
+    syntax::{
+        ast::{
+            flag_or_list::FlagOrList,
+            with_span::WithSpan,
+        },
+        validated::non_empty_path::NonEmptyPath,
+    },
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
+};
+use darling::FromMeta;
+use proc_macro2::{
+    Ident,
+    TokenStream,
+};
+use quote::{
+    ToTokens,
+    quote,
+    quote_spanned,
+};
+use std::marker::PhantomData;
+use syn::{
+    parse_quote,
+    spanned::Spanned,
+};
+
+#[derive(FromMeta, Debug, Clone, PartialEq, Hash)]
+#[darling(derive_syn_parse)]
+pub struct RelationshipArgs {
+    pub target: syn::Path,
+    #[darling(default)]
+    pub linked_spawn: bool,
+    #[darling(default)]
+    pub generate_target: bool,
+    #[darling(default)]
+    pub derive: FlagOrList<NonEmptyPath>,
+    #[darling(default)]
+    pub reflect: FlagOrList<Ident>,
+    #[darling(default)]
+    pub register: bool,
+}
+
+impl AttributeIdent for RelationshipArgs {
+    const IDENT: &'static str = "auto_relationship";
+}
+
+impl_has_keys!(RelationshipArgs => [
+    "target",
+    "linked_spawn",
+    "generate_target",
+    "derive",
+    "reflect",
+    "register",
+]);
+
+impl<'a> From<&'a RelationshipArgs> for ComponentArgs {
+    fn from(value: &'a RelationshipArgs) -> Self {
+        Self {
+            derive: value.derive.clone(),
+            reflect: value.reflect.clone(),
+            register: value.register,
+            relationship: Some(WithSpan::new(
+                ComponentRelationship { relationship_target: value.target.clone() },
+                value.target.span(),
+            )),
+            ..Default::default()
+        }
+    }
+}
+
+pub type IaRelationship =
+    ItemAttribute<Composed<RelationshipArgs, WithPlugin, WithNoGenerics>, AllowStructOrEnum>;
+pub type RelationshipAttrExpandEmitter = AttrExpansionEmitter<IaRelationship>;
+
+impl RelationshipAttrExpandEmitter {
+    /// The source side is a regular `auto_component` with `relationship(...)` set
+    fn component_emitter(&self) -> ComponentAttrExpandEmitter {
+        let ItemAttribute { args, context, input_item, target, _resolver } = self.args.clone();
+        ComponentAttrExpandEmitter::from_item_attribute(ItemAttribute {
+            args: Composed {
+                base: ComponentArgs::from(&args.base),
+                plugin: args.plugin,
+                generics: WithZeroOrManyGenerics::default(),
+            },
+            context,
+            input_item,
+            target,
+            _resolver: PhantomData,
+        })
+    }
+
+    /// `#[relationship_target(...)]` struct paired with the input item
+    fn generated_target(&self, target_ident: &Ident) -> TokenStream {
+        let args = &self.args.args.base;
+        let component_emitter = self.component_emitter();
+        let source = &self.args.target;
+        let mut input_item = self.args.input_item.clone();
+        let vis = match input_item.ensure_ast() {
+            Ok(syn::Item::Struct(item)) => item.vis.to_token_stream(),
+            _ => TokenStream::new(),
+        };
+        let mut attrs = vec![tokens::derive_component([])];
+        if args.reflect.present {
+            attrs.push(tokens::derive_reflect());
+            let component_ident: Ident = parse_quote!(Component);
+            attrs.extend(tokens::reflect([&component_ident]).attrs);
+        }
+        if args.register {
+            attrs.push(tokens::auto_register_type((&component_emitter).into()));
+        }
+        attrs.push(tokens::relationship_target(source, args.linked_spawn));
+        let entity = tokens::entity_path();
+        quote! {
+            #(#attrs)*
+            #vis struct #target_ident(Vec<#entity>);
+        }
+    }
+
+    /// Registers the existing target through a `use` of it, it isn't the annotated item
+    fn registered_target(&self) -> TokenStream {
+        let register = tokens::auto_register_type((&self.component_emitter()).into());
+        let target = &self.args.args.base.target;
+        quote! {
+            #register
+            #[allow(unused_imports)]
+            use #target as _;
+        }
+    }
+
+    /// Fails to compile if the existing target isn't paired with the input item
+    fn target_assertions(&self) -> TokenStream {
+        let args = &self.args.args.base;
+        let source = &self.args.target;
+        let target = &args.target;
+        let target_name = target.to_token_stream().to_string().replace(' ', "");
+        let relationship_target = tokens::relationship_target_path();
+        let linked_spawn = args.linked_spawn;
+        let message = if linked_spawn {
+            format!(
+                "`{target_name}` must be declared with `linked_spawn` to match `auto_relationship`"
+            )
+        } else {
+            format!(
+                "`{target_name}` is declared with `linked_spawn` but `auto_relationship` is missing `linked_spawn`"
+            )
+        };
+        quote_spanned! { target.span() =>
+            const _: () = {
+                fn assert_relationship_target<T: #relationship_target<Relationship = #source>>() {}
+                let _ = assert_relationship_target::<#target>;
+                assert!(<#target as #relationship_target>::LINKED_SPAWN == #linked_spawn, #message);
+            };
+        }
+    }
+}
+
+impl AttrExpansionEmitterToExpandAttr for RelationshipAttrExpandEmitter {
+    fn validate(&self) -> syn::Result<()> {
+        let args = &self.args.args.base;
+        if args.generate_target && args.target.get_ident().is_none() {
+            return Err(syn::Error::new(
+                args.target.span(),
+                "`generate_target` requires `target` to be a plain identifier",
+            ));
+        }
+        self.component_emitter().validate()
+    }
+    fn to_expand_attrs(&self, expand_attrs: &mut ExpandAttrs) {
+        self.component_emitter().to_expand_attrs(expand_attrs);
+        let args = &self.args.args.base;
+        match args.target.get_ident() {
+            Some(target_ident) if args.generate_target => {
+                expand_attrs.items.push(self.generated_target(target_ident));
+            }
+            _ => {
+                expand_attrs.items.push(self.target_assertions());
+                if args.register {
+                    expand_attrs.items.push(self.registered_target());
+                }
+            }
+        }
+    }
+}
+
+impl_from_default!(RelationshipArgs => (RegisterTypeArgs));
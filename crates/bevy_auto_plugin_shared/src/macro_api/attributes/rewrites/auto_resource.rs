@@ -12,26 +12,103 @@ use crate::{
         },
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
 use proc_macro2::Ident;
-use syn::parse_quote;
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    LitStr,
+    parse_quote,
+};
+
+const LOAD_CONFLICT_ERR: &str =
+    "`load` and `env_prefix` insert the resource - remove `init` and `insert`";
 
 #[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
-#[darling(derive_syn_parse, default)]
+#[darling(derive_syn_parse, default, and_then = Self::validate)]
 pub struct ResourceArgs {
     pub derive: FlagOrList<NonEmptyPath>,
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
     pub init: bool,
     pub insert: Option<AnyExprList<AnyExprCallClosureMacroPath>>,
+    /// `.ron`, `.toml` or `.json` file deserialized when the plugin is built
+    pub load: Option<LitStr>,
+    /// overrides fields from `<PREFIX><FIELD>` env vars
+    pub env_prefix: Option<LitStr>,
+}
+
+impl ResourceArgs {
+    fn validate(self) -> darling::Result<Self> {
+        let load_span = match (&self.load, &self.env_prefix) {
+            (Some(lit), _) | (None, Some(lit)) => lit.span(),
+            (None, None) => return Ok(self),
+        };
+        if self.init || self.insert.is_some() {
+            return Err(darling::Error::custom(LOAD_CONFLICT_ERR).with_span(&load_span));
+        }
+        if let Some(load) = &self.load {
+            Self::parse_fn_ident(load)?;
+        }
+        Ok(self)
+    }
+
+    /// `_resource_config_parse_<ext>` macro, which checks the format's feature
+    fn parse_fn_ident(load: &LitStr) -> darling::Result<Ident> {
+        let path = load.value();
+        let extension = std::path::Path::new(&path).extension().and_then(|ext| ext.to_str());
+        match extension {
+            Some(ext @ ("ron" | "toml" | "json")) => {
+                Ok(format_ident!("_resource_config_parse_{ext}"))
+            }
+            _ => Err(darling::Error::custom("`load` expects a `.ron`, `.toml` or `.json` file")
+                .with_span(&load.span())),
+        }
+    }
+
+    /// `insert` expression for `load` / `env_prefix`
+    fn load_expr(&self) -> Option<AnyExprCallClosureMacroPath> {
+        if self.load.is_none() && self.env_prefix.is_none() {
+            return None;
+        }
+        let shared = quote! { ::bevy_auto_plugin::__private::shared };
+        let file = match &self.load {
+            Some(load) => {
+                // checked in `validate`
+                let parse_fn = Self::parse_fn_ident(load).ok()?;
+                quote! { ::core::option::Option::Some((#load, #shared::#parse_fn!())) }
+            }
+            None => quote! { ::core::option::Option::None },
+        };
+        let env_prefix = match &self.env_prefix {
+            Some(env_prefix) => quote! { ::core::option::Option::Some(#env_prefix) },
+            None => quote! { ::core::option::Option::None },
+        };
+        Some(parse_quote! { #shared::_resource_config_load!(#file, #env_prefix) })
+    }
 }
 
 impl AttributeIdent for ResourceArgs {
     const IDENT: &'static str = "auto_resource";
 }
 
+impl_has_keys!(ResourceArgs => [
+    "derive",
+    "reflect",
+    "register",
+    "init",
+    "insert",
+    "load",
+    "env_prefix",
+]);
+
 impl<'a> From<&'a ResourceArgs> for RegisterTypeArgs {
     fn from(_: &'a ResourceArgs) -> Self {
         Self {}
@@ -64,26 +141,31 @@ impl AttrExpansionEmitterToExpandAttr for ResourceAttrExpandEmitter {
         if self.args.args.base.register {
             expand_attrs.attrs.push(tokens::auto_register_type(self.into()));
         }
-        if self.args.args.base.init {
+        // the non generic `insert` below already initializes the resource
+        if self.args.args.base.init
+            && (self.args.args.base.insert.is_none()
+                || !self.args.args.generics.generics.is_empty())
+        {
             expand_attrs.attrs.push(tokens::auto_init_resource(self.into()));
         }
-        if let Some(insert) = &self.args.args.base.insert {
-            for item in insert.iter() {
-                let ia_insert_resource: IaInsertResource = self
-                    .args
-                    .clone()
-                    .into_zero_or_one_generic_target::<InsertResourceArgs>(
-                        |_| InsertResourceArgs::from_insert(item.clone()),
-                        |_| {
-                            // TODO: if we wanted to actually use the generics passed
-                            //  we could store an iterator before the for loop and call .next()
-                            Default::default()
-                        },
-                    )
-                    .into_resolver::<AllowStructOrEnumOrUse>();
-                let ia_insert_resource_emitter = AttrEmitter::from_args(ia_insert_resource);
-                expand_attrs.attrs.push(tokens::auto_insert_resource(ia_insert_resource_emitter));
-            }
+        // `load` / `env_prefix` are inserted like any other `insert` value
+        let load = self.args.args.base.load_expr();
+        let insert = self.args.args.base.insert.iter().flat_map(|insert| insert.iter().cloned());
+        for item in insert.chain(load) {
+            let ia_insert_resource: IaInsertResource = self
+                .args
+                .clone()
+                .into_zero_or_one_generic_target::<InsertResourceArgs>(
+                    |_| InsertResourceArgs::from_insert(item.clone()),
+                    |_| {
+                        // TODO: if we wanted to actually use the generics passed
+                        //  we could store an iterator before the for loop and call .next()
+                        Default::default()
+                    },
+                )
+                .into_resolver::<AllowStructOrEnumOrUse>();
+            let ia_insert_resource_emitter = AttrEmitter::from_args(ia_insert_resource);
+            expand_attrs.attrs.push(tokens::auto_insert_resource(ia_insert_resource_emitter));
         }
     }
 }
@@ -3,12 +3,18 @@ use crate::{
         ExpandAttrs,
         tokens,
     },
-    macro_api::prelude::*,
+    macro_api::{
+        prelude::*,
+        state_transitions::StateTransitionArgs,
+    },
     syntax::{
         ast::flag_or_list::FlagOrList,
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
 use proc_macro2::Ident;
@@ -20,12 +26,27 @@ pub struct StatesArgs {
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
     pub init: bool,
+    pub initial: Option<syn::Expr>,
+    pub scoped_entities: bool,
+    #[darling(flatten)]
+    pub transitions: StateTransitionArgs,
 }
 
 impl AttributeIdent for StatesArgs {
     const IDENT: &'static str = "auto_states";
 }
 
+impl_has_keys!(StatesArgs => [
+    "derive",
+    "reflect",
+    "register",
+    "init",
+    "initial",
+    "scoped_entities",
+    "log_transitions",
+    "allow_same_state_transitions",
+]);
+
 impl<'a> From<&'a StatesArgs> for RegisterTypeArgs {
     fn from(_value: &'a StatesArgs) -> Self {
         Self::default()
@@ -39,8 +60,22 @@ impl<'a> From<&'a StatesArgs> for RegisterStateTypeArgs {
 }
 
 impl<'a> From<&'a StatesArgs> for InitStateArgs {
-    fn from(_value: &'a StatesArgs) -> Self {
-        Self::default()
+    fn from(value: &'a StatesArgs) -> Self {
+        Self { initial: value.initial.clone(), transitions: value.transitions.clone() }
+    }
+}
+
+impl From<StatesArgs> for InitStateArgs {
+    fn from(value: StatesArgs) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl StatesArgs {
+    /// Bevy sets up state-scoped entities when the state is initialized,
+    /// so every initialization option implies `init`
+    fn should_init(&self) -> bool {
+        self.init || self.initial.is_some() || self.scoped_entities || !self.transitions.is_empty()
     }
 }
 pub type IaState =
@@ -61,10 +96,10 @@ impl AttrExpansionEmitterToExpandAttr for StateAttrExpandEmitter {
             expand_attrs.attrs.push(tokens::auto_register_type(self.into()));
             expand_attrs.attrs.push(tokens::auto_register_state_type(self.into()));
         }
-        if self.args.args.base.init {
+        if self.args.args.base.should_init() {
             expand_attrs.attrs.push(tokens::auto_init_states(self.into()));
         }
     }
 }
 
-impl_from_default!(StatesArgs => (RegisterTypeArgs, RegisterStateTypeArgs, InitStateArgs));
+impl_from_default!(StatesArgs => (RegisterTypeArgs, RegisterStateTypeArgs));
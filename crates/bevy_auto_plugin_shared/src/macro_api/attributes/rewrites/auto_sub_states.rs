@@ -3,12 +3,18 @@ use crate::{
         ExpandAttrs,
         tokens,
     },
-    macro_api::prelude::*,
+    macro_api::{
+        prelude::*,
+        state_transitions::StateTransitionArgs,
+    },
     syntax::{
         ast::flag_or_list::FlagOrList,
         validated::non_empty_path::NonEmptyPath,
     },
-    util::macros::impl_from_default,
+    util::macros::{
+        impl_from_default,
+        impl_has_keys,
+    },
 };
 use darling::FromMeta;
 use proc_macro2::Ident;
@@ -20,12 +26,25 @@ pub struct SubStatesArgs {
     pub reflect: FlagOrList<Ident>,
     pub register: bool,
     pub init: bool,
+    pub scoped_entities: bool,
+    #[darling(flatten)]
+    pub transitions: StateTransitionArgs,
 }
 
 impl AttributeIdent for SubStatesArgs {
     const IDENT: &'static str = "auto_sub_states";
 }
 
+impl_has_keys!(SubStatesArgs => [
+    "derive",
+    "reflect",
+    "register",
+    "init",
+    "scoped_entities",
+    "log_transitions",
+    "allow_same_state_transitions",
+]);
+
 impl<'a> From<&'a SubStatesArgs> for RegisterTypeArgs {
     fn from(_value: &'a SubStatesArgs) -> Self {
         Self::default()
@@ -39,8 +58,22 @@ impl<'a> From<&'a SubStatesArgs> for RegisterStateTypeArgs {
 }
 
 impl<'a> From<&'a SubStatesArgs> for InitSubStateArgs {
-    fn from(_value: &'a SubStatesArgs) -> Self {
-        Self::default()
+    fn from(value: &'a SubStatesArgs) -> Self {
+        Self { transitions: value.transitions.clone() }
+    }
+}
+
+impl From<SubStatesArgs> for InitSubStateArgs {
+    fn from(value: SubStatesArgs) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl SubStatesArgs {
+    /// Bevy sets up state-scoped entities when the state is initialized,
+    /// so every initialization option implies `init`
+    fn should_init(&self) -> bool {
+        self.init || self.scoped_entities || !self.transitions.is_empty()
     }
 }
 
@@ -64,10 +97,10 @@ impl AttrExpansionEmitterToExpandAttr for SubStateAttrExpandEmitter {
             expand_attrs.attrs.push(tokens::auto_register_type(self.into()));
             expand_attrs.attrs.push(tokens::auto_register_state_type(self.into()));
         }
-        if self.args.args.base.init {
+        if self.args.args.base.should_init() {
             expand_attrs.attrs.push(tokens::auto_init_sub_states(self.into()));
         }
     }
 }
 
-impl_from_default!(SubStatesArgs => (RegisterTypeArgs, RegisterStateTypeArgs, InitSubStateArgs));
+impl_from_default!(SubStatesArgs => (RegisterTypeArgs, RegisterStateTypeArgs));
@@ -11,6 +11,7 @@ use crate::{
         any_expr::AnyExprCallClosureMacroPath,
         any_expr_list::AnyExprList,
     },
+    util::macros::impl_has_keys,
 };
 use darling::FromMeta;
 
@@ -27,6 +28,17 @@ impl AttributeIdent for SystemArgs {
     const IDENT: &'static str = "auto_system";
 }
 
+impl_has_keys!(SystemArgs => [
+    "schedule",
+    "fixed",
+    "startup",
+    "update",
+    "post_update",
+    "in_state",
+    "config",
+    "pipe_in",
+]);
+
 impl<'a> From<&'a SystemArgs> for RegisterTypeArgs {
     fn from(_: &'a SystemArgs) -> Self {
         Self {}
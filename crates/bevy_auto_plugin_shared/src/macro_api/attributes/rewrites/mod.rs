@@ -1,7 +1,9 @@
+mod auto_bundle;
 mod auto_component;
 mod auto_event;
 mod auto_message;
 mod auto_observer;
+mod auto_relationship;
 mod auto_resource;
 mod auto_states;
 mod auto_sub_states;
@@ -9,10 +11,12 @@ mod auto_system;
 
 pub mod prelude {
     pub use super::*;
+    pub use auto_bundle::*;
     pub use auto_component::*;
     pub use auto_event::*;
     pub use auto_message::*;
     pub use auto_observer::*;
+    pub use auto_relationship::*;
     pub use auto_resource::*;
     pub use auto_states::*;
     pub use auto_sub_states::*;
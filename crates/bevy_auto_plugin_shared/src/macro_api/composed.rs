@@ -1,6 +1,9 @@
 use crate::{
     macro_api::prelude::*,
-    syntax::ast::type_list::TypeList,
+    syntax::{
+        ast::type_list::TypeList,
+        diagnostic::unknown_key::unknown_key_error,
+    },
 };
 use darling::{
     FromMeta,
@@ -46,7 +49,7 @@ where
 
 impl<CBase, MPlugin, MGenerics> FromMeta for Composed<CBase, MPlugin, MGenerics>
 where
-    CBase: FromMeta,
+    CBase: FromMeta + HasKeys,
     MPlugin: Mixin,
     MGenerics: Mixin,
 {
@@ -59,6 +62,8 @@ where
         let mut has_plugin_key = false;
         let mut generics_bucket = Vec::<NestedMeta>::new();
         let mut base_bucket = Vec::<NestedMeta>::new();
+        let mut unknown_keys = Vec::<darling::Error>::new();
+        let valid_keys = [CBase::keys(), MPlugin::keys(), MGenerics::keys()].concat();
 
         for nm in items {
             let key_opt = match &nm {
@@ -92,8 +97,20 @@ where
                 false
             };
 
-            if !routed {
-                base_bucket.push(nm.clone());
+            if routed {
+                continue;
+            }
+            match key_opt {
+                Some(key) if !CBase::keys().contains(&key.as_str()) => {
+                    unknown_keys.push(unknown_key_error(
+                        nm,
+                        &key,
+                        CBase::attribute(),
+                        &valid_keys,
+                        &attribute_keys(),
+                    ));
+                }
+                _ => base_bucket.push(nm.clone()),
             }
         }
 
@@ -106,7 +123,12 @@ where
         }
 
         // Parse each bucket
-        let base = CBase::from_list(&base_bucket)?;
+        let base = CBase::from_list(&base_bucket);
+        if !unknown_keys.is_empty() {
+            unknown_keys.extend(base.err());
+            return Err(darling::Error::multiple(unknown_keys));
+        }
+        let base = base?;
         let plugin = MPlugin::from_list(&plugin_bucket)?;
         let generics = MGenerics::from_list(&generics_bucket)?;
 
@@ -116,7 +138,7 @@ where
 
 impl<CBase, MPlugin, MGenerics> Parse for Composed<CBase, MPlugin, MGenerics>
 where
-    CBase: FromMeta,
+    CBase: FromMeta + HasKeys,
     MPlugin: Mixin,
     MGenerics: Mixin,
 {
@@ -167,11 +189,11 @@ where
                 .generics()
                 .iter()
                 .map(|g| {
-                    let types = g.types_in_declared_order();
-                    if types.is_empty() {
+                    let args = g.args_in_declared_order();
+                    if args.is_empty() {
                         target.clone()
                     } else {
-                        parse_quote!(#target :: < #(#types),* >)
+                        parse_quote!(#target :: < #(#args),* >)
                     }
                 })
                 .collect()
@@ -52,7 +52,7 @@ impl Default for MacroPaths {
             emit_run_on_build_macro:            parse_quote!(  ::bevy_auto_plugin::prelude::auto_run_on_build          ),
             emit_auto_name_macro:               parse_quote!(  ::bevy_auto_plugin::prelude::auto_name                  ),
             emit_configure_system_set_macro:    parse_quote!(  ::bevy_auto_plugin::prelude::auto_configure_system_set  ),
-            emit_auto_plugin_hook_macro:      parse_quote!(  ::bevy_auto_plugin::prelude::auto_plugin_hook         ),
+            emit_auto_plugin_hook_macro:        parse_quote!(  ::bevy_auto_plugin::prelude::auto_plugin_build_hook     ),
         }
     }
 }
@@ -2,6 +2,7 @@ use crate::{
     __private::auto_plugin_registry::{
         _plugin_entry_block,
         _plugin_entry_block_after_build,
+        _plugin_entry_block_plugin_default,
     },
     macro_api::prelude::*,
 };
@@ -35,7 +36,7 @@ impl<T> AppMutationEmitter<T> {
             + ItemAttributeContext
             + ItemAttributeUniqueIdent
             + ItemAttributePlugin,
-        AppMutationEmitter<T>: ToTokens,
+        AppMutationEmitter<T>: ToTokens + EmitAppMutationTokens,
     {
         let ident = self.args.target().to_token_stream();
         let app_param = &self.app_param;
@@ -48,11 +49,12 @@ impl<T> AppMutationEmitter<T> {
         });
         // required for generics
         let unique_ident = format_ident!("{unique_ident}");
-        let output = if use_after_build {
-            _plugin_entry_block_after_build(&unique_ident, &plugin, &expr)
-        } else {
-            _plugin_entry_block(&unique_ident, &plugin, &expr)
+        let mut output = match use_after_build {
+            Some(true) => _plugin_entry_block_after_build(&unique_ident, &plugin, &expr),
+            Some(false) => _plugin_entry_block(&unique_ident, &plugin, &expr),
+            None => _plugin_entry_block_plugin_default(&unique_ident, &plugin, &expr),
         };
+        output.extend(self.to_registry_items(&unique_ident)?);
         assert!(!output.is_empty(), "No plugin entry points were generated for ident: {ident}");
         Ok(output)
     }
@@ -63,6 +65,10 @@ pub trait EmitAppMutationTokens {
     fn post_process_inner_item(&mut self) -> Result<(), (InputItem, syn::Error)> {
         Ok(())
     }
+    /// Registry statics emitted next to the entry, e.g. the dependency edges of `auto_add_plugin`
+    fn to_registry_items(&self, _unique_ident: &syn::Ident) -> syn::Result<TokenStream> {
+        Ok(TokenStream::new())
+    }
     fn to_app_mutation_token_stream(&self, app_param: &syn::Ident) -> TokenStream {
         let mut tokens = TokenStream::new();
         if let Err(err) = self.to_app_mutation_tokens(&mut tokens, app_param) {
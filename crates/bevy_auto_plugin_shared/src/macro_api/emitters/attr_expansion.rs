@@ -22,6 +22,10 @@ where
 }
 
 pub trait AttrExpansionEmitterToExpandAttr {
+    /// Checks the args against the input item before any attrs are emitted
+    fn validate(&self) -> syn::Result<()> {
+        Ok(())
+    }
     fn to_expand_attrs(&self, expand_attrs: &mut ExpandAttrs);
 }
 
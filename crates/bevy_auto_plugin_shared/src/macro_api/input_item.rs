@@ -116,62 +116,72 @@ impl InputItem {
                 .ok_or_else(|| syn::Error::new(item.span(), "expected item to have an ident"))
         })
     }
-    pub fn type_param_idents(&self) -> syn::Result<Vec<syn::Ident>> {
+    /// Type and const params of the item in declared order (lifetimes are skipped)
+    pub fn generic_params(&self) -> syn::Result<Vec<syn::GenericParam>> {
         let mut cloned = self.clone();
         let item = cloned.ensure_ast()?;
-        let idents = match item {
-            syn::Item::Const(_) => vec![],
-            syn::Item::Enum(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::ExternCrate(_) => vec![],
-            syn::Item::Fn(item) => {
-                item.sig.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::ForeignMod(_) => vec![],
-            syn::Item::Impl(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::Macro(_) => vec![],
-            syn::Item::Mod(_) => vec![],
-            syn::Item::Static(_) => vec![],
-            syn::Item::Struct(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::Trait(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::TraitAlias(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::Type(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::Union(item) => {
-                item.generics.type_params().map(|tp| tp.ident.clone()).collect()
-            }
-            syn::Item::Use(_) => vec![],
-            syn::Item::Verbatim(_) => vec![],
-            _ => vec![],
+        let generics = match item {
+            syn::Item::Enum(item) => &item.generics,
+            syn::Item::Fn(item) => &item.sig.generics,
+            syn::Item::Impl(item) => &item.generics,
+            syn::Item::Struct(item) => &item.generics,
+            syn::Item::Trait(item) => &item.generics,
+            syn::Item::TraitAlias(item) => &item.generics,
+            syn::Item::Type(item) => &item.generics,
+            syn::Item::Union(item) => &item.generics,
+            _ => return Ok(vec![]),
         };
-        Ok(idents)
+        Ok(generics
+            .params
+            .iter()
+            .filter(|param| !matches!(param, syn::GenericParam::Lifetime(_)))
+            .cloned()
+            .collect())
     }
-    pub fn use_target_paths(&self) -> syn::Result<Option<Vec<syn::Path>>> {
+    pub fn use_targets(&self) -> syn::Result<Option<Vec<UseTarget>>> {
         let mut cloned = self.clone();
         let item = cloned.ensure_ast()?;
         let syn::Item::Use(item_use) = item else {
             return Ok(None);
         };
-        let mut idents = Vec::new();
-        collect_use_tree_local_idents(&item_use.tree, &mut idents)?;
-        if idents.is_empty() {
+        let mut prefix =
+            UsePrefix { leading_colon: item_use.leading_colon.is_some(), idents: vec![] };
+        let mut targets = Vec::new();
+        collect_use_tree_targets(&item_use.tree, &mut prefix, &mut targets)?;
+        if targets.is_empty() {
             return Err(syn::Error::new_spanned(
                 item_use,
                 "use statement does not import any named items for auto_* attributes",
             ));
         }
-        let paths = idents.into_iter().map(|ident| syn::parse_quote!(#ident)).collect();
-        Ok(Some(paths))
+        Ok(Some(targets))
+    }
+    /// The item left to emit once its `use` targets are expanded
+    ///
+    /// Globs only feed the `auto_export` lists, so they're dropped. `None` when nothing else is
+    /// imported and no other attribute still needs the item.
+    pub fn without_use_globs(&self) -> syn::Result<Option<syn::Item>> {
+        let mut cloned = self.clone();
+        let item = cloned.ensure_ast()?;
+        let syn::Item::Use(item_use) = item else {
+            return Ok(Some(item.clone()));
+        };
+        let mut item_use = item_use.clone();
+        let lint_or_doc = |attr: &syn::Attribute| {
+            ["allow", "expect", "warn", "deny", "doc"]
+                .iter()
+                .any(|ident| attr.path().is_ident(ident))
+        };
+        if !item_use.attrs.iter().all(lint_or_doc) {
+            // a later attribute still has to see the globs
+            item_use.attrs.push(syn::parse_quote!(#[allow(unused_imports)]));
+            return Ok(Some(syn::Item::Use(item_use)));
+        }
+        let Some(tree) = use_tree_without_globs(&item_use.tree) else {
+            return Ok(None);
+        };
+        item_use.tree = tree;
+        Ok(Some(syn::Item::Use(item_use)))
     }
     pub fn map_ast<F>(&mut self, f: F) -> syn::Result<()>
     where
@@ -182,41 +192,99 @@ impl InputItem {
     }
 }
 
-fn collect_use_tree_local_idents(tree: &UseTree, out: &mut Vec<syn::Ident>) -> syn::Result<()> {
+/// A single target resolved from a `use` item
+#[derive(Debug, Clone, PartialEq)]
+pub enum UseTarget {
+    /// A named import, addressed by its local name (or its full path for `as _` imports)
+    Item(syn::Path),
+    /// A module imported through `*` or `self`, expanded through its `auto_export` list
+    Module(syn::Path),
+}
+
+struct UsePrefix {
+    leading_colon: bool,
+    idents: Vec<syn::Ident>,
+}
+
+impl UsePrefix {
+    fn path(&self) -> syn::Path {
+        self.path_with(None)
+    }
+    fn path_with(&self, last: Option<&syn::Ident>) -> syn::Path {
+        let idents = self.idents.iter().chain(last);
+        if self.leading_colon {
+            syn::parse_quote!(:: #(#idents)::*)
+        } else {
+            syn::parse_quote!(#(#idents)::*)
+        }
+    }
+    fn module_path(&self, tokens: &impl ToTokens) -> syn::Result<syn::Path> {
+        if self.idents.is_empty() {
+            return Err(syn::Error::new_spanned(
+                tokens,
+                "unable to resolve the module being imported for auto_* attributes",
+            ));
+        }
+        Ok(self.path())
+    }
+}
+
+fn collect_use_tree_targets(
+    tree: &UseTree,
+    prefix: &mut UsePrefix,
+    out: &mut Vec<UseTarget>,
+) -> syn::Result<()> {
     match tree {
         UseTree::Name(name) => {
-            validate_use_ident(&name.ident)?;
-            out.push(name.ident.clone());
+            if name.ident == "self" {
+                out.push(UseTarget::Module(prefix.module_path(name)?));
+            } else {
+                out.push(UseTarget::Item(name.ident.clone().into()));
+            }
         }
         UseTree::Rename(rename) => {
-            validate_use_ident(&rename.rename)?;
-            out.push(rename.rename.clone());
+            if rename.ident == "self" {
+                out.push(UseTarget::Module(prefix.module_path(rename)?));
+            } else if rename.rename == "_" {
+                // nothing is bound locally, so the item is addressed through its full path
+                out.push(UseTarget::Item(prefix.path_with(Some(&rename.ident))));
+            } else {
+                out.push(UseTarget::Item(rename.rename.clone().into()));
+            }
+        }
+        UseTree::Path(path) => {
+            prefix.idents.push(path.ident.clone());
+            let res = collect_use_tree_targets(&path.tree, prefix, out);
+            prefix.idents.pop();
+            res?;
         }
-        UseTree::Path(path) => collect_use_tree_local_idents(&path.tree, out)?,
         UseTree::Group(group) => {
             for item in &group.items {
-                collect_use_tree_local_idents(item, out)?;
+                collect_use_tree_targets(item, prefix, out)?;
             }
         }
         UseTree::Glob(glob) => {
-            return Err(syn::Error::new_spanned(
-                glob,
-                "glob imports are not supported for auto_* attributes on use statements",
-            ));
+            out.push(UseTarget::Module(prefix.module_path(glob)?));
         }
     }
     Ok(())
 }
 
-fn validate_use_ident(ident: &syn::Ident) -> syn::Result<()> {
-    let name = ident.to_string();
-    if name == "self" || name == "_" {
-        return Err(syn::Error::new(
-            ident.span(),
-            "unsupported use import for auto_* attributes; import explicit named items instead",
-        ));
+fn use_tree_without_globs(tree: &UseTree) -> Option<UseTree> {
+    match tree {
+        UseTree::Glob(_) => None,
+        UseTree::Name(_) | UseTree::Rename(_) => Some(tree.clone()),
+        UseTree::Path(path) => {
+            let mut path = path.clone();
+            *path.tree = use_tree_without_globs(&path.tree)?;
+            Some(UseTree::Path(path))
+        }
+        UseTree::Group(group) => {
+            let mut group = group.clone();
+            group.items = group.items.iter().filter_map(use_tree_without_globs).collect();
+            (!group.items.is_empty()).then_some(UseTree::Group(group))
+        }
     }
-    Ok(())
 }
 
 impl ToTokens for InputItem {
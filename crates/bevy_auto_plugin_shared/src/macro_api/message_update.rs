@@ -0,0 +1,107 @@
+use crate::syntax::ast::{
+    any_expr::AnyExprCallMacroPath,
+    flag::Flag,
+};
+use darling::FromMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+use syn::spanned::Spanned;
+
+const UPDATE_CONFLICT_ERR: &str = "`update_in` and `manual_update` are mutually exclusive";
+
+/// How `Messages<T>` is updated, shared by the message attributes
+///
+/// Both options register the message in a `MessageRegistry` of its own, so it isn't updated in
+/// `First`
+#[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
+#[darling(derive_syn_parse, default, and_then = Self::validate)]
+pub struct MessageUpdateArgs {
+    /// schedule running `Messages::<T>::update`
+    pub update_in: Option<AnyExprCallMacroPath>,
+    /// messages are updated when `update_manual_messages` runs
+    pub manual_update: Flag,
+}
+
+impl MessageUpdateArgs {
+    fn validate(self) -> darling::Result<Self> {
+        if let Some(update_in) = &self.update_in
+            && self.manual_update.is_present()
+        {
+            return Err(darling::Error::custom(UPDATE_CONFLICT_ERR).with_span(&update_in.span()));
+        }
+        Ok(self)
+    }
+
+    pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
+        let mut tokens = vec![];
+        if let Some(update_in) = &self.update_in {
+            tokens.push(quote! { update_in = #update_in });
+        }
+        if self.manual_update.is_present() {
+            tokens.push(quote! { manual_update });
+        }
+        tokens
+    }
+
+    pub fn to_app_mutation_tokens(
+        &self,
+        app_param: &syn::Ident,
+        message: &impl ToTokens,
+    ) -> MacroStream {
+        let message_update =
+            quote! { ::bevy_auto_plugin::__private::shared::__private::message_update };
+        if let Some(update_in) = &self.update_in {
+            quote! {
+                #message_update::add_message_updated_in::<#message>(#app_param, #update_in);
+            }
+        } else if self.manual_update.is_present() {
+            quote! {
+                #message_update::add_manual_message::<#message>(#app_param);
+            }
+        } else {
+            quote! {
+                #app_param.add_message::<#message>();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use syn::{
+        parse_quote,
+        parse2,
+    };
+
+    #[xtest]
+    fn test_update_in_conflicts_with_manual_update() {
+        let res = parse2::<MessageUpdateArgs>(quote!(update_in = FixedPostUpdate, manual_update));
+        assert_eq!(res.map_err(|err| err.to_string()), Err(UPDATE_CONFLICT_ERR.to_string()));
+    }
+
+    #[xtest]
+    fn test_default_adds_message() {
+        let app_param: syn::Ident = parse_quote!(app);
+        let tokens = MessageUpdateArgs::default().to_app_mutation_tokens(&app_param, &quote!(Foo));
+        assert_eq!(tokens.to_string(), quote! { app.add_message::<Foo>(); }.to_string());
+    }
+
+    #[xtest]
+    fn test_update_in_registers_message() {
+        let app_param: syn::Ident = parse_quote!(app);
+        let args = parse2::<MessageUpdateArgs>(quote!(update_in = FixedPostUpdate)).unwrap();
+        let tokens = args.to_app_mutation_tokens(&app_param, &quote!(Foo));
+        assert_eq!(
+            tokens.to_string(),
+            quote! {
+                ::bevy_auto_plugin::__private::shared::__private::message_update::add_message_updated_in::<Foo>(app, FixedPostUpdate);
+            }
+            .to_string()
+        );
+    }
+}
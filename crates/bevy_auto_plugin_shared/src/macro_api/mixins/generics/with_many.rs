@@ -3,22 +3,60 @@ use crate::{
         HasKeys,
         generics::HasGenerics,
     },
-    syntax::ast::type_list::TypeList,
+    syntax::ast::type_list::{
+        TypeList,
+        type_lists_from_meta,
+    },
+};
+use darling::{
+    FromMeta,
+    ast::NestedMeta,
 };
-use darling::FromMeta;
 use proc_macro2::TokenStream;
 use quote::{
     ToTokens,
     quote,
 };
 
-#[derive(Debug, Clone, Default, FromMeta, PartialEq, Hash)]
-#[darling(derive_syn_parse)]
+/// `generics(...)` may be repeated, and `generics(product(...))` expands into every combination
+#[derive(Debug, Clone, Default, PartialEq, Hash)]
 pub struct WithZeroOrManyGenerics {
-    #[darling(multiple, default, rename = "generics")]
     pub generics: Vec<TypeList>,
 }
 
+impl FromMeta for WithZeroOrManyGenerics {
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        let mut errors = darling::Error::accumulator();
+        let mut generics = Vec::new();
+        for item in items {
+            match item {
+                NestedMeta::Meta(meta) if meta.path().is_ident("generics") => {
+                    if let Some(lists) = errors.handle(type_lists_from_meta(meta)) {
+                        generics.extend(lists);
+                    }
+                }
+                NestedMeta::Meta(meta) => {
+                    errors.push(darling::Error::unknown_field_path(meta.path()).with_span(meta));
+                }
+                NestedMeta::Lit(lit) => {
+                    errors.push(darling::Error::unsupported_format("literal").with_span(lit));
+                }
+            }
+        }
+        errors.finish_with(Self { generics })
+    }
+}
+
+impl syn::parse::Parse for WithZeroOrManyGenerics {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let items =
+            syn::punctuated::Punctuated::<NestedMeta, syn::Token![,]>::parse_terminated(input)?
+                .into_iter()
+                .collect::<Vec<_>>();
+        Ok(Self::from_list(&items)?)
+    }
+}
+
 impl WithZeroOrManyGenerics {
     pub const KEYS: &'static [&'static str] = &["generics"];
 }
@@ -87,4 +125,24 @@ mod tests {
             quote!(generics(bool, u32), generics(usize)).to_string()
         );
     }
+
+    #[xtest]
+    fn test_from_list_product() -> syn::Result<()> {
+        let args = syn::parse2::<WithZeroOrManyGenerics>(quote! {
+            generics(u8),
+            generics(product(A = [bool, u32], B = [usize, i64]))
+        })?;
+        assert_eq!(
+            args.to_token_stream().to_string(),
+            quote!(
+                generics(u8),
+                generics(A = bool, B = usize),
+                generics(A = bool, B = i64),
+                generics(A = u32, B = usize),
+                generics(A = u32, B = i64)
+            )
+            .to_string()
+        );
+        Ok(())
+    }
 }
@@ -6,6 +6,11 @@ pub mod with_plugin;
 
 pub trait HasKeys {
     fn keys() -> &'static [&'static str];
+
+    /// Attribute the keys belong to, used in unknown key diagnostics.
+    fn attribute() -> Option<&'static str> {
+        None
+    }
 }
 
 pub trait Mixin: Sized {
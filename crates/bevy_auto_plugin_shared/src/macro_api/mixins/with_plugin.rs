@@ -14,7 +14,8 @@ use quote::{
 pub struct WithPlugin {
     #[darling(rename = "plugin")]
     pub plugin: syn::Path,
-    pub after_build: Flag,
+    /// `None` follows the plugin's `defaults(after_build)`
+    pub after_build: Option<Flag>,
 }
 
 impl WithPlugin {
@@ -24,10 +25,10 @@ impl WithPlugin {
 impl ToTokens for WithPlugin {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let plugin = &self.plugin;
-        if self.after_build.is_present() {
-            tokens.extend(quote! { plugin = #plugin, after_build });
-        } else {
-            tokens.extend(quote! { plugin = #plugin });
+        match self.after_build.as_ref().map(Flag::is_present) {
+            Some(true) => tokens.extend(quote! { plugin = #plugin, after_build }),
+            Some(false) => tokens.extend(quote! { plugin = #plugin, after_build = false }),
+            None => tokens.extend(quote! { plugin = #plugin }),
         }
     }
 }
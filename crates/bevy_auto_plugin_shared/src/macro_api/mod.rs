@@ -4,8 +4,12 @@ mod context;
 mod derives;
 mod emitters;
 mod input_item;
+mod message_update;
 mod mixins;
-mod schedule_config;
+mod observer_config;
+pub(crate) mod plugin_defaults;
+pub(crate) mod schedule_config;
+mod state_transitions;
 
 pub(crate) mod prelude {
     use super::*;
@@ -0,0 +1,74 @@
+use crate::syntax::ast::any_expr::AnyExprCallClosureMacroPath;
+use darling::FromMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+
+/// Options for how an observer is spawned, shared by the observer attributes
+#[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct ObserverConfigArgs {
+    pub on_entity_with: Option<syn::Path>,
+    pub name: Option<syn::LitStr>,
+    #[darling(multiple)]
+    pub run_if: Vec<AnyExprCallClosureMacroPath>,
+}
+
+impl ObserverConfigArgs {
+    pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
+        let mut tokens = vec![];
+        if let Some(on_entity_with) = &self.on_entity_with {
+            tokens.push(quote! { on_entity_with = #on_entity_with });
+        }
+        if let Some(name) = &self.name {
+            tokens.push(quote! { name = #name });
+        }
+        for run_if in &self.run_if {
+            tokens.push(quote! { run_if = #run_if });
+        }
+        tokens
+    }
+
+    pub fn to_app_mutation_tokens(
+        &self,
+        app_param: &syn::Ident,
+        observer: &impl ToTokens,
+    ) -> MacroStream {
+        if self.on_entity_with.is_none() && self.name.is_none() && self.run_if.is_empty() {
+            return quote! {
+                #app_param.add_observer( #observer );
+            };
+        }
+        let ecs_root = crate::__private::paths::ecs::ecs_root_path();
+        let mut system = observer.to_token_stream();
+        for run_if in &self.run_if {
+            system = quote! {
+                ::bevy_auto_plugin::__private::shared::__private::observer::observer_run_if(#system, #run_if)
+            };
+        }
+        let name = self.name.as_ref().map(|name| {
+            quote! { , #ecs_root::name::Name::new(#name) }
+        });
+        match &self.on_entity_with {
+            // a new observer watching each entity the marker is added to
+            Some(marker) => quote! {
+                #app_param.add_observer(
+                    |add: #ecs_root::observer::On<#ecs_root::lifecycle::Add, #marker>, mut commands: #ecs_root::system::Commands| {
+                        commands.spawn((
+                            #ecs_root::observer::Observer::new(#system).with_entity(add.entity)
+                            #name
+                        ));
+                    }
+                );
+            },
+            None => quote! {
+                #app_param.world_mut().spawn((
+                    #ecs_root::observer::Observer::new(#system)
+                    #name
+                ));
+            },
+        }
+    }
+}
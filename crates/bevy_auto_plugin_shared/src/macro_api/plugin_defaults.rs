@@ -0,0 +1,169 @@
+use crate::syntax::ast::{
+    any_expr::{
+        AnyExprCallClosureMacroPath,
+        AnyExprCallMacroPath,
+    },
+    flag::Flag,
+    flag_or_list::FlagOrList,
+};
+use darling::FromMeta;
+use proc_macro2::{
+    Ident,
+    TokenStream as MacroStream,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    Generics,
+    parse_quote,
+};
+
+/// `#[auto_plugin(defaults(...))]`
+#[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct PluginDefaultsArgs {
+    pub system: SystemDefaultsArgs,
+    pub component: ComponentDefaultsArgs,
+    pub after_build: Flag,
+}
+
+/// `defaults(system(...))`, used by `auto_system` and `auto_add_system`
+#[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct SystemDefaultsArgs {
+    pub schedule: Option<AnyExprCallMacroPath>,
+    #[darling(multiple)]
+    pub in_set: Vec<AnyExprCallClosureMacroPath>,
+    #[darling(multiple)]
+    pub run_if: Vec<AnyExprCallClosureMacroPath>,
+}
+
+/// `defaults(component(...))`, used by `auto_component`
+#[derive(FromMeta, Debug, Default, Clone, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct ComponentDefaultsArgs {
+    /// list used by a bare `reflect`
+    pub reflect: FlagOrList<Ident>,
+}
+
+impl PluginDefaultsArgs {
+    /// Overrides for the `AutoPlugin` impl
+    pub fn auto_plugin_items(&self) -> MacroStream {
+        let ecs = quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_ecs };
+        let mut items = MacroStream::new();
+        if self.after_build.is_present() {
+            items.extend(quote! {
+                const DEFAULT_AFTER_BUILD: bool = true;
+            });
+        }
+        let configs = quote! { #ecs::schedule::ScheduleConfigs<#ecs::system::ScheduleSystem> };
+        if !self.system.in_set.is_empty() {
+            let in_set = &self.system.in_set;
+            items.extend(quote! {
+                fn default_system_in_set(systems: #configs) -> #configs {
+                    #(let systems = #ecs::schedule::IntoScheduleConfigs::in_set(systems, #in_set);)*
+                    systems
+                }
+            });
+        }
+        if !self.system.run_if.is_empty() {
+            let run_if = &self.system.run_if;
+            items.extend(quote! {
+                fn default_system_run_if(systems: #configs) -> #configs {
+                    #(let systems = #ecs::schedule::IntoScheduleConfigs::run_if(systems, #run_if);)*
+                    systems
+                }
+            });
+        }
+        items
+    }
+
+    /// `AutoPluginComponentReflect` impl of the plugin, registering
+    /// `defaults(component(reflect(...)))` as type data for a bare `reflect`
+    pub fn component_reflect_impl(
+        &self,
+        ident: &Ident,
+        generics: &Generics,
+    ) -> syn::Result<MacroStream> {
+        let registry =
+            quote! { ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry };
+        let component = format_ident!("__AutoPluginComponent");
+        let mut impl_generics = generics.clone();
+        impl_generics.params.push(parse_quote!(#component));
+        let (_, ty_generics, _) = generics.split_for_impl();
+        let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
+        let items = &self.component.reflect.items;
+        if items.is_empty() {
+            return Ok(quote! {
+                impl #impl_generics #registry::AutoPluginComponentReflect<#component>
+                    for #ident #ty_generics #where_clause {}
+            });
+        }
+        if let Some(item) = items
+            .iter()
+            .find(|item| REFLECT_TRAIT_IDENTS.iter().any(|reflect_trait| *item == reflect_trait))
+        {
+            return Err(syn::Error::new(
+                item.span(),
+                format!(
+                    "`{item}` changes the `Reflect` impl and can't be a plugin default - \
+                     list it in the component's own `reflect(...)`"
+                ),
+            ));
+        }
+        let reflect_root = crate::__private::paths::reflect::reflect_root_path();
+        // type data is named the way `#[reflect(...)]` names it, resolved at the plugin
+        let type_data = items.iter().map(|item| format_ident!("Reflect{item}")).collect::<Vec<_>>();
+        let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+        where_clause.predicates.push(parse_quote! {
+            #component: #reflect_root::Reflect + #reflect_root::TypePath + #reflect_root::GetTypeRegistration
+        });
+        where_clause.predicates.extend(type_data.iter().map(|type_data| -> syn::WherePredicate {
+            parse_quote!(#type_data: #reflect_root::FromType<#component>)
+        }));
+        Ok(quote! {
+            impl #impl_generics #registry::AutoPluginComponentReflect<#component>
+                for #ident #ty_generics #where_clause
+            {
+                fn register_component_reflect(app: &mut #registry::bevy_app::App) {
+                    app.register_type::<#component>();
+                    #(app.register_type_data::<#component, #type_data>();)*
+                }
+            }
+        })
+    }
+}
+
+/// `#[reflect(...)]` idents that change the `Reflect` impl instead of naming type data
+const REFLECT_TRAIT_IDENTS: [&str; 5] = ["Debug", "PartialEq", "PartialOrd", "Hash", "Clone"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_component_reflect_impl() -> syn::Result<()> {
+        let defaults: PluginDefaultsArgs = parse_quote! { component(reflect(Default)) };
+        let tokens =
+            defaults.component_reflect_impl(&format_ident!("Test"), &Generics::default())?;
+        let tokens = tokens.to_string();
+        assert!(tokens.contains("ReflectDefault : :: bevy_reflect :: FromType"), "{tokens}");
+        assert!(
+            tokens.contains("register_type_data :: < __AutoPluginComponent , ReflectDefault >"),
+            "{tokens}"
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_component_reflect_impl_rejects_reflect_traits() {
+        let defaults: PluginDefaultsArgs = parse_quote! { component(reflect(Default, Debug)) };
+        let err = defaults
+            .component_reflect_impl(&format_ident!("Test"), &Generics::default())
+            .unwrap_err();
+        assert!(err.to_string().contains("`Debug` changes the `Reflect` impl"), "{err}");
+    }
+}
@@ -1,6 +1,9 @@
-use crate::syntax::ast::any_expr::{
-    AnyExprCallClosureMacroPath,
-    AnyExprCallMacroPath,
+use crate::syntax::ast::{
+    any_expr::{
+        AnyExprCallClosureMacroPath,
+        AnyExprCallMacroPath,
+    },
+    flag::Flag,
 };
 use darling::FromMeta;
 use proc_macro2::TokenStream as MacroStream;
@@ -8,20 +11,117 @@ use quote::{
     ToTokens,
     quote,
 };
+use syn::{
+    Meta,
+    parse_quote,
+    spanned::Spanned,
+};
+
+const SCHEDULE_CONFLICT_ERR: &str =
+    "only one of `schedule`, `fixed`, `startup`, `update` and `post_update` can be set";
+const IN_STATE_CONFLICT_ERR: &str =
+    "`in_state(..)` conflicts with `run_if = in_state(..)` - keep only one of them";
 
-#[derive(FromMeta, Clone, Debug, PartialEq, Hash)]
-#[darling(derive_syn_parse)]
+#[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
+#[darling(derive_syn_parse, and_then = Self::resolve)]
 pub struct ScheduleWithScheduleConfigArgs {
-    pub schedule: AnyExprCallMacroPath,
+    /// `None` falls back to the plugin's `default_schedule`
+    #[darling(default)]
+    pub schedule: Option<AnyExprCallMacroPath>,
+    /// `schedule = FixedUpdate`
+    pub fixed: Flag,
+    /// `schedule = Startup`
+    pub startup: Flag,
+    /// `schedule = Update`
+    pub update: Flag,
+    /// `schedule = PostUpdate`
+    pub post_update: Flag,
+    /// `run_if = in_state(..)`
+    #[darling(default)]
+    pub in_state: Option<InStateCondition>,
     #[darling(default)]
     pub config: ScheduleConfigArgs,
 }
 
 impl ScheduleWithScheduleConfigArgs {
+    /// Folds the shortcuts into `schedule` and `config.run_if`
+    fn resolve(mut self) -> darling::Result<Self> {
+        let shortcuts = [
+            (&self.fixed, "FixedUpdate"),
+            (&self.startup, "Startup"),
+            (&self.update, "Update"),
+            (&self.post_update, "PostUpdate"),
+        ];
+        let mut schedule = self.schedule.take();
+        for (flag, label) in shortcuts {
+            if !flag.is_present() {
+                continue;
+            }
+            if schedule.is_some() {
+                return Err(darling::Error::custom(SCHEDULE_CONFLICT_ERR).with_span(&flag.span()));
+            }
+            let label = syn::Ident::new(label, flag.span());
+            schedule = Some(AnyExprCallMacroPath::Path(parse_quote! {
+                ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_app::#label
+            }));
+        }
+        self.schedule = schedule;
+        self.fixed = Flag::default();
+        self.startup = Flag::default();
+        self.update = Flag::default();
+        self.post_update = Flag::default();
+
+        if let Some(InStateCondition(state)) = self.in_state.take() {
+            if let Some(run_if) = self.config.run_if.iter().find(|run_if| is_in_state_call(run_if))
+            {
+                return Err(darling::Error::custom(IN_STATE_CONFLICT_ERR).with_span(&run_if.span()));
+            }
+            let state_root = crate::__private::paths::state::root_path();
+            self.config.run_if.insert(
+                0,
+                AnyExprCallClosureMacroPath::Call(parse_quote! {
+                    #state_root::condition::in_state(#state)
+                }),
+            );
+        }
+        Ok(self)
+    }
+
+    /// The explicit schedule, or the plugin's `default_schedule`
+    pub fn schedule_tokens(&self, plugin: &syn::Path) -> MacroStream {
+        match &self.schedule {
+            Some(schedule) => schedule.to_token_stream(),
+            None => quote! {
+                <#plugin as ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPluginDefaultSchedule>::default_schedule()
+            },
+        }
+    }
+
+    /// The configured system, falling back to the plugin's `defaults(system(...))` for
+    /// `in_set` and `run_if` when the attribute doesn't set its own
+    pub fn system_tokens(&self, plugin: &syn::Path, system: &impl ToTokens) -> MacroStream {
+        let ecs_root = crate::__private::paths::ecs::ecs_root_path();
+        let auto_plugin = quote! {
+            <#plugin as ::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::AutoPlugin>
+        };
+        let config = &self.config;
+        let mut tokens = quote! {
+            #ecs_root::schedule::IntoScheduleConfigs::into_configs(#system #config)
+        };
+        if config.in_set.is_empty() {
+            tokens = quote! { #auto_plugin::default_system_in_set(#tokens) };
+        }
+        if config.run_if.is_empty() {
+            tokens = quote! { #auto_plugin::default_system_run_if(#tokens) };
+        }
+        tokens
+    }
+
     pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
         let mut tokens = vec![];
-        let schedule = &self.schedule;
-        tokens.push(quote! { schedule = #schedule });
+        if let Some(schedule) = &self.schedule {
+            tokens.push(quote! { schedule = #schedule });
+        }
         let config = self.config.to_inner_arg_tokens_vec();
         if !config.is_empty() {
             tokens.push(quote! { config( #(#config),* )});
@@ -30,6 +130,30 @@ impl ScheduleWithScheduleConfigArgs {
     }
 }
 
+/// `in_state(State::Variant)` or `in_state = State::Variant`
+#[derive(Clone, Debug, PartialEq, Hash)]
+pub struct InStateCondition(pub syn::Expr);
+
+impl FromMeta for InStateCondition {
+    fn from_meta(item: &Meta) -> darling::Result<Self> {
+        match item {
+            Meta::List(list) => Ok(Self(list.parse_args()?)),
+            Meta::NameValue(nv) => Ok(Self(nv.value.clone())),
+            Meta::Path(_) => Err(darling::Error::unsupported_format("word").with_span(item)),
+        }
+    }
+}
+
+fn is_in_state_call(expr: &AnyExprCallClosureMacroPath) -> bool {
+    let AnyExprCallClosureMacroPath::Call(call) = expr else {
+        return false;
+    };
+    let syn::Expr::Path(func) = &*call.func else {
+        return false;
+    };
+    func.path.segments.last().is_some_and(|segment| segment.ident == "in_state")
+}
+
 #[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
 #[darling(derive_syn_parse, default)]
 pub struct ScheduleConfigArgs {
@@ -153,3 +277,32 @@ impl ToTokens for ScheduleConfigArgs {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use syn::parse2;
+
+    #[xtest]
+    fn test_shortcut_resolves_schedule() -> syn::Result<()> {
+        let args = parse2::<ScheduleWithScheduleConfigArgs>(quote!(fixed))?;
+        assert_eq!(
+            args.schedule.to_token_stream().to_string(),
+            quote!(::bevy_auto_plugin::__private::shared::__private::auto_plugin_registry::bevy_app::FixedUpdate)
+                .to_string()
+        );
+        assert!(!args.fixed.is_present());
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_in_state_conflicts_with_run_if_in_state() {
+        let res = parse2::<ScheduleWithScheduleConfigArgs>(quote!(
+            update,
+            in_state(Foo::A),
+            config(run_if = in_state(Foo::B))
+        ));
+        assert_eq!(res.map_err(|err| err.to_string()), Err(IN_STATE_CONFLICT_ERR.to_string()));
+    }
+}
@@ -0,0 +1,73 @@
+use darling::FromMeta;
+use proc_macro2::TokenStream as MacroStream;
+use quote::quote;
+
+/// Options for `StateTransition` behavior shared by the state attributes
+#[derive(FromMeta, Clone, Debug, Default, PartialEq, Hash)]
+#[darling(derive_syn_parse, default)]
+pub struct StateTransitionArgs {
+    pub log_transitions: bool,
+    pub allow_same_state_transitions: Option<bool>,
+}
+
+impl StateTransitionArgs {
+    pub fn is_empty(&self) -> bool {
+        !self.log_transitions && self.allow_same_state_transitions.is_none()
+    }
+
+    pub fn to_inner_arg_tokens_vec(&self) -> Vec<MacroStream> {
+        let mut tokens = vec![];
+        if self.log_transitions {
+            tokens.push(quote! { log_transitions });
+        }
+        if let Some(allow) = self.allow_same_state_transitions {
+            tokens.push(quote! { allow_same_state_transitions = #allow });
+        }
+        tokens
+    }
+
+    pub fn to_app_mutation_tokens(
+        &self,
+        app_param: &syn::Ident,
+        state: &impl quote::ToTokens,
+    ) -> MacroStream {
+        let state_root = crate::__private::paths::state::root_path();
+        let ecs_root = crate::__private::paths::ecs::ecs_root_path();
+        let mut tokens = MacroStream::new();
+        if self.log_transitions {
+            tokens.extend(quote! {
+                #app_param.add_systems(
+                    #state_root::state::StateTransition,
+                    #ecs_root::schedule::IntoScheduleConfigs::after(|mut transitions: #ecs_root::message::MessageReader<#state_root::state::StateTransitionEvent<#state>>| {
+                        for transition in transitions.read() {
+                            ::bevy_auto_plugin::__private::shared::__private::log::debug!(
+                                "{} transition: {:?} => {:?}",
+                                ::core::any::type_name::<#state>(),
+                                transition.exited,
+                                transition.entered,
+                            );
+                        }
+                    }, #state_root::state::StateTransitionSystems::DependentTransitions),
+                );
+            });
+        }
+        // `NextState::set` re-runs the transition schedules for the current state by default
+        if self.allow_same_state_transitions == Some(false) {
+            tokens.extend(quote! {
+                #app_param.add_systems(
+                    #state_root::state::StateTransition,
+                    #ecs_root::schedule::IntoScheduleConfigs::before(|current: ::core::option::Option<#ecs_root::system::Res<#state_root::state::State<#state>>>,
+                      mut next: #ecs_root::system::ResMut<#state_root::state::NextState<#state>>| {
+                        let Some(current) = current else {
+                            return;
+                        };
+                        if matches!(&*next, #state_root::state::NextState::Pending(pending) if pending == current.get()) {
+                            next.reset();
+                        }
+                    }, #state_root::state::StateTransitionSystems::DependentTransitions),
+                );
+            });
+        }
+        tokens
+    }
+}
@@ -5,6 +5,8 @@ use crate::syntax::extensions::{
 use syn::{
     Attribute,
     Item,
+    Token,
+    punctuated::Punctuated,
 };
 
 pub fn item_has_attr(item: &Item, path: &syn::Path) -> bool {
@@ -15,9 +17,21 @@ pub fn has_attr(attrs: &[Attribute], path: &syn::Path) -> bool {
     attrs.iter().any(|attr| attr.path().is_similar_path_or_ident(path))
 }
 
+/// Whether one of the `#[derive(..)]` attributes lists a path ending in `ident`
+pub fn has_derive(attrs: &[Attribute], ident: &str) -> bool {
+    attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated).is_ok_and(
+            |paths| paths.iter().any(|path| path.segments.last().is_some_and(|s| s.ident == ident)),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::syntax::analysis::item::has_attr;
+    use crate::syntax::analysis::item::{
+        has_attr,
+        has_derive,
+    };
     use internal_test_proc_macro::xtest;
     use syn::parse_quote;
 
@@ -55,4 +69,15 @@ mod tests {
         };
         assert!(has_attr(&input, &target_path));
     }
+
+    #[xtest]
+    fn test_has_derive() {
+        let input: Vec<_> = parse_quote! {
+            #[derive(Debug)]
+            #[derive(Default, bevy::reflect::Reflect)]
+        };
+        assert!(has_derive(&input, "Reflect"));
+        assert!(has_derive(&input, "Debug"));
+        assert!(!has_derive(&input, "Component"));
+    }
 }
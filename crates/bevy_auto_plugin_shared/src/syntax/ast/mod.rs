@@ -1,6 +1,6 @@
 pub mod any_expr;
 pub mod any_expr_list;
-mod expr_path_or_call;
+pub mod expr_path_or_call;
 pub mod expr_value;
 pub mod flag;
 pub mod flag_or_expr;
@@ -9,3 +9,4 @@ pub mod flag_or_lit;
 #[allow(dead_code)]
 pub mod flag_or_meta;
 pub mod type_list;
+pub mod with_span;
@@ -11,6 +11,8 @@ use quote::{
     quote,
 };
 use syn::{
+    GenericArgument,
+    GenericParam,
     Ident,
     Meta,
     Token,
@@ -20,32 +22,44 @@ use syn::{
     spanned::Spanned,
 };
 
+/// Parses a single generic argument: a type (`u32`) or a const value (`8`, `{ N * 2 }`)
+pub fn parse_generic_arg(input: syn::parse::ParseStream) -> syn::Result<GenericArgument> {
+    let arg: GenericArgument = input.parse()?;
+    match arg {
+        GenericArgument::Type(_) | GenericArgument::Const(_) => Ok(arg),
+        other => Err(syn::Error::new(
+            other.span(),
+            "expected a type or a const value (lifetimes and associated types are not supported here)",
+        )),
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Hash)]
 pub enum TypeListEntry {
-    Positional(Type),
-    Named { ident: Ident, ty: Type },
+    Positional(GenericArgument),
+    Named { ident: Ident, arg: GenericArgument },
 }
 
 impl TypeListEntry {
-    pub fn ty(&self) -> &Type {
+    pub fn arg(&self) -> &GenericArgument {
         match self {
-            TypeListEntry::Positional(ty) => ty,
-            TypeListEntry::Named { ty, .. } => ty,
+            TypeListEntry::Positional(arg) => arg,
+            TypeListEntry::Named { arg, .. } => arg,
         }
     }
 }
 
 impl From<Type> for TypeListEntry {
     fn from(ty: Type) -> Self {
-        TypeListEntry::Positional(ty)
+        TypeListEntry::Positional(GenericArgument::Type(ty))
     }
 }
 
 impl ToTokens for TypeListEntry {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
-            TypeListEntry::Positional(ty) => ty.to_tokens(tokens),
-            TypeListEntry::Named { ident, ty } => tokens.extend(quote! { #ident = #ty }),
+            TypeListEntry::Positional(arg) => arg.to_tokens(tokens),
+            TypeListEntry::Named { ident, arg } => tokens.extend(quote! { #ident = #arg }),
         }
     }
 }
@@ -55,12 +69,46 @@ impl syn::parse::Parse for TypeListEntry {
         if input.peek(Ident) && input.peek2(Token![=]) {
             let ident: Ident = input.parse()?;
             let _: Token![=] = input.parse()?;
-            let ty: Type = input.parse()?;
-            Ok(TypeListEntry::Named { ident, ty })
+            let arg = parse_generic_arg(input)?;
+            Ok(TypeListEntry::Named { ident, arg })
         } else {
-            let ty: Type = input.parse()?;
-            Ok(TypeListEntry::Positional(ty))
+            Ok(TypeListEntry::Positional(parse_generic_arg(input)?))
+        }
+    }
+}
+
+/// Checks that `arg` can be supplied for `param`.
+///
+/// A bare path is accepted for const params since it may name a const item.
+fn check_arg_kind(param: &GenericParam, arg: &GenericArgument) -> syn::Result<()> {
+    match (param, arg) {
+        (GenericParam::Type(param), GenericArgument::Const(value)) => Err(syn::Error::new(
+            value.span(),
+            format!(
+                "expected a type for generic parameter `{}`, found const value `{}`",
+                param.ident,
+                value.to_token_stream()
+            ),
+        )),
+        (GenericParam::Const(param), GenericArgument::Type(ty)) if !matches!(ty, Type::Path(type_path) if type_path.qself.is_none()) => {
+            Err(syn::Error::new(
+                ty.span(),
+                format!(
+                    "expected a const value for const generic parameter `{}`, found type `{}`",
+                    param.ident,
+                    ty.to_token_stream()
+                ),
+            ))
         }
+        _ => Ok(()),
+    }
+}
+
+fn generic_param_ident(param: &GenericParam) -> Option<&Ident> {
+    match param {
+        GenericParam::Type(param) => Some(&param.ident),
+        GenericParam::Const(param) => Some(&param.ident),
+        GenericParam::Lifetime(_) => None,
     }
 }
 
@@ -80,44 +128,48 @@ impl TypeList {
     pub fn len(&self) -> usize {
         self.0.len()
     }
-    pub fn types_in_declared_order(&self) -> Vec<Type> {
-        self.0.iter().map(|entry| entry.ty().clone()).collect()
+    pub fn args_in_declared_order(&self) -> Vec<GenericArgument> {
+        self.0.iter().map(|entry| entry.arg().clone()).collect()
     }
-    pub fn resolve_types(&self, target_params: &[Ident]) -> syn::Result<Vec<Type>> {
-        use std::collections::{
-            HashMap,
-            HashSet,
-        };
-
-        let target_set: HashSet<String> =
-            target_params.iter().map(|ident| ident.to_string()).collect();
-        let mut named_types: HashMap<String, Type> = HashMap::new();
-        let mut positional_types: Vec<Type> = Vec::new();
+    /// Orders the entries by `target_params` (type and const params, lifetimes are skipped)
+    /// and validates each argument against the kind of its param
+    pub fn resolve_args(
+        &self,
+        target_params: &[GenericParam],
+    ) -> syn::Result<Vec<GenericArgument>> {
+        use std::collections::HashMap;
+
+        let target_params = target_params
+            .iter()
+            .filter_map(|param| generic_param_ident(param).map(|ident| (ident, param)))
+            .collect::<Vec<_>>();
+        let mut named_args: HashMap<String, GenericArgument> = HashMap::new();
+        let mut positional_args: Vec<GenericArgument> = Vec::new();
 
         for entry in &self.0 {
             match entry {
-                TypeListEntry::Positional(ty) => positional_types.push(ty.clone()),
-                TypeListEntry::Named { ident, ty } => {
+                TypeListEntry::Positional(arg) => positional_args.push(arg.clone()),
+                TypeListEntry::Named { ident, arg } => {
                     let key = ident.to_string();
-                    if !target_set.contains(&key) {
+                    if !target_params.iter().any(|(param, _)| **param == key) {
                         return Err(syn::Error::new(
                             ident.span(),
                             format!("unknown generic parameter `{}`", ident),
                         ));
                     }
-                    if named_types.contains_key(&key) {
+                    if named_args.contains_key(&key) {
                         return Err(syn::Error::new(
                             ident.span(),
                             format!("duplicate generic parameter `{}`", ident),
                         ));
                     }
-                    named_types.insert(key, ty.clone());
+                    named_args.insert(key, arg.clone());
                 }
             }
         }
 
-        let has_named = !named_types.is_empty();
-        let has_positional = !positional_types.is_empty();
+        let has_named = !named_args.is_empty();
+        let has_positional = !positional_args.is_empty();
 
         if has_positional && has_named {
             return Err(syn::Error::new(
@@ -128,40 +180,42 @@ impl TypeList {
 
         // Positional-only case: enforce arity.
         if !has_named {
-            if positional_types.len() != target_params.len() {
-                let extra = &positional_types[target_params.len()];
+            if positional_args.len() != target_params.len() {
+                let span = positional_args
+                    .get(target_params.len())
+                    .map(|extra| extra.span())
+                    .unwrap_or_else(|| self.span());
                 return Err(syn::Error::new(
-                    extra.span(),
+                    span,
                     format!(
                         "unexpected number of generic arguments: expected {}, got {}",
                         target_params.len(),
-                        positional_types.len()
+                        positional_args.len()
                     ),
                 ));
             }
-            return Ok(positional_types);
+            for ((_, param), arg) in target_params.iter().zip(&positional_args) {
+                check_arg_kind(param, arg)?;
+            }
+            return Ok(positional_args);
         }
 
-        let mut resolved_types = Vec::new();
-        let mut positional_iter = positional_types.into_iter();
-        for ident in target_params {
-            let key = ident.to_string();
-            if let Some(ty) = named_types.remove(&key) {
-                resolved_types.push(ty);
-            } else if let Some(ty) = positional_iter.next() {
-                resolved_types.push(ty);
-            } else {
+        let mut resolved_args = Vec::new();
+        for (ident, param) in target_params {
+            let Some(arg) = named_args.remove(&ident.to_string()) else {
                 return Err(syn::Error::new(
                     ident.span(),
                     format!(
-                        "missing generic argument for `{}` (note: defaults/lifetimes/const generics are not supported here)",
+                        "missing generic argument for `{}` (note: defaults/lifetimes are not supported here)",
                         ident
                     ),
                 ));
-            }
+            };
+            check_arg_kind(param, &arg)?;
+            resolved_args.push(arg);
         }
 
-        Ok(resolved_types)
+        Ok(resolved_args)
     }
 }
 
@@ -189,14 +243,155 @@ impl From<TypeList> for TokenStream {
     }
 }
 
-fn failed_err_literal(e: syn::Error, span: &proc_macro2::Span) -> Error {
-    Error::multiple(vec![
-        Error::custom(
-            "Failed to parse TypeList: expected a list of *types*, but found literal value(s). \
-                 Use types like `Foo`, `Vec<T>`, `Option<Bar>`; not `1`, `true`, or string literals."
-        ).with_span(span),
-        Error::from(e),
-    ])
+/// Values of a single `product(...)` factor
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub enum TypeListProductValues {
+    /// `[A, B, C]`
+    List(Vec<GenericArgument>),
+    /// a reference to a set declared with `auto_generics_set!`
+    Set(syn::Path),
+}
+
+impl syn::parse::Parse for TypeListProductValues {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+            let args = Punctuated::<GenericArgument, Token![,]>::parse_terminated_with(
+                &content,
+                parse_generic_arg,
+            )?;
+            Ok(Self::List(args.into_iter().collect()))
+        } else {
+            Ok(Self::Set(input.parse()?))
+        }
+    }
+}
+
+impl ToTokens for TypeListProductValues {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::List(args) => tokens.extend(quote! { [#(#args),*] }),
+            Self::Set(path) => path.to_tokens(tokens),
+        }
+    }
+}
+
+/// A single factor of `product(...)`: `Name = [..]` or `[..]`
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct TypeListProductFactor {
+    pub ident: Option<Ident>,
+    pub values: TypeListProductValues,
+}
+
+impl syn::parse::Parse for TypeListProductFactor {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident = if input.peek(Ident) && input.peek2(Token![=]) {
+            let ident: Ident = input.parse()?;
+            let _: Token![=] = input.parse()?;
+            Some(ident)
+        } else {
+            None
+        };
+        Ok(Self { ident, values: input.parse()? })
+    }
+}
+
+impl ToTokens for TypeListProductFactor {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let values = &self.values;
+        match &self.ident {
+            Some(ident) => tokens.extend(quote! { #ident = #values }),
+            None => values.to_tokens(tokens),
+        }
+    }
+}
+
+/// `product(A = [..], B = [..])` - every combination of the factors, in declared order
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub struct TypeListProduct(pub Vec<TypeListProductFactor>);
+
+impl TypeListProduct {
+    pub const IDENT: &'static str = "product";
+
+    /// Parses `product(...)` if the tokens consist of exactly that
+    pub fn maybe_parse(tokens: &TokenStream) -> Option<syn::Result<Self>> {
+        let mut iter = tokens.clone().into_iter();
+        let (Some(TokenTree::Ident(ident)), Some(TokenTree::Group(group)), None) =
+            (iter.next(), iter.next(), iter.next())
+        else {
+            return None;
+        };
+        if ident != Self::IDENT || group.delimiter() != proc_macro2::Delimiter::Parenthesis {
+            return None;
+        }
+        let parser = Punctuated::<TypeListProductFactor, Token![,]>::parse_terminated;
+        Some(parser.parse2(group.stream()).map(|factors| Self(factors.into_iter().collect())))
+    }
+
+    pub fn expand(&self) -> syn::Result<Vec<TypeList>> {
+        let named = self.0.iter().filter(|factor| factor.ident.is_some()).count();
+        if named != 0 && named != self.0.len() {
+            return Err(syn::Error::new(
+                self.span(),
+                "cannot mix positional and named factors in a single product",
+            ));
+        }
+        let mut lists = vec![TypeList::empty()];
+        for factor in &self.0 {
+            let args = match &factor.values {
+                TypeListProductValues::List(args) if args.is_empty() => {
+                    return Err(syn::Error::new(
+                        factor.span(),
+                        "product factors must not be empty",
+                    ));
+                }
+                TypeListProductValues::List(args) => args,
+                TypeListProductValues::Set(path) => {
+                    return Err(syn::Error::new(
+                        path.span(),
+                        format!(
+                            "unresolved generics set `{}` (generics sets are not supported here)",
+                            path.to_token_stream()
+                        ),
+                    ));
+                }
+            };
+            lists = lists
+                .into_iter()
+                .flat_map(|list| {
+                    args.iter().map(move |arg| {
+                        let entry = match &factor.ident {
+                            Some(ident) => {
+                                TypeListEntry::Named { ident: ident.clone(), arg: arg.clone() }
+                            }
+                            None => TypeListEntry::Positional(arg.clone()),
+                        };
+                        let mut list = list.clone();
+                        list.0.push(entry);
+                        list
+                    })
+                })
+                .collect();
+        }
+        Ok(lists)
+    }
+}
+
+impl ToTokens for TypeListProduct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let factors = &self.0;
+        tokens.extend(quote! { product(#(#factors),*) });
+    }
+}
+
+/// Parses a `generics(...)` meta into one or more instantiations (`product(...)` expands to many)
+pub fn type_lists_from_meta(meta: &Meta) -> Result<Vec<TypeList>, Error> {
+    let list = meta.require_list()?;
+    match TypeListProduct::maybe_parse(&list.tokens) {
+        Some(product) => Ok(product.and_then(|product| product.expand())?),
+        None => Ok(vec![TypeList::from_meta(meta)?]),
+    }
 }
 
 fn failed_err(e: syn::Error, span: &proc_macro2::Span) -> Error {
@@ -206,33 +401,13 @@ fn failed_err(e: syn::Error, span: &proc_macro2::Span) -> Error {
     ])
 }
 
-/// Inspect `TokenStream` for `Literal`
-fn contains_literal(ts: &TokenStream) -> bool {
-    ts.clone().into_iter().any(|tt| matches!(tt, TokenTree::Literal(_)))
-}
-
-/// Inspect `ParseStream` for `Literal`
-fn fork_has_literal(input: syn::parse::ParseStream) -> bool {
-    let f = input.fork();
-    let ts: TokenStream = match f.parse() {
-        Ok(ts) => ts,
-        Err(_) => return false,
-    };
-    ts.into_iter().any(|tt| matches!(tt, TokenTree::Literal(_)))
-}
-
 impl FromMeta for TypeList {
     fn from_meta(meta: &Meta) -> Result<Self, Error> {
         let list = meta.require_list()?;
-        // Parse its tokens as `T, T, ...` where each `T` is a syn::Type
+        // Parse its tokens as `T, T, ...` where each `T` is a type or a const value
         let parser = Punctuated::<TypeListEntry, Token![,]>::parse_terminated;
-        let elems = parser.parse2(list.tokens.clone()).map_err(|e| {
-            if contains_literal(&list.tokens) {
-                failed_err_literal(e, &list.tokens.span())
-            } else {
-                failed_err(e, &list.tokens.span())
-            }
-        })?;
+        let elems =
+            parser.parse2(list.tokens.clone()).map_err(|e| failed_err(e, &list.tokens.span()))?;
         Ok(TypeList(elems.into_iter().collect()))
     }
 }
@@ -244,13 +419,7 @@ impl syn::parse::Parse for TypeList {
             punctuated::Punctuated,
         };
         let elems = Punctuated::<TypeListEntry, Token![,]>::parse_terminated(input)
-            .map_err(|e| {
-                if fork_has_literal(input) {
-                    failed_err_literal(e, &input.span())
-                } else {
-                    failed_err(e, &input.span())
-                }
-            })?
+            .map_err(|e| failed_err(e, &input.span()))?
             .into_iter()
             .collect();
         Ok(TypeList(elems))
@@ -262,7 +431,6 @@ mod tests {
     use super::*;
     use internal_test_proc_macro::xtest;
     use syn::{
-        Ident,
         Meta,
         Type,
         parse_quote,
@@ -285,7 +453,7 @@ mod tests {
 
         // The third element should be `Foo<u32>` with generics preserved.
         match &attr.types.0[2] {
-            TypeListEntry::Positional(Type::Path(tp)) => {
+            TypeListEntry::Positional(GenericArgument::Type(Type::Path(tp))) => {
                 let seg = tp.path.segments.last().unwrap();
                 assert_eq!(seg.ident, "FooBar");
                 assert!(matches!(seg.arguments, syn::PathArguments::AngleBracketed(_)));
@@ -308,7 +476,7 @@ mod tests {
 
         // The third element should be `Foo<u32>` with generics preserved.
         match &attr.types.0[2] {
-            TypeListEntry::Positional(Type::Path(tp)) => {
+            TypeListEntry::Positional(GenericArgument::Type(Type::Path(tp))) => {
                 let seg = tp.path.segments.last().unwrap();
                 assert_eq!(seg.ident, "FooBar");
                 assert!(matches!(seg.arguments, syn::PathArguments::AngleBracketed(_)));
@@ -330,15 +498,115 @@ mod tests {
         assert_eq!(attr.types.0.len(), 2);
 
         match &attr.types.0[0] {
-            TypeListEntry::Named { ident, ty } => {
+            TypeListEntry::Named { ident, arg } => {
                 assert_eq!(ident, "T2");
-                assert_eq!(quote!(#ty).to_string(), "i32");
+                assert_eq!(quote!(#arg).to_string(), "i32");
             }
             _ => panic!("expected TypeListEntry::Named for element 0"),
         }
 
-        let target_params: Vec<Ident> = vec![parse_quote!(T1), parse_quote!(T2)];
-        let resolved = attr.types.resolve_types(&target_params).unwrap();
+        let target_params: Vec<GenericParam> = vec![parse_quote!(T1), parse_quote!(T2)];
+        let resolved = attr.types.resolve_args(&target_params).unwrap();
         assert_eq!(quote!(#(#resolved),*).to_string(), "u32 , i32");
     }
+
+    #[xtest]
+    fn expand_named_product() -> syn::Result<()> {
+        let product = TypeListProduct::maybe_parse(&quote! {
+            product(Unit = [Foot, Horse], Stat = [Hp, Mp])
+        })
+        .expect("expected a product")?;
+        let lists = product.expand()?;
+        assert_eq!(
+            quote!(#((#lists)),*).to_string(),
+            quote! {
+                (Unit = Foot, Stat = Hp),
+                (Unit = Foot, Stat = Mp),
+                (Unit = Horse, Stat = Hp),
+                (Unit = Horse, Stat = Mp)
+            }
+            .to_string()
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn expand_positional_product() -> syn::Result<()> {
+        let meta: Meta = parse_quote!(generics(product([u8, u16], [bool])));
+        let lists = type_lists_from_meta(&meta)?;
+        assert_eq!(quote!(#((#lists)),*).to_string(), quote!((u8, bool), (u16, bool)).to_string());
+        Ok(())
+    }
+
+    #[xtest]
+    fn product_rejects_mixed_factors() {
+        let product = TypeListProduct::maybe_parse(&quote!(product(A = [u8], [u16])))
+            .expect("expected a product")
+            .expect("expected to parse");
+        let err = product.expand().expect_err("expected an error");
+        assert_eq!(err.to_string(), "cannot mix positional and named factors in a single product");
+    }
+
+    #[xtest]
+    fn product_rejects_empty_factor() {
+        let product = TypeListProduct::maybe_parse(&quote!(product(A = [])))
+            .expect("expected a product")
+            .expect("expected to parse");
+        let err = product.expand().expect_err("expected an error");
+        assert_eq!(err.to_string(), "product factors must not be empty");
+    }
+
+    #[xtest]
+    fn plain_type_list_is_not_a_product() {
+        assert!(TypeListProduct::maybe_parse(&quote!(product)).is_none());
+        assert!(TypeListProduct::maybe_parse(&quote!(Foo<u8>)).is_none());
+    }
+
+    #[xtest]
+    fn resolve_const_args() -> syn::Result<()> {
+        let target_params: Vec<GenericParam> = vec![
+            parse_quote!(T),
+            parse_quote!(const N: usize),
+            parse_quote!(const M: u8),
+        ];
+        let list = parse2::<TypeList>(quote!(u32, 8, { 2 * 4 }))?;
+        let resolved = list.resolve_args(&target_params)?;
+        assert_eq!(quote!(#(#resolved),*).to_string(), quote!(u32, 8, { 2 * 4 }).to_string());
+
+        let list = parse2::<TypeList>(quote!(M = 2, T = bool, N = SLOTS))?;
+        let resolved = list.resolve_args(&target_params)?;
+        assert_eq!(quote!(#(#resolved),*).to_string(), quote!(bool, SLOTS, 2).to_string());
+        Ok(())
+    }
+
+    #[xtest]
+    fn resolve_rejects_mismatched_kinds() -> syn::Result<()> {
+        let target_params: Vec<GenericParam> = vec![parse_quote!(T), parse_quote!(const N: usize)];
+        let err = parse2::<TypeList>(quote!(8, 8))?
+            .resolve_args(&target_params)
+            .expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "expected a type for generic parameter `T`, found const value `8`"
+        );
+
+        let err = parse2::<TypeList>(quote!(u8, [u8; 4]))?
+            .resolve_args(&target_params)
+            .expect_err("expected an error");
+        assert_eq!(
+            err.to_string(),
+            "expected a const value for const generic parameter `N`, found type `[u8 ; 4]`"
+        );
+        Ok(())
+    }
+
+    #[xtest]
+    fn resolve_rejects_missing_args() -> syn::Result<()> {
+        let target_params: Vec<GenericParam> = vec![parse_quote!(T), parse_quote!(const N: usize)];
+        let err = parse2::<TypeList>(quote!(u8))?
+            .resolve_args(&target_params)
+            .expect_err("expected an error");
+        assert_eq!(err.to_string(), "unexpected number of generic arguments: expected 2, got 1");
+        Ok(())
+    }
 }
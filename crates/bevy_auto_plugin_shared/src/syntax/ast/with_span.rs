@@ -0,0 +1,80 @@
+use darling::FromMeta;
+use proc_macro2::Span;
+use std::{
+    hash::{
+        Hash,
+        Hasher,
+    },
+    ops::Deref,
+};
+use syn::{
+    Meta,
+    spanned::Spanned,
+};
+
+/// Keeps the span of the meta a value was parsed from, for errors raised after parsing
+///
+/// Ignores span for comparison checks
+#[derive(Debug, Clone)]
+pub struct WithSpan<T> {
+    value: T,
+    span: Span,
+}
+
+impl<T> WithSpan<T> {
+    pub fn new(value: T, span: Span) -> Self {
+        Self { value, span }
+    }
+    pub fn span(&self) -> Span {
+        self.span
+    }
+}
+
+impl<T: Default> Default for WithSpan<T> {
+    fn default() -> Self {
+        Self::new(T::default(), Span::call_site())
+    }
+}
+
+impl<T> Deref for WithSpan<T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T: PartialEq> PartialEq for WithSpan<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value.eq(&other.value)
+    }
+}
+
+impl<T: Eq> Eq for WithSpan<T> {}
+
+impl<T: Hash> Hash for WithSpan<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+}
+
+impl<T: FromMeta> FromMeta for WithSpan<T> {
+    fn from_meta(meta: &Meta) -> darling::Result<Self> {
+        Ok(Self { value: T::from_meta(meta)?, span: meta.span() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use syn::parse_quote;
+
+    #[xtest]
+    fn test_from_meta_ignores_span_for_eq() -> darling::Result<()> {
+        let a = WithSpan::<bool>::from_meta(&parse_quote!(flag))?;
+        let b = WithSpan::new(true, Span::call_site());
+        assert_eq!(a, b);
+        assert!(*a);
+        Ok(())
+    }
+}
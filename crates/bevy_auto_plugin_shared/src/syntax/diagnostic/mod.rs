@@ -1 +1,2 @@
 pub mod kind;
+pub mod unknown_key;
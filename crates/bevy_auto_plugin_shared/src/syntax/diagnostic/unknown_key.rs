@@ -0,0 +1,137 @@
+use darling::ast::NestedMeta;
+
+/// Keys accepted by every attribute: `(attribute, keys)`
+pub type AttributeKeys = [(&'static str, &'static [&'static str])];
+
+/// Edit distance between `a` and `b`
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut prev = (0..=b.len()).collect::<Vec<_>>();
+    let mut cur = vec![0; b.len() + 1];
+    for (i, a_char) in a.chars().enumerate() {
+        cur[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let cost = usize::from(a_char != *b_char);
+            cur[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+/// Closest candidate within a third of the key's length (at least one edit)
+pub fn closest_key<'a>(
+    key: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let max_distance = (key.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn backticks<'a>(items: impl IntoIterator<Item = &'a str>) -> String {
+    items.into_iter().map(|item| format!("`{item}`")).collect::<Vec<_>>().join(", ")
+}
+
+/// Message for `key` not being accepted by `attribute`
+pub fn unknown_key_message(
+    key: &str,
+    attribute: Option<&str>,
+    valid_keys: &[&str],
+    attribute_keys: &AttributeKeys,
+) -> String {
+    let mut message = match attribute {
+        Some(attribute) => format!("unknown key `{key}` for `{attribute}`"),
+        None => format!("unknown key `{key}`"),
+    };
+    if let Some(suggestion) = closest_key(key, valid_keys.iter().copied()) {
+        message.push_str(&format!(" - did you mean `{suggestion}`?"));
+    } else {
+        let other_attributes = attribute_keys
+            .iter()
+            .filter(|(other, keys)| Some(*other) != attribute && keys.contains(&key))
+            .map(|(other, _)| *other)
+            .collect::<Vec<_>>();
+        if !other_attributes.is_empty() {
+            message.push_str(&format!(" - `{key}` is valid on {}", backticks(other_attributes)));
+            if let Some(attribute) = attribute {
+                message.push_str(&format!(", not `{attribute}`"));
+            }
+        }
+    }
+    if valid_keys.is_empty() {
+        message.push_str("\nthis attribute doesn't take any keys");
+    } else {
+        let mut valid_keys = valid_keys.to_vec();
+        valid_keys.sort_unstable();
+        valid_keys.dedup();
+        message.push_str(&format!("\nvalid keys: {}", backticks(valid_keys)));
+    }
+    message
+}
+
+/// Error for a top level `key` that isn't one of `valid_keys`, spanning the whole `meta`
+///
+/// Raised before darling parses the attribute, so the message doesn't depend on darling's wording
+/// and darling only ever sees the known keys.
+pub fn unknown_key_error(
+    meta: &NestedMeta,
+    key: &str,
+    attribute: Option<&str>,
+    valid_keys: &[&str],
+    attribute_keys: &AttributeKeys,
+) -> darling::Error {
+    darling::Error::custom(unknown_key_message(key, attribute, valid_keys, attribute_keys))
+        .with_span(meta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    const ATTRIBUTE_KEYS: &AttributeKeys = &[
+        ("auto_component", &["derive", "reflect", "register"]),
+        ("auto_resource", &["derive", "reflect", "register", "init"]),
+    ];
+
+    #[xtest]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("schedul", "schedule"), 1);
+        assert_eq!(levenshtein("reflects", "reflect"), 1);
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[xtest]
+    fn test_did_you_mean() {
+        let message = unknown_key_message(
+            "reflects",
+            Some("auto_component"),
+            &["derive", "reflect", "register"],
+            ATTRIBUTE_KEYS,
+        );
+        assert_eq!(
+            message,
+            "unknown key `reflects` for `auto_component` - did you mean `reflect`?\nvalid keys: `derive`, `reflect`, `register`"
+        );
+    }
+
+    #[xtest]
+    fn test_key_of_other_attribute() {
+        let message = unknown_key_message(
+            "init",
+            Some("auto_component"),
+            &["derive", "reflect", "register"],
+            ATTRIBUTE_KEYS,
+        );
+        assert_eq!(
+            message,
+            "unknown key `init` for `auto_component` - `init` is valid on `auto_resource`, not `auto_component`\nvalid keys: `derive`, `reflect`, `register`"
+        );
+    }
+}
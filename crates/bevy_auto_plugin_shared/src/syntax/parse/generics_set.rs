@@ -0,0 +1,149 @@
+use crate::syntax::ast::type_list::{
+    TypeListProduct,
+    TypeListProductValues,
+    parse_generic_arg,
+};
+use darling::ast::NestedMeta;
+use proc_macro2::TokenStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+use std::collections::HashMap;
+use syn::{
+    GenericArgument,
+    Meta,
+    Token,
+    parse::Parser,
+    punctuated::Punctuated,
+};
+
+/// meta injected by the `auto_generics_set!` callback: `__generics_set(NAME = [A, B, ...])`
+pub const GENERICS_SET_META_IDENT: &str = "__generics_set";
+
+/// Result of substituting named generics sets in attribute args
+#[derive(Debug)]
+pub enum GenericsSets {
+    /// every set reference was substituted (or there were none)
+    Resolved(TokenStream),
+    /// the set still has to be expanded by invoking its macro
+    Pending(syn::Path),
+}
+
+struct GenericsSetMeta {
+    path: syn::Path,
+    args: Vec<GenericArgument>,
+}
+
+impl syn::parse::Parse for GenericsSetMeta {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let path = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let content;
+        syn::bracketed!(content in input);
+        let args = Punctuated::<GenericArgument, Token![,]>::parse_terminated_with(
+            &content,
+            parse_generic_arg,
+        )?;
+        Ok(Self { path, args: args.into_iter().collect() })
+    }
+}
+
+fn path_key(path: &syn::Path) -> String {
+    path.to_token_stream().to_string()
+}
+
+/// Substitutes `product(Name = SET)` factors with the types injected by `auto_generics_set!`.
+///
+/// Args that don't parse as metas are returned untouched so the regular parser reports them.
+pub fn resolve_generics_sets(attr: TokenStream) -> syn::Result<GenericsSets> {
+    let parser = Punctuated::<NestedMeta, Token![,]>::parse_terminated;
+    let Ok(items) = parser.parse2(attr.clone()) else {
+        return Ok(GenericsSets::Resolved(attr));
+    };
+
+    let mut sets = HashMap::new();
+    let mut kept = Vec::with_capacity(items.len());
+    for item in items {
+        match item {
+            NestedMeta::Meta(Meta::List(list)) if list.path.is_ident(GENERICS_SET_META_IDENT) => {
+                let set = list.parse_args::<GenericsSetMeta>()?;
+                sets.insert(path_key(&set.path), set.args);
+            }
+            other => kept.push(other),
+        }
+    }
+
+    let mut changed = !sets.is_empty();
+    for item in kept.iter_mut() {
+        let NestedMeta::Meta(Meta::List(list)) = item else {
+            continue;
+        };
+        if !list.path.is_ident("generics") {
+            continue;
+        }
+        let Some(product) = TypeListProduct::maybe_parse(&list.tokens) else {
+            continue;
+        };
+        let mut product = product?;
+        for factor in product.0.iter_mut() {
+            let TypeListProductValues::Set(path) = &factor.values else {
+                continue;
+            };
+            let Some(args) = sets.get(&path_key(path)) else {
+                return Ok(GenericsSets::Pending(path.clone()));
+            };
+            factor.values = TypeListProductValues::List(args.clone());
+            changed = true;
+        }
+        list.tokens = quote! { #product };
+    }
+
+    if !changed {
+        return Ok(GenericsSets::Resolved(attr));
+    }
+    Ok(GenericsSets::Resolved(quote! { #(#kept),* }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_resolve_without_sets() -> syn::Result<()> {
+        let attr = quote! { plugin = Test, generics(product(A = [u8, u16])) };
+        let GenericsSets::Resolved(tokens) = resolve_generics_sets(attr.clone())? else {
+            panic!("expected resolved");
+        };
+        assert_eq!(tokens.to_string(), attr.to_string());
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_resolve_pending_set() -> syn::Result<()> {
+        let attr = quote! { plugin = Test, generics(product(A = [u8], B = sets::STATS)) };
+        let GenericsSets::Pending(path) = resolve_generics_sets(attr)? else {
+            panic!("expected pending");
+        };
+        assert_eq!(path.to_token_stream().to_string(), quote!(sets::STATS).to_string());
+        Ok(())
+    }
+
+    #[xtest]
+    fn test_resolve_injected_set() -> syn::Result<()> {
+        let attr = quote! {
+            __generics_set(sets::STATS = [Hp, Mp]),
+            plugin = Test,
+            generics(product(A = [u8], B = sets::STATS))
+        };
+        let GenericsSets::Resolved(tokens) = resolve_generics_sets(attr)? else {
+            panic!("expected resolved");
+        };
+        assert_eq!(
+            tokens.to_string(),
+            quote! { plugin = Test, generics(product(A = [u8], B = [Hp, Mp])) }.to_string()
+        );
+        Ok(())
+    }
+}
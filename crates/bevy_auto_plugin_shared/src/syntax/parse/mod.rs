@@ -1,3 +1,4 @@
+pub mod generics_set;
 pub mod item;
 pub mod rewrite;
 pub mod scrub_helpers;
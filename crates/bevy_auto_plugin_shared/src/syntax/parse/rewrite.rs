@@ -1,10 +1,10 @@
-use proc_macro2::TokenStream;
 #[cfg(feature = "compat_generics_angles")]
+use proc_macro2::Punct;
 use proc_macro2::{
     Delimiter,
     Group,
-    Punct,
     Spacing,
+    TokenStream,
     TokenTree,
 };
 
@@ -145,10 +145,59 @@ pub fn maybe_rewrite_generics_angles(tokens: TokenStream, field_name: &str) -> T
     }
 }
 
-#[cfg(all(test, feature = "compat_generics_angles"))]
+/// Rewrite top level `field_name = Type` to `field_name(Type)`.
+///
+/// Types like `&'static ChildOf` aren't valid expressions, so they can't be parsed as meta values.
+pub fn rewrite_type_value(tokens: TokenStream, field_name: &str) -> TokenStream {
+    let mut out = Vec::<TokenTree>::new();
+    let mut it = tokens.into_iter().peekable();
+    let mut prev_is_colon = false;
+
+    while let Some(tt) = it.next() {
+        let is_field = matches!(&tt, TokenTree::Ident(id) if id == field_name);
+        let next_is_eq = matches!(it.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '=' && p.spacing() == Spacing::Alone);
+        if !is_field || prev_is_colon || !next_is_eq {
+            prev_is_colon = matches!(&tt, TokenTree::Punct(p) if p.as_char() == ':');
+            out.push(tt);
+            continue;
+        }
+        it.next(); // consume '='
+
+        let mut captured = Vec::<TokenTree>::new();
+        let mut depth: usize = 0;
+        let mut prev_is_dash = false;
+        while let Some(next) = it.peek() {
+            if let TokenTree::Punct(p) = next {
+                match p.as_char() {
+                    ',' if depth == 0 => break,
+                    '<' => depth += 1,
+                    // `->` isn't a closing angle bracket
+                    '>' if !prev_is_dash => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                prev_is_dash = p.as_char() == '-' && p.spacing() == Spacing::Joint;
+            } else {
+                prev_is_dash = false;
+            }
+            captured.extend(it.next());
+        }
+
+        out.push(tt);
+        out.push(TokenTree::Group(Group::new(
+            Delimiter::Parenthesis,
+            captured.into_iter().collect(),
+        )));
+        prev_is_colon = false;
+    }
+
+    out.into_iter().collect()
+}
+
+#[cfg(test)]
 mod tests {
     #[cfg(feature = "compat_generics_angles")]
     use super::rewrite_generics_angles;
+    use super::rewrite_type_value;
     use internal_test_proc_macro::xtest;
     use quote::quote;
 
@@ -167,4 +216,24 @@ mod tests {
         let output = rewrite_generics_angles(input, "generics");
         assert_eq!(output.to_string(), quote!(foo(::generics = <T>, generics(U))).to_string());
     }
+
+    #[xtest]
+    fn rewrite_type_value_reference() {
+        let input = quote!(target(entity), traversal = &'static ChildOf, propagate);
+        let output = rewrite_type_value(input, "traversal");
+        assert_eq!(
+            output.to_string(),
+            quote!(target(entity), traversal(&'static ChildOf), propagate).to_string()
+        );
+    }
+
+    #[xtest]
+    fn rewrite_type_value_keeps_generic_commas() {
+        let input = quote!(traversal = &'static Foo<A, fn() -> B>, ::traversal = C);
+        let output = rewrite_type_value(input, "traversal");
+        assert_eq!(
+            output.to_string(),
+            quote!(traversal(&'static Foo<A, fn() -> B>), ::traversal = C).to_string()
+        );
+    }
 }
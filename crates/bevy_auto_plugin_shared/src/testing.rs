@@ -0,0 +1,193 @@
+//! Assertions for checking what a plugin adds to an [`App`]
+
+use crate::__private::auto_plugin_registry::{
+    AUTO_PLUGIN_REGISTRY_AFTER_BUILD,
+    AUTO_PLUGIN_REGISTRY_BEFORE_BUILD,
+};
+use bevy_app::{
+    App,
+    Plugin,
+};
+use bevy_ecs::{
+    event::Event,
+    observer::Observer,
+    reflect::AppTypeRegistry,
+    resource::Resource,
+    schedule::{
+        IntoSystemSet,
+        ScheduleLabel,
+        SystemSet,
+    },
+};
+use bevy_state::{
+    app::StatesPlugin,
+    state::{
+        State,
+        States,
+        setup_state_transitions_in_world,
+    },
+};
+use std::{
+    any::{
+        TypeId,
+        type_name,
+    },
+    fmt::Debug,
+};
+
+/// Number of `auto_*` entries bound to plugin `P`, before and after build combined
+///
+/// Read from the registry the macros fill at link time, so it doesn't need an [`App`].
+pub fn registry_entry_count<P: 'static>() -> usize {
+    let type_id = TypeId::of::<P>();
+    AUTO_PLUGIN_REGISTRY_BEFORE_BUILD.get_entries(type_id).len()
+        + AUTO_PLUGIN_REGISTRY_AFTER_BUILD.get_entries(type_id).len()
+}
+
+/// Panics if nothing is bound to plugin `P`
+///
+/// Catches items that were never linked in, e.g. a module that's no longer reachable.
+#[track_caller]
+pub fn assert_has_registry_entries<P: 'static>() {
+    assert!(
+        registry_entry_count::<P>() > 0,
+        "no `auto_*` entries are bound to `{}`",
+        type_name::<P>()
+    );
+}
+
+/// A headless [`App`] with a plugin added and built
+///
+/// Only [`StatesPlugin`] is added besides the plugin under test, so `init_state` works without
+/// pulling in rendering or windowing. It's skipped when the plugin under test already adds it.
+///
+/// Every assertion panics with the missing item's type name and returns `&Self` for chaining.
+pub struct AutoPluginTestApp {
+    app: App,
+}
+
+impl AutoPluginTestApp {
+    /// Builds a minimal app with `plugin`, running `finish` and `cleanup` like `App::run` would
+    pub fn new(plugin: impl Plugin) -> Self {
+        let mut app = App::new();
+        // `init_state` only needs the transition schedule, `StatesPlugin` is added after the build
+        // unless the plugin under test already added it
+        setup_state_transitions_in_world(app.world_mut());
+        app.add_plugins(plugin);
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        app.finish();
+        app.cleanup();
+        Self { app }
+    }
+
+    pub fn app(&self) -> &App {
+        &self.app
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    pub fn into_app(self) -> App {
+        self.app
+    }
+
+    /// Runs the schedules once, e.g. to apply state transitions or run startup systems
+    pub fn update(&mut self) -> &mut Self {
+        self.app.update();
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_plugin_added<P: Plugin>(&self) -> &Self {
+        assert!(self.app.is_plugin_added::<P>(), "plugin `{}` was not added", type_name::<P>());
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_registered_type<T: 'static>(&self) -> &Self {
+        let registered = self
+            .app
+            .world()
+            .get_resource::<AppTypeRegistry>()
+            .is_some_and(|registry| registry.read().contains(TypeId::of::<T>()));
+        assert!(registered, "type `{}` is not registered", type_name::<T>());
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_resource_initialized<R: Resource>(&self) -> &Self {
+        assert!(
+            self.app.world().contains_resource::<R>(),
+            "resource `{}` is not initialized",
+            type_name::<R>()
+        );
+        self
+    }
+
+    /// Panics unless `S` was initialized with `init_state`/`insert_state` or is an active sub state
+    #[track_caller]
+    pub fn assert_state<S: States>(&self) -> &Self {
+        assert!(
+            self.app.world().contains_resource::<State<S>>(),
+            "state `{}` is not initialized",
+            type_name::<S>()
+        );
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_state_eq<S: States + Debug>(&self, expected: S) -> &Self {
+        self.assert_state::<S>();
+        let state = self.app.world().resource::<State<S>>().get();
+        assert_eq!(state, &expected, "state `{}`", type_name::<S>());
+        self
+    }
+
+    /// Panics unless `system` was added to `schedule`
+    ///
+    /// Systems are matched the same way `before`/`after` match them, so this works for systems
+    /// added with `run_if` or `in_set`, but not for piped or adapted systems.
+    #[track_caller]
+    pub fn assert_system_in_schedule<M>(
+        &self,
+        schedule: impl ScheduleLabel,
+        system: impl IntoSystemSet<M>,
+    ) -> &Self {
+        let set = system.into_system_set().intern();
+        let schedule_label = schedule.intern();
+        let Some(schedule) = self.app.get_schedule(schedule_label) else {
+            panic!("schedule `{schedule_label:?}` does not exist");
+        };
+        // systems move from the graph to the executable once the schedule first runs
+        let in_graph = schedule
+            .graph()
+            .systems
+            .iter()
+            .any(|(_, system, _)| system.default_system_sets().contains(&set));
+        let in_executable = schedule.systems().is_ok_and(|mut systems| {
+            systems.any(|(_, system)| system.default_system_sets().contains(&set))
+        });
+        assert!(
+            in_graph || in_executable,
+            "system `{set:?}` is not in schedule `{schedule_label:?}`"
+        );
+        self
+    }
+
+    #[track_caller]
+    pub fn assert_observer_for<E: Event>(&self) -> &Self {
+        let world = self.app.world();
+        let observed = world.event_key::<E>().is_some_and(|event_key| {
+            world.try_query::<&Observer>().is_some_and(|mut query| {
+                query
+                    .iter(world)
+                    .any(|observer| observer.descriptor().event_keys().contains(&event_key))
+            })
+        });
+        assert!(observed, "no observer for event `{}`", type_name::<E>());
+        self
+    }
+}
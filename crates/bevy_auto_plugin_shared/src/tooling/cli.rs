@@ -0,0 +1,234 @@
+//! The `cargo auto-plugin <command>` command line
+
+use crate::tooling::{
+    eject::eject,
+    format_rewritten,
+    manifest::manifest_dir_of,
+    migrate::migrate,
+    report::Report,
+};
+use std::{
+    fs,
+    io::Write,
+    ops::Range,
+    path::{
+        Path,
+        PathBuf,
+    },
+    process::{
+        Command,
+        ExitCode,
+        Stdio,
+    },
+};
+
+const USAGE: &str = "\
+usage: cargo auto-plugin <command>
+
+commands:
+    eject <file> [--plugin <name>] [--in-place]
+        replace the `auto_*` attributes bound to a plugin with an explicit `Plugin::build`,
+        printing the result unless `--in-place` is passed
+    migrate <file> [--plugin <name>] [--in-place]
+        move the calls of a hand-written `Plugin::build` onto their items as `auto_*` attributes,
+        calls without an attribute equivalent stay in `build`
+    report [<dir>] [--format markdown|json|dot]
+        list the plugins declared under <dir> (default `.`) and everything registered with them";
+
+/// What a command did to the file
+struct Rewrite {
+    /// e.g. "ejected 3 attribute(s) bound to `MyPlugin`"
+    summary: String,
+    source: String,
+    rewritten: Vec<Range<usize>>,
+    warnings: Vec<String>,
+}
+
+/// Runs `cargo auto-plugin` with the arguments after the binary name
+pub fn main(args: impl IntoIterator<Item = String>) -> ExitCode {
+    // `cargo auto-plugin ..` passes the subcommand name along
+    let args = args.into_iter().skip_while(|arg| arg == "auto-plugin").collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("eject") => run(&args[1..], |source, plugin, manifest_dir| {
+            let ejected = eject(source, plugin, manifest_dir)?;
+            Ok(Rewrite {
+                summary: format!(
+                    "ejected {} attribute(s) bound to `{}`",
+                    ejected.entries, ejected.plugin
+                ),
+                source: ejected.source,
+                rewritten: ejected.rewritten,
+                warnings: ejected.warnings,
+            })
+        }),
+        Some("migrate") => run(&args[1..], |source, plugin, manifest_dir| {
+            let migrated = migrate(source, plugin, manifest_dir)?;
+            Ok(Rewrite {
+                summary: format!(
+                    "migrated {} call(s) of `{}::build`",
+                    migrated.entries, migrated.plugin
+                ),
+                source: migrated.source,
+                rewritten: migrated.rewritten,
+                warnings: migrated.warnings,
+            })
+        }),
+        Some("report") => report(&args[1..]),
+        Some("-h" | "--help") => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Some(other) => Err(format!("unknown command `{other}`\n\n{USAGE}")),
+        None => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a command that rewrites one file, `<file> [--plugin <name>] [--in-place]`
+fn run(
+    args: &[String],
+    command: impl FnOnce(&str, Option<&str>, &Path) -> syn::Result<Rewrite>,
+) -> Result<(), String> {
+    let mut file = None;
+    let mut plugin = None;
+    let mut in_place = false;
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--plugin" => {
+                plugin = Some(args.next().ok_or("`--plugin` needs a plugin name")?.as_str());
+            }
+            "--in-place" => in_place = true,
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown flag `{flag}`\n\n{USAGE}"));
+            }
+            path if file.is_none() => file = Some(PathBuf::from(path)),
+            extra => return Err(format!("unexpected argument `{extra}`\n\n{USAGE}")),
+        }
+    }
+    let file = file.ok_or_else(|| format!("missing <file>\n\n{USAGE}"))?;
+    let source = fs::read_to_string(&file)
+        .map_err(|err| format!("failed to read {}: {err}", file.display()))?;
+    let manifest_dir = find_manifest_dir(&file)?;
+
+    let rewrite = command(&source, plugin, &manifest_dir).map_err(|err| describe(&file, &err))?;
+    for warning in &rewrite.warnings {
+        eprintln!("warning: {}: {warning}", file.display());
+    }
+    let output = format_rewritten(&rewrite.source, &rewrite.rewritten, rustfmt);
+    if in_place {
+        fs::write(&file, output)
+            .map_err(|err| format!("failed to write {}: {err}", file.display()))?;
+        eprintln!("{} in {}", rewrite.summary, file.display());
+    } else {
+        print!("{output}");
+    }
+    Ok(())
+}
+
+/// `report [<dir>] [--format markdown|json|dot]`
+fn report(args: &[String]) -> Result<(), String> {
+    let mut dir = None;
+    let mut format = "markdown";
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = args.next().ok_or("`--format` needs a format")?.as_str(),
+            flag if flag.starts_with('-') => {
+                return Err(format!("unknown flag `{flag}`\n\n{USAGE}"));
+            }
+            path if dir.is_none() => dir = Some(PathBuf::from(path)),
+            extra => return Err(format!("unexpected argument `{extra}`\n\n{USAGE}")),
+        }
+    }
+    if !matches!(format, "markdown" | "json" | "dot") {
+        return Err(format!("unknown format `{format}`, expected markdown, json or dot"));
+    }
+    let dir = dir.unwrap_or_else(|| PathBuf::from("."));
+    let mut files = Vec::new();
+    collect_sources(&dir, &mut files)
+        .map_err(|err| format!("failed to read {}: {err}", dir.display()))?;
+    files.sort();
+
+    let mut report = Report::default();
+    for file in &files {
+        let source = fs::read_to_string(file)
+            .map_err(|err| format!("failed to read {}: {err}", file.display()))?;
+        let name = file.strip_prefix(&dir).unwrap_or(file).display().to_string();
+        // a workspace spans several manifests
+        let manifest_dir = find_manifest_dir(file)?;
+        if let Err(err) = report.scan(&name, &source, &manifest_dir) {
+            eprintln!("warning: {}", describe(file, &err));
+        }
+    }
+    report.sort();
+    for note in &report.notes {
+        eprintln!("note: {note}");
+    }
+    match format {
+        "json" => println!("{}", report.to_json()),
+        "dot" => print!("{}", report.to_dot()),
+        _ => print!("{}", report.to_markdown()),
+    }
+    Ok(())
+}
+
+/// Every `.rs` file under `dir`, skipping build output and hidden directories
+fn collect_sources(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy();
+            name.starts_with('.') || name == "target"
+        });
+        if path.is_dir() {
+            if !hidden {
+                collect_sources(&path, files)?;
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// The macros resolve bevy crate paths from the manifest of the crate being expanded
+fn find_manifest_dir(file: &Path) -> Result<PathBuf, String> {
+    manifest_dir_of(file)
+        .ok_or_else(|| format!("no Cargo.toml found in the directories above {}", file.display()))
+}
+
+fn describe(file: &Path, err: &syn::Error) -> String {
+    err.clone()
+        .into_iter()
+        .map(|err| {
+            let start = err.span().start();
+            if start.line == 0 {
+                format!("{}: {err}", file.display())
+            } else {
+                format!("{}:{}:{}: {err}", file.display(), start.line, start.column + 1)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Best effort, the generated code is valid but unformatted without it
+fn rustfmt(source: &str) -> Option<String> {
+    let mut child = Command::new("rustfmt")
+        .args(["--edition", "2024", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    child.stdin.take()?.write_all(source.as_bytes()).ok()?;
+    let output = child.wait_with_output().ok()?;
+    output.status.success().then(|| String::from_utf8(output.stdout).ok()).flatten()
+}
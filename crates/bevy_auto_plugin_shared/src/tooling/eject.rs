@@ -0,0 +1,873 @@
+//! `cargo auto-plugin eject`: replaces the `auto_*` attributes bound to one plugin with an explicit
+//! `Plugin::build`
+//!
+//! Attributes are expanded with the same parsers and emitters the macros use, in the order the
+//! registry would run them, but the statements are written into the plugin instead of registry
+//! entries. Attributes bound to other plugins are left untouched.
+
+use crate::{
+    __private::{
+        expand::attr::auto_bind_plugin::auto_bind_plugin_inner,
+        paths,
+    },
+    codegen::ExpandAttrs,
+    macro_api::prelude::*,
+    syntax::{
+        extensions::item::ItemAttrsExt,
+        parse::generics_set::{
+            GenericsSets,
+            resolve_generics_sets,
+        },
+    },
+    tooling::{
+        Edit,
+        SourceMap,
+        apply_edits_with_spans,
+        attr_to_string,
+        manifest::with_manifest_dir,
+    },
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+use std::{
+    collections::VecDeque,
+    ops::Range,
+};
+use syn::{
+    Attribute,
+    Expr,
+    Ident,
+    ImplItem,
+    Item,
+    Meta,
+    Path,
+    Token,
+    parse::Parser,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    visit_mut::{
+        self,
+        VisitMut,
+    },
+};
+
+pub struct Ejected {
+    pub plugin: Ident,
+    pub source: String,
+    /// byte ranges of `source` that were written by the eject
+    pub rewritten: Vec<Range<usize>>,
+    /// number of attributes turned into `build` statements
+    pub entries: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Ejects `plugin`, or the only `#[derive(AutoPlugin)]` type in `source` when `None`
+///
+/// Bevy crate paths are resolved from the `Cargo.toml` in `manifest_dir`.
+pub fn eject(
+    source: &str,
+    plugin: Option<&str>,
+    manifest_dir: &std::path::Path,
+) -> syn::Result<Ejected> {
+    with_manifest_dir(manifest_dir, || eject_inner(source, plugin))
+}
+
+fn eject_inner(source: &str, plugin: Option<&str>) -> syn::Result<Ejected> {
+    let file = syn::parse_file(source)?;
+    let map = SourceMap::new(source);
+    let plugin_item = find_plugin(&file.items, plugin)?;
+    let plugin_ident = plugin_item.get_ident().cloned().expect("plugins are structs or enums");
+    let build = find_build(&file.items, plugin_item, &plugin_ident)?;
+
+    let mut ejector = Ejector {
+        map: &map,
+        plugin: plugin_ident.clone(),
+        app_param: build.app_param(),
+        edits: vec![],
+        before_build: vec![],
+        after_build: vec![],
+        warnings: vec![],
+    };
+    ejector.visit_items(&file.items)?;
+    ejector.edit_plugin(plugin_item, &build)?;
+
+    let entries = ejector.before_build.len() + ejector.after_build.len();
+    let mut warnings = ejector.warnings;
+    warnings.push(format!(
+        "`auto_*` attributes bound to `{plugin_ident}` outside this file no longer run, eject them too"
+    ));
+    let (source, rewritten) = apply_edits_with_spans(source, ejector.edits);
+    if let Err(err) = syn::parse_file(&source) {
+        return Err(syn::Error::new(
+            plugin_ident.span(),
+            format!("ejecting `{plugin_ident}` produced invalid code, please report this: {err}"),
+        ));
+    }
+    Ok(Ejected { plugin: plugin_ident, source, rewritten, entries, warnings })
+}
+
+enum Build<'a> {
+    /// `#[auto_plugin(impl_plugin_trait)]`, a `Plugin` impl gets generated
+    ImplPluginTrait,
+    /// `#[auto_plugin]` on a build fn, the statements go into its body
+    Fn { attr: &'a Attribute, block: &'a syn::Block, app_param: Ident },
+}
+
+impl Build<'_> {
+    fn app_param(&self) -> Ident {
+        match self {
+            Build::ImplPluginTrait => quote::format_ident!("app"),
+            Build::Fn { app_param, .. } => app_param.clone(),
+        }
+    }
+}
+
+/// Last path segment of an attribute, e.g. `auto_component` for `::bevy_auto_plugin::prelude::auto_component`
+fn attr_name(attr: &Attribute) -> Option<String> {
+    attr.path().segments.last().map(|segment| segment.ident.to_string())
+}
+
+fn attr_args(attr: &Attribute) -> MacroStream {
+    match &attr.meta {
+        Meta::List(list) => list.tokens.clone(),
+        _ => MacroStream::new(),
+    }
+}
+
+fn derives(attr: &Attribute) -> syn::Result<Option<Punctuated<Path, Token![,]>>> {
+    if !attr.path().is_ident("derive") {
+        return Ok(None);
+    }
+    attr.parse_args_with(Punctuated::parse_terminated).map(Some)
+}
+
+fn is_auto_plugin_derive(path: &Path) -> bool {
+    path.segments.last().is_some_and(|segment| segment.ident == "AutoPlugin")
+}
+
+pub(super) fn derives_auto_plugin(item: &Item) -> bool {
+    item.attrs().unwrap_or_default().iter().any(|attr| {
+        derives(attr).ok().flatten().is_some_and(|paths| paths.iter().any(is_auto_plugin_derive))
+    })
+}
+
+fn visit_all<'a>(items: &'a [Item], f: &mut impl FnMut(&'a Item)) {
+    for item in items {
+        f(item);
+        if let Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) = item {
+            visit_all(items, f);
+        }
+    }
+}
+
+fn find_plugin<'a>(items: &'a [Item], plugin: Option<&str>) -> syn::Result<&'a Item> {
+    let mut plugins = vec![];
+    visit_all(items, &mut |item| {
+        if matches!(item, Item::Struct(_) | Item::Enum(_)) && derives_auto_plugin(item) {
+            plugins.push(item);
+        }
+    });
+    let names = || {
+        plugins
+            .iter()
+            .filter_map(|item| item.get_ident())
+            .map(|ident| format!("`{ident}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let found = match plugin {
+        Some(name) => {
+            plugins.iter().find(|item| item.get_ident().is_some_and(|ident| ident == name))
+        }
+        None if plugins.len() == 1 => plugins.first(),
+        None if plugins.is_empty() => None,
+        None => {
+            return Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!("more than one plugin derives `AutoPlugin`, pick one of {}", names()),
+            ));
+        }
+    };
+    found.copied().ok_or_else(|| {
+        let message = match plugin {
+            Some(name) if !plugins.is_empty() => {
+                format!("no `#[derive(AutoPlugin)]` type named `{name}`, found {}", names())
+            }
+            Some(name) => format!("no `#[derive(AutoPlugin)]` type named `{name}` in this file"),
+            None => "no `#[derive(AutoPlugin)]` type in this file".to_string(),
+        };
+        syn::Error::new(proc_macro2::Span::call_site(), message)
+    })
+}
+
+fn find_build<'a>(items: &'a [Item], plugin: &'a Item, ident: &Ident) -> syn::Result<Build<'a>> {
+    let (generics, attrs) = match plugin {
+        Item::Struct(item) => (&item.generics, &item.attrs),
+        Item::Enum(item) => (&item.generics, &item.attrs),
+        _ => unreachable!(),
+    };
+    if !generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(generics, "generic plugins can't be ejected"));
+    }
+    let mut impl_plugin_trait = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("auto_plugin")) {
+        if matches!(attr.meta, Meta::Path(_)) {
+            continue;
+        }
+        for meta in attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)? {
+            if meta.path().is_ident("impl_plugin_trait") && matches!(meta, Meta::Path(_)) {
+                impl_plugin_trait = true;
+            } else {
+                return Err(syn::Error::new_spanned(
+                    meta,
+                    "eject only supports `impl_plugin_trait` here, move the other plugin options into the code by hand first",
+                ));
+            }
+        }
+    }
+    if impl_plugin_trait {
+        return Ok(Build::ImplPluginTrait);
+    }
+
+    let mut found = None;
+    visit_all(items, &mut |item| {
+        let mut check = |fn_attrs: &'a [Attribute], sig: &syn::Signature, block: &'a syn::Block| {
+            let Some(attr) =
+                fn_attrs.iter().find(|attr| attr_name(attr).as_deref() == Some("auto_plugin"))
+            else {
+                return;
+            };
+            found.get_or_insert((attr, sig.clone(), block, fn_attrs.to_vec()));
+        };
+        match item {
+            Item::Impl(item_impl) => {
+                let is_plugin_impl = item_impl.trait_.as_ref().is_some_and(|(_, path, _)| {
+                    path.segments.last().is_some_and(|segment| segment.ident == "Plugin")
+                });
+                let is_self = matches!(&*item_impl.self_ty, syn::Type::Path(ty)
+                    if ty.path.segments.last().is_some_and(|segment| &segment.ident == ident));
+                if !(is_plugin_impl && is_self) {
+                    return;
+                }
+                for impl_item in &item_impl.items {
+                    if let ImplItem::Fn(impl_fn) = impl_item {
+                        check(&impl_fn.attrs, &impl_fn.sig, &impl_fn.block);
+                    }
+                }
+            }
+            Item::Fn(item_fn) => {
+                let binds_plugin = item_fn.attrs.iter().any(|attr| {
+                    attr_name(attr).as_deref() == Some("auto_plugin")
+                        && attr
+                            .parse_args::<AutoPluginFnArgs>()
+                            .ok()
+                            .and_then(|args| args.plugin)
+                            .is_some_and(|path| {
+                                path.segments.last().is_some_and(|segment| &segment.ident == ident)
+                            })
+                });
+                if binds_plugin {
+                    check(&item_fn.attrs, &item_fn.sig, &item_fn.block);
+                }
+            }
+            _ => {}
+        }
+    });
+    let Some((attr, sig, block, fn_attrs)) = found else {
+        return Err(syn::Error::new(
+            ident.span(),
+            format!(
+                "no `#[auto_plugin]` build fn for `{ident}` in this file, add `#[auto_plugin(impl_plugin_trait)]` or eject the file with its build fn"
+            ),
+        ));
+    };
+    if !matches!(attr.meta, Meta::Path(_))
+        && !attr.parse_args::<AutoPluginFnArgs>()?.generics.is_empty()
+    {
+        return Err(syn::Error::new_spanned(attr, "generic plugins can't be ejected"));
+    }
+    let item_fn = syn::ItemFn {
+        attrs: fn_attrs,
+        vis: syn::Visibility::Inherited,
+        sig,
+        block: Box::new(block.clone()),
+    };
+    let app_param = resolve_app_param_name(&item_fn)?.clone();
+    Ok(Build::Fn { attr, block, app_param })
+}
+
+/// Drops the calls that only exist to apply plugin `defaults(...)`
+///
+/// Ejected plugins can't have defaults, so these are no-ops that would otherwise keep the
+/// ejected code tied to the `AutoPlugin` trait.
+fn simplify(stmts: MacroStream) -> syn::Result<MacroStream> {
+    struct Simplify;
+
+    impl VisitMut for Simplify {
+        fn visit_expr_mut(&mut self, expr: &mut Expr) {
+            visit_mut::visit_expr_mut(self, expr);
+            let Expr::Call(call) = expr else {
+                return;
+            };
+            let Expr::Path(func) = &*call.func else {
+                return;
+            };
+            let Some(name) = func.path.segments.last().map(|segment| segment.ident.to_string())
+            else {
+                return;
+            };
+            let identity = match name.as_str() {
+                "default_system_in_set" | "default_system_run_if" => func.qself.is_some(),
+                "into_configs" => {
+                    func.path.segments.iter().any(|segment| segment.ident == "IntoScheduleConfigs")
+                }
+                _ => false,
+            };
+            if identity && call.args.len() == 1 {
+                *expr = call.args[0].clone();
+            }
+        }
+    }
+
+    let mut block = syn::parse2::<syn::Block>(quote!({ #stmts }))?;
+    Simplify.visit_block_mut(&mut block);
+    let stmts = block.stmts;
+    Ok(quote!(#(#stmts)*))
+}
+
+struct Tagged {
+    attr: Attribute,
+    /// index of the attribute in the source this one replaces
+    origin: usize,
+    modified: bool,
+}
+
+enum Step {
+    /// bound to another plugin, or not ours to expand
+    Keep,
+    /// `auto_bind_plugin` for another plugin, the attributes below it belong to that plugin too
+    KeepRest,
+    /// rewrite expanded into the attributes it stands for
+    Expand { attrs: Vec<Attribute>, items: Vec<MacroStream> },
+    /// `auto_bind_plugin` injected the plugin into the attributes below it
+    Bind { attrs: Vec<Attribute> },
+    /// action turned into statements
+    Entry { after_build: bool, stmts: MacroStream, item: Box<Item> },
+}
+
+struct Ejector<'a> {
+    map: &'a SourceMap<'a>,
+    plugin: Ident,
+    app_param: Ident,
+    edits: Vec<Edit>,
+    before_build: Vec<MacroStream>,
+    after_build: Vec<MacroStream>,
+    warnings: Vec<String>,
+}
+
+macro_rules! eject_attr_dispatch {
+    ($self:ident, $name:expr, $attr:ident, $input:ident;
+        actions: [$($action:ty),+ $(,)?];
+        rewrites: [$($rewrite:ty),+ $(,)?] $(;)?
+    ) => {{
+        $(
+            if $name == <$action as AttributeIdent>::IDENT {
+                return $self.eject_action::<$action>($attr, $input);
+            }
+        )+
+        $(
+            if $name == <$rewrite as AttributeIdent>::IDENT {
+                return $self.eject_rewrite::<$rewrite>($attr, $input);
+            }
+        )+
+    }};
+}
+
+impl Ejector<'_> {
+    fn is_plugin(&self, path: &Path) -> bool {
+        path.segments.last().is_some_and(|segment| segment.ident == self.plugin)
+    }
+
+    fn visit_items(&mut self, items: &[Item]) -> syn::Result<()> {
+        for item in items {
+            self.eject_item(item)?;
+            match item {
+                Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) => {
+                    self.visit_items(items)?;
+                }
+                Item::Impl(item_impl)
+                    if item_impl
+                        .attrs
+                        .iter()
+                        .any(|attr| attr_name(attr).as_deref() == Some("auto_assoc_fns")) =>
+                {
+                    self.warnings.push(format!(
+                        "line {}: `auto_assoc_fns` impls are left as is",
+                        item_impl.impl_token.span.start().line
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn resolve_generics_sets(&self, attr: MacroStream) -> syn::Result<MacroStream> {
+        match resolve_generics_sets(attr)? {
+            GenericsSets::Resolved(attr) => Ok(attr),
+            GenericsSets::Pending(set) => Err(syn::Error::new_spanned(
+                &set,
+                format!(
+                    "eject can't expand generics sets, replace `{}` with its types first",
+                    set.to_token_stream()
+                ),
+            )),
+        }
+    }
+
+    fn eject_bind_plugin(&self, attr: MacroStream, input: MacroStream) -> syn::Result<Step> {
+        let bind = ItemAttribute::<Composed<Nothing, WithPlugin>, AllowAny>::from_attr_input(
+            attr.clone(),
+            input.clone(),
+            Context::default(),
+        )?;
+        if !self.is_plugin(bind.args.plugin()) {
+            return Ok(Step::KeepRest);
+        }
+        let mut item =
+            syn::parse2::<Item>(auto_bind_plugin_inner(attr, input, Context::default())?)?;
+        let attrs = item.take_attrs().unwrap_or_default();
+        Ok(Step::Bind { attrs })
+    }
+
+    fn eject_attr(&self, name: &str, attr: MacroStream, input: MacroStream) -> syn::Result<Step> {
+        eject_attr_dispatch! {
+            self, name, attr, input;
+            actions: [
+                IaRunOnBuild,
+                IaRegisterType,
+                IaAddMessage,
+                IaInitResource,
+                IaInsertResource,
+                IaInitState,
+                IaInitSubState,
+                IaName,
+                IaRegisterStateType,
+                IaAddSystem,
+                IaAddObserver,
+                IaAddPlugin,
+                IaConfigureSystemSet,
+                IaAutoPluginBuildHook,
+            ];
+            rewrites: [
+                IaBundle,
+                IaComponent,
+                IaRelationship,
+                IaResource,
+                IaSystem,
+                IaEvent,
+                IaMessage,
+                IaObserver,
+                IaState,
+                IaSubState,
+            ];
+        }
+        Ok(Step::Keep)
+    }
+
+    fn eject_action<T>(&self, attr: MacroStream, input: MacroStream) -> syn::Result<Step>
+    where
+        T: ItemAttributeArgs
+            + Clone
+            + ItemAttributeParse
+            + ItemAttributeInput
+            + ItemAttributeTargetMut
+            + ItemAttributeContext
+            + ItemAttributePlugin,
+        AppMutationEmitter<T>: EmitAppMutationTokens,
+    {
+        let attr = self.resolve_generics_sets(attr)?;
+        let args = T::from_attr_input_with_context(attr, input, Context::default())?;
+        if !self.is_plugin(args.plugin()) {
+            return Ok(Step::Keep);
+        }
+        let mut emitter = AppMutationEmitter { args, app_param: self.app_param.clone() };
+        emitter.post_process_inner_item().map_err(|(_, err)| err)?;
+        let after_build = emitter.args.plugin_after_build().unwrap_or(false);
+        let mut stmts = MacroStream::new();
+        match emitter.args.input_item().use_targets()? {
+            None => emitter.to_app_mutation_tokens(&mut stmts, &self.app_param)?,
+            Some(targets) => {
+                for target in targets {
+                    let target = match target {
+                        UseTarget::Item(path) => path,
+                        UseTarget::Module(module) => {
+                            return Err(syn::Error::new_spanned(
+                                module,
+                                "eject can't expand module imports, import the items by name first",
+                            ));
+                        }
+                    };
+                    let mut args = emitter.args.clone();
+                    args.set_target(target);
+                    AppMutationEmitter { args, app_param: self.app_param.clone() }
+                        .to_app_mutation_tokens(&mut stmts, &self.app_param)?;
+                }
+            }
+        }
+        let item = Box::new(syn::parse2(emitter.args.input_item().to_token_stream())?);
+        Ok(Step::Entry { after_build, stmts: simplify(stmts)?, item })
+    }
+
+    fn eject_rewrite<T>(&self, attr: MacroStream, input: MacroStream) -> syn::Result<Step>
+    where
+        T: ItemAttributeArgs
+            + ItemAttributeParse
+            + ItemAttributeInput
+            + ItemAttributeContext
+            + ItemAttributePlugin,
+        AttrExpansionEmitter<T>: AttrExpansionEmitterToExpandAttr,
+    {
+        let attr = self.resolve_generics_sets(attr)?;
+        let args = T::from_attr_input_with_context(attr, input, Context::default())?;
+        if !self.is_plugin(args.plugin()) {
+            return Ok(Step::Keep);
+        }
+        let emitter = AttrExpansionEmitter::from_item_attribute(args);
+        emitter.validate()?;
+        let mut expand_attrs = ExpandAttrs::default();
+        emitter.to_expand_attrs(&mut expand_attrs);
+        let mut attrs = vec![];
+        for tokens in expand_attrs.attrs {
+            attrs.extend(Attribute::parse_outer.parse2(tokens)?);
+        }
+        // ejectable plugins have no `defaults(...)`, so a bare `reflect` has nothing to register
+        let items = expand_attrs
+            .items
+            .into_iter()
+            .filter(|tokens| {
+                !syn::parse2::<syn::ItemFn>(tokens.clone()).is_ok_and(|item| {
+                    item.sig.ident.to_string().starts_with(REFLECT_DEFAULTS_FN_PREFIX)
+                })
+            })
+            .chain(expand_attrs.use_items)
+            .collect();
+        Ok(Step::Expand { attrs, items })
+    }
+
+    fn eject_item(&mut self, item: &Item) -> syn::Result<()> {
+        let Some(source_attrs) = item.attrs() else {
+            return Ok(());
+        };
+        if !source_attrs
+            .iter()
+            .any(|attr| attr_name(attr).is_some_and(|name| name.starts_with("auto_")))
+        {
+            return Ok(());
+        }
+        let mut body = item.clone();
+        let _ = body.take_attrs();
+        let source_body = body.to_token_stream().to_string();
+
+        let mut rest = source_attrs
+            .iter()
+            .enumerate()
+            .map(|(origin, attr)| Tagged { attr: attr.clone(), origin, modified: false })
+            .collect::<VecDeque<_>>();
+        let mut kept = vec![];
+        let mut items = vec![];
+        let mut changed = false;
+        while let Some(tagged) = rest.pop_front() {
+            let Some(name) = attr_name(&tagged.attr) else {
+                kept.push(tagged);
+                continue;
+            };
+            let input = |attrs: Vec<Attribute>| {
+                let mut input = body.clone();
+                let _ = input.put_attrs(attrs);
+                input.to_token_stream()
+            };
+            let below = rest.iter().map(|tagged| tagged.attr.clone()).collect::<Vec<_>>();
+            let step = if name == "auto_bind_plugin" {
+                self.eject_bind_plugin(attr_args(&tagged.attr), input(below))?
+            } else {
+                // unlike the macros, eject can show the attributes above this one too, e.g. a
+                // `#[derive(Reflect)]` that lets `register_type` skip its `Reflect` check
+                let above = kept.iter().map(|tagged| tagged.attr.clone());
+                self.eject_attr(
+                    &name,
+                    attr_args(&tagged.attr),
+                    input(above.chain(below).collect()),
+                )?
+            };
+            match step {
+                Step::Keep => kept.push(tagged),
+                Step::KeepRest => {
+                    kept.push(tagged);
+                    kept.extend(rest.drain(..));
+                }
+                Step::Expand { attrs, items: expanded_items } => {
+                    changed = true;
+                    items.extend(expanded_items);
+                    for attr in attrs.into_iter().rev() {
+                        rest.push_front(Tagged { attr, origin: tagged.origin, modified: true });
+                    }
+                }
+                Step::Bind { attrs } => {
+                    changed = true;
+                    for (tagged, attr) in rest.iter_mut().zip(attrs) {
+                        if tagged.attr != attr {
+                            tagged.attr = attr;
+                            tagged.modified = true;
+                        }
+                    }
+                }
+                Step::Entry { after_build, stmts, item } => {
+                    changed = true;
+                    if after_build {
+                        self.after_build.push(stmts);
+                    } else {
+                        self.before_build.push(stmts);
+                    }
+                    body = *item;
+                    let _ = body.take_attrs();
+                }
+            }
+        }
+        if !changed {
+            return Ok(());
+        }
+
+        let map = self.map;
+        let item_start = source_attrs
+            .first()
+            .map(|attr| map.start(attr.pound_token.span))
+            .unwrap_or_else(|| map.start(item.span()));
+        let indent = map.indent_at(item_start);
+        let items_text =
+            items.iter().map(|tokens| format!("{tokens}\n{indent}")).collect::<String>();
+
+        if body.to_token_stream().to_string() != source_body {
+            // the item itself changed, so it's rewritten as a whole
+            let attrs =
+                kept.iter().map(|tagged| format!("{}\n{indent}", attr_to_string(&tagged.attr)));
+            let text = format!("{}{}", attrs.collect::<String>(), body.to_token_stream());
+            let item_end = map.end(item.span());
+            self.edits.push(Edit::replace(item_start..item_end, format!("{items_text}{text}")));
+            return Ok(());
+        }
+
+        if !items_text.is_empty() {
+            self.edits.push(Edit::insert(item_start, items_text));
+        }
+        for (origin, attr) in source_attrs.iter().enumerate() {
+            let survivors =
+                kept.iter().filter(|tagged| tagged.origin == origin).collect::<Vec<_>>();
+            if let [tagged] = survivors.as_slice()
+                && !tagged.modified
+            {
+                continue;
+            }
+            let start = map.start(attr.pound_token.span);
+            let end = map.skip_whitespace(map.end(attr.bracket_token.span.close()));
+            let text = survivors
+                .iter()
+                .map(|tagged| format!("{}\n{indent}", attr_to_string(&tagged.attr)))
+                .collect::<String>();
+            self.edits.push(Edit::replace(start..end, text));
+        }
+        Ok(())
+    }
+
+    fn remove_attr(&mut self, attr: &Attribute) {
+        let start = self.map.start(attr.pound_token.span);
+        let end = self.map.skip_whitespace(self.map.end(attr.bracket_token.span.close()));
+        self.edits.push(Edit::remove(start..end));
+    }
+
+    fn edit_plugin(&mut self, plugin_item: &Item, build: &Build) -> syn::Result<()> {
+        for attr in plugin_item.attrs().unwrap_or_default() {
+            if attr.path().is_ident("auto_plugin") {
+                self.remove_attr(attr);
+            }
+            let Some(derives) = derives(attr)? else {
+                continue;
+            };
+            if !derives.iter().any(is_auto_plugin_derive) {
+                continue;
+            }
+            let remaining = derives.into_iter().filter(|path| !is_auto_plugin_derive(path));
+            let remaining = remaining.collect::<Punctuated<Path, Token![,]>>();
+            if remaining.is_empty() {
+                self.remove_attr(attr);
+            } else {
+                let start = self.map.start(attr.pound_token.span);
+                let end = self.map.end(attr.bracket_token.span.close());
+                self.edits
+                    .push(Edit::replace(start..end, quote!(#[derive(#remaining)]).to_string()));
+            }
+        }
+
+        let app_param = &self.app_param;
+        let before_build = &self.before_build;
+        let after_build = &self.after_build;
+        match build {
+            Build::ImplPluginTrait => {
+                let app_root = paths::app::app_root_path();
+                let plugin = &self.plugin;
+                let plugin_impl = quote! {
+                    impl #app_root::Plugin for #plugin {
+                        fn build(&self, #app_param: &mut #app_root::App) {
+                            #(#before_build)*
+                            #(#after_build)*
+                        }
+                    }
+                };
+                let item_end = self.map.end(plugin_item.span());
+                let indent = self.map.indent_at(item_end);
+                self.edits.push(Edit::insert(item_end, format!("\n\n{indent}{plugin_impl}")));
+            }
+            Build::Fn { attr, block, .. } => {
+                let open = self.map.end(block.brace_token.span.open());
+                let close = self.map.start(block.brace_token.span.close());
+                let before_build = quote!(#(#before_build)*);
+                let after_build = quote!(#(#after_build)*);
+                if !before_build.is_empty() {
+                    self.edits.push(Edit::insert(open, format!("\n{before_build}\n")));
+                }
+                if !after_build.is_empty() {
+                    self.edits.push(Edit::insert(close, format!("\n{after_build}\n")));
+                }
+                self.remove_attr(attr);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tooling::manifest_dir;
+    use internal_test_proc_macro::xtest;
+
+    fn normalize(source: &str) -> String {
+        syn::parse_file(source).unwrap().to_token_stream().to_string()
+    }
+
+    #[xtest]
+    fn test_eject_impl_plugin_trait() {
+        let source = r#"
+            #[derive(AutoPlugin, Debug)]
+            #[auto_plugin(impl_plugin_trait)]
+            struct MyPlugin;
+
+            /// docs stay
+            #[derive(Reflect)]
+            #[auto_register_type(plugin = MyPlugin)]
+            #[auto_name(plugin = Other)]
+            struct Foo;
+
+            #[auto_add_system(plugin = MyPlugin, schedule = Update, after_build)]
+            fn late() {}
+
+            #[auto_add_system(plugin = MyPlugin, schedule = Update)]
+            fn early() {}
+        "#;
+        let ejected = eject(source, None, manifest_dir()).unwrap();
+        assert_eq!(ejected.plugin, "MyPlugin");
+        assert_eq!(ejected.entries, 3);
+        assert!(!ejected.source.contains("AutoPlugin"), "{}", ejected.source);
+        assert!(!ejected.source.contains("auto_register_type"), "{}", ejected.source);
+        assert!(ejected.source.contains("/// docs stay"), "{}", ejected.source);
+        assert!(ejected.source.contains("#[auto_name(plugin = Other)]"), "{}", ejected.source);
+        let build = normalize(&ejected.source);
+        let register = build.find("register_type").unwrap();
+        let early = build.find("add_systems (Update , early)").unwrap();
+        let late = build.find("add_systems (Update , late)").unwrap();
+        assert!(register < early && early < late, "{build}");
+        assert!(build.contains("impl :: bevy_app :: Plugin for MyPlugin"), "{build}");
+    }
+
+    #[xtest]
+    fn test_eject_rewrite_keeps_derives() {
+        let source = r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait)]
+            struct MyPlugin;
+
+            #[auto_component(plugin = MyPlugin, derive(Debug), reflect, register)]
+            struct Foo;
+        "#;
+        let ejected = eject(source, Some("MyPlugin"), manifest_dir()).unwrap();
+        let ejected = normalize(&ejected.source);
+        assert!(
+            ejected.contains("derive (:: bevy_ecs :: prelude :: Component , Debug)"),
+            "{ejected}"
+        );
+        assert!(ejected.contains("derive (:: bevy_reflect :: Reflect)"), "{ejected}");
+        assert!(ejected.contains("reflect (Component)"), "{ejected}");
+        assert!(ejected.contains("app . register_type :: < Foo > ()"), "{ejected}");
+        assert!(!ejected.contains("auto_"), "{ejected}");
+    }
+
+    #[xtest]
+    fn test_eject_into_build_fn() {
+        let source = r#"
+            #[derive(AutoPlugin)]
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                #[auto_plugin]
+                fn build(&self, my_app: &mut App) {
+                    my_app.add_plugins(Other);
+                }
+            }
+
+            #[auto_bind_plugin(plugin = MyPlugin)]
+            #[auto_init_resource]
+            #[derive(Resource, Default)]
+            struct Foo;
+        "#;
+        let ejected = normalize(&eject(source, None, manifest_dir()).unwrap().source);
+        assert!(
+            ejected.contains(
+                "fn build (& self , my_app : & mut App) { my_app . init_resource :: < Foo > () ; my_app . add_plugins (Other) ; }"
+            ),
+            "{ejected}"
+        );
+        assert!(!ejected.contains("auto_"), "{ejected}");
+    }
+
+    #[xtest]
+    fn test_eject_requires_plugin_choice() {
+        let source = r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait)]
+            struct A;
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait)]
+            struct B;
+        "#;
+        let err = eject(source, None, manifest_dir()).err().unwrap();
+        assert_eq!(
+            err.to_string(),
+            "more than one plugin derives `AutoPlugin`, pick one of `A`, `B`"
+        );
+        assert!(eject(source, Some("B"), manifest_dir()).is_ok());
+    }
+
+    #[xtest]
+    fn test_eject_rejects_plugin_options() {
+        let source = r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait, default_schedule = Update)]
+            struct MyPlugin;
+        "#;
+        let err = eject(source, None, manifest_dir()).err().unwrap();
+        assert!(err.to_string().starts_with("eject only supports `impl_plugin_trait`"), "{err}");
+    }
+}
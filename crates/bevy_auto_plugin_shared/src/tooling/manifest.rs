@@ -0,0 +1,131 @@
+//! The manifest the tools resolve bevy crate paths against
+//!
+//! The macros find it through `CARGO_MANIFEST_DIR`, which cargo only sets while it builds the
+//! crate. The tools take the directory explicitly and scope it to the parse with
+//! [`with_manifest_dir`], where a crate that can't be found is an error instead of a panic.
+
+use proc_macro_crate::FoundCrate;
+use proc_macro2::Span;
+use std::{
+    cell::RefCell,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+struct Scope {
+    manifest_dir: PathBuf,
+    errors: Vec<String>,
+}
+
+thread_local! {
+    static SCOPE: RefCell<Option<Scope>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with bevy crate paths resolved from `manifest_dir/Cargo.toml`
+pub fn with_manifest_dir<T>(
+    manifest_dir: &Path,
+    f: impl FnOnce() -> syn::Result<T>,
+) -> syn::Result<T> {
+    let scope = Scope { manifest_dir: manifest_dir.to_path_buf(), errors: vec![] };
+    let outer = SCOPE.with(|cell| cell.replace(Some(scope)));
+    let res = f();
+    let scope = SCOPE.with(|cell| cell.replace(outer)).expect("scope set above");
+    let mut errors = scope.errors;
+    errors.dedup();
+    if errors.is_empty() { res } else { Err(syn::Error::new(Span::call_site(), errors.join("\n"))) }
+}
+
+/// The closest directory above `file` with a `Cargo.toml`
+pub fn manifest_dir_of(file: &Path) -> Option<PathBuf> {
+    let file = file.canonicalize().unwrap_or_else(|_| file.to_path_buf());
+    file.ancestors().skip(1).find(|dir| dir.join("Cargo.toml").is_file()).map(Path::to_path_buf)
+}
+
+/// `proc_macro_crate::crate_name` against the scoped manifest, `None` outside of a scope
+pub(crate) fn crate_name(name: &str) -> Option<Result<FoundCrate, String>> {
+    SCOPE.with(|cell| {
+        let scope = cell.borrow();
+        let manifest_dir = &scope.as_ref()?.manifest_dir;
+        Some(find_crate(&manifest_dir.join("Cargo.toml"), name))
+    })
+}
+
+/// Records a crate that failed to resolve, `false` outside of a scope
+pub(crate) fn record_unresolved(message: String) -> bool {
+    SCOPE.with(|cell| match cell.borrow_mut().as_mut() {
+        Some(scope) => {
+            scope.errors.push(message);
+            true
+        }
+        None => false,
+    })
+}
+
+const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+fn find_crate(manifest: &Path, name: &str) -> Result<FoundCrate, String> {
+    let text = fs::read_to_string(manifest)
+        .map_err(|err| format!("Could not read `{}`: {err}", manifest.display()))?;
+    let manifest_table = text
+        .parse::<toml::Table>()
+        .map_err(|err| format!("Could not parse `{}`: {err}", manifest.display()))?;
+    let package = manifest_table.get("package").and_then(|package| package.get("name"));
+    if package.and_then(toml::Value::as_str) == Some(name) {
+        return Ok(FoundCrate::Itself);
+    }
+    let targets = manifest_table.get("target").and_then(toml::Value::as_table);
+    let tables = std::iter::once(&manifest_table).chain(
+        targets.into_iter().flat_map(|targets| targets.values().filter_map(|t| t.as_table())),
+    );
+    for table in tables {
+        for deps in DEPENDENCY_TABLES.iter().filter_map(|key| table.get(*key)?.as_table()) {
+            for (key, dep) in deps {
+                let package = dep.get("package").and_then(toml::Value::as_str).unwrap_or(key);
+                if package == name {
+                    return Ok(FoundCrate::Name(key.replace('-', "_")));
+                }
+            }
+        }
+    }
+    Err(format!(
+        "Could not find `{name}` in `dependencies` or `dev-dependencies` in `{}`!",
+        manifest.display()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tooling::manifest_dir;
+    use internal_test_proc_macro::xtest;
+
+    #[xtest]
+    fn test_crate_name_outside_of_a_scope() {
+        assert_eq!(crate_name("bevy_ecs"), None);
+        assert!(!record_unresolved("unused".to_string()));
+    }
+
+    #[xtest]
+    fn test_crate_name_in_scope() {
+        let found = with_manifest_dir(manifest_dir(), || {
+            Ok((crate_name("bevy_ecs"), crate_name("bevy_auto_plugin_shared")))
+        })
+        .unwrap();
+        assert_eq!(
+            found,
+            (Some(Ok(FoundCrate::Name("bevy_ecs".to_string()))), Some(Ok(FoundCrate::Itself)))
+        );
+    }
+
+    #[xtest]
+    fn test_unresolved_crate_is_an_error() {
+        let res = with_manifest_dir(manifest_dir(), || {
+            record_unresolved("failed to resolve `bevy_foo`".to_string());
+            Ok(())
+        });
+        assert_eq!(res.map_err(|err| err.to_string()), Err("failed to resolve `bevy_foo`".into()));
+    }
+}
@@ -0,0 +1,1018 @@
+//! `cargo auto-plugin migrate`: moves the calls of a hand-written `Plugin::build` onto the items
+//! they target as `auto_*` attributes
+//!
+//! Every recognized call is parsed back through the attribute's own parser against its target
+//! item and written with the attribute's emitter, so the output is exactly what the macros accept.
+//! Calls that can't be expressed as an attribute, or target items outside this file, stay in
+//! `build`, which then becomes an `#[auto_plugin]` build fn.
+//!
+//! The registry runs entries in the order their items are declared, so a call is only moved when
+//! that doesn't change its order relative to the calls it depends on.
+
+use crate::{
+    macro_api::prelude::*,
+    syntax::extensions::item::ItemAttrsExt,
+    tooling::{
+        Edit,
+        SourceMap,
+        apply_edits_with_spans,
+        attr_to_string,
+        eject::derives_auto_plugin,
+        manifest::with_manifest_dir,
+    },
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::{
+    ToTokens,
+    quote,
+};
+use std::{
+    collections::{
+        BTreeSet,
+        HashMap,
+        HashSet,
+    },
+    ops::Range,
+};
+use syn::{
+    Attribute,
+    Expr,
+    ExprMethodCall,
+    Ident,
+    ImplItem,
+    Item,
+    ItemImpl,
+    Path,
+    PathArguments,
+    Stmt,
+    UseTree,
+    parse::Parser,
+    spanned::Spanned,
+};
+
+pub struct Migrated {
+    pub plugin: Ident,
+    pub source: String,
+    /// byte ranges of `source` that were written by the migration
+    pub rewritten: Vec<Range<usize>>,
+    /// number of calls turned into attributes
+    pub entries: usize,
+    pub warnings: Vec<String>,
+}
+
+/// Migrates the `Plugin` impl of `plugin`, or the only `Plugin` impl in `source` when `None`
+///
+/// Bevy crate paths are resolved from the `Cargo.toml` in `manifest_dir`.
+pub fn migrate(
+    source: &str,
+    plugin: Option<&str>,
+    manifest_dir: &std::path::Path,
+) -> syn::Result<Migrated> {
+    with_manifest_dir(manifest_dir, || migrate_inner(source, plugin))
+}
+
+fn migrate_inner(source: &str, plugin: Option<&str>) -> syn::Result<Migrated> {
+    let file = syn::parse_file(source)?;
+    let map = SourceMap::new(source);
+    let plugin_impl = find_plugin_impl(&file.items, plugin)?;
+    let plugin_ident = impl_self_ident(plugin_impl).cloned().expect("filtered by find_plugin_impl");
+    let plugin_item = file
+        .items
+        .iter()
+        .find(|item| {
+            matches!(item, Item::Struct(_) | Item::Enum(_))
+                && item.get_ident() == Some(&plugin_ident)
+        })
+        .ok_or_else(|| {
+            syn::Error::new(
+                plugin_ident.span(),
+                format!("`{plugin_ident}` has to be declared in this file to migrate it"),
+            )
+        })?;
+    if !plugin_impl.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &plugin_impl.generics,
+            "generic plugins can't be migrated",
+        ));
+    }
+    let build = plugin_impl
+        .items
+        .iter()
+        .find_map(|item| match item {
+            ImplItem::Fn(item_fn) if item_fn.sig.ident == "build" => Some(item_fn),
+            _ => None,
+        })
+        .ok_or_else(|| syn::Error::new(plugin_ident.span(), "`Plugin` impl has no `build` fn"))?;
+    let build_fn = syn::ItemFn {
+        attrs: vec![],
+        vis: syn::Visibility::Inherited,
+        sig: build.sig.clone(),
+        block: Box::new(build.block.clone()),
+    };
+    let app_param = resolve_app_param_name(&build_fn)?.clone();
+
+    let mut targets = HashMap::<String, Vec<&Item>>::new();
+    for item in &file.items {
+        if let (Item::Struct(_) | Item::Enum(_) | Item::Fn(_), Some(ident)) =
+            (item, item.get_ident())
+        {
+            targets.entry(ident.to_string()).or_default().push(item);
+        }
+    }
+
+    let mut migrator = Migrator {
+        map: &map,
+        plugin: plugin_ident.clone(),
+        app_param,
+        targets,
+        kept: vec![],
+        stmt: 0,
+        warnings: vec![],
+    };
+    let stmts = &build.block.stmts;
+    // every statement that stays in `build` can change which calls need `after_build`, so the
+    // calls are migrated again until nothing else has to stay
+    let mut kept = BTreeSet::new();
+    let entries = 'migrate: loop {
+        migrator.kept = kept.iter().map(|&ix| Kept::new(ix, &stmts[ix])).collect();
+        let mut entries = vec![];
+        for (ix, stmt) in stmts.iter().enumerate().filter(|(ix, _)| !kept.contains(ix)) {
+            migrator.stmt = ix;
+            match migrator.migrate_stmt(stmt)? {
+                Some(stmt_entries) => entries.extend(stmt_entries),
+                None => {
+                    kept.insert(ix);
+                    continue 'migrate;
+                }
+            }
+        }
+        match migrator.reordered(&entries) {
+            Some(ix) => kept.insert(ix),
+            None => break entries,
+        };
+    };
+    if entries.is_empty() {
+        return Err(syn::Error::new(
+            build.sig.ident.span(),
+            format!("nothing in `{plugin_ident}::build` can be migrated"),
+        ));
+    }
+    let migrated_all = kept.is_empty();
+    let mut edits = vec![];
+    for (_, stmt) in stmts.iter().enumerate().filter(|(ix, _)| !kept.contains(ix)) {
+        let start = map.start(stmt.span());
+        let end = map.skip_whitespace(map.end(stmt.span()));
+        edits.push(Edit::remove(start..end));
+    }
+
+    for entry in &entries {
+        let at = item_start(&map, entry.target);
+        let indent = map.indent_at(at);
+        edits.push(Edit::insert(at, format!("{}\n{indent}", attr_to_string(&entry.attr))));
+    }
+
+    let removable_impl = migrated_all
+        && plugin_impl.attrs.is_empty()
+        && plugin_impl.items.len() == 1
+        && build.attrs.iter().all(|attr| attr.path().is_ident("doc"));
+    let mut plugin_attrs = vec![];
+    if !derives_auto_plugin(plugin_item) {
+        plugin_attrs.push("#[derive(AutoPlugin)]");
+    }
+    if removable_impl {
+        plugin_attrs.push("#[auto_plugin(impl_plugin_trait)]");
+        let start = map.start(plugin_impl.span());
+        let end = map.skip_whitespace(map.end(plugin_impl.span()));
+        edits.push(Edit::remove(start..end));
+    } else {
+        let at = map.start(build.sig.fn_token.span);
+        let indent = map.indent_at(at);
+        edits.push(Edit::insert(at, format!("#[auto_plugin]\n{indent}")));
+    }
+    if !plugin_attrs.is_empty() {
+        let at = item_start(&map, plugin_item);
+        let indent = map.indent_at(at);
+        let text = plugin_attrs.iter().map(|attr| format!("{attr}\n{indent}")).collect::<String>();
+        edits.push(Edit::insert(at, text));
+    }
+    if !imports_prelude(&file.items) {
+        let import = "use bevy_auto_plugin::prelude::*;";
+        let edit = match file.items.iter().rfind(|item| matches!(item, Item::Use(_))) {
+            Some(last_use) => Edit::insert(map.end(last_use.span()), format!("\n{import}")),
+            None => Edit::insert(
+                file.items.first().map_or(source.len(), |item| map.start(item.span())),
+                format!("{import}\n\n"),
+            ),
+        };
+        // ahead of any attributes inserted at the same offset
+        edits.insert(0, edit);
+    }
+
+    let (source, rewritten) = apply_edits_with_spans(source, edits);
+    if let Err(err) = syn::parse_file(&source) {
+        return Err(syn::Error::new(
+            plugin_ident.span(),
+            format!("migrating `{plugin_ident}` produced invalid code, please report this: {err}"),
+        ));
+    }
+    Ok(Migrated {
+        plugin: plugin_ident,
+        source,
+        rewritten,
+        entries: entries.len(),
+        warnings: migrator.warnings,
+    })
+}
+
+fn impl_self_ident(item_impl: &ItemImpl) -> Option<&Ident> {
+    match &*item_impl.self_ty {
+        syn::Type::Path(ty) if ty.qself.is_none() => ty.path.get_ident(),
+        _ => None,
+    }
+}
+
+fn find_plugin_impl<'a>(items: &'a [Item], plugin: Option<&str>) -> syn::Result<&'a ItemImpl> {
+    let impls = items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Impl(item_impl) => Some(item_impl),
+            _ => None,
+        })
+        .filter(|item_impl| {
+            item_impl.trait_.as_ref().is_some_and(|(_, path, _)| {
+                path.segments.last().is_some_and(|segment| segment.ident == "Plugin")
+            }) && impl_self_ident(item_impl).is_some()
+        })
+        .collect::<Vec<_>>();
+    let names = || {
+        impls
+            .iter()
+            .filter_map(|item_impl| impl_self_ident(item_impl))
+            .map(|ident| format!("`{ident}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    match plugin {
+        Some(name) => impls
+            .iter()
+            .find(|item_impl| impl_self_ident(item_impl).is_some_and(|ident| ident == name))
+            .copied()
+            .ok_or_else(|| {
+                let message = if impls.is_empty() {
+                    format!("no `Plugin` impl for `{name}` in this file")
+                } else {
+                    format!("no `Plugin` impl for `{name}`, found {}", names())
+                };
+                syn::Error::new(proc_macro2::Span::call_site(), message)
+            }),
+        None => match impls.as_slice() {
+            [item_impl] => Ok(item_impl),
+            [] => Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                "no `Plugin` impl in this file",
+            )),
+            _ => Err(syn::Error::new(
+                proc_macro2::Span::call_site(),
+                format!("more than one `Plugin` impl, pick one of {}", names()),
+            )),
+        },
+    }
+}
+
+/// Whether the file has `use bevy_auto_plugin::prelude::*`, which covers every inserted attribute
+fn imports_prelude(items: &[Item]) -> bool {
+    fn is_prelude_glob(tree: &UseTree, path: &[&str]) -> bool {
+        match tree {
+            UseTree::Path(use_path) => is_prelude_glob(
+                &use_path.tree,
+                &[path, &[use_path.ident.to_string().as_str()]].concat(),
+            ),
+            UseTree::Group(group) => group.items.iter().any(|tree| is_prelude_glob(tree, path)),
+            UseTree::Glob(_) => path == ["bevy_auto_plugin", "prelude"],
+            UseTree::Name(_) | UseTree::Rename(_) => false,
+        }
+    }
+    items
+        .iter()
+        .any(|item| matches!(item, Item::Use(item_use) if is_prelude_glob(&item_use.tree, &[])))
+}
+
+/// Offset of the first token after the item's attributes
+///
+/// Attributes go right above the item so the derives they look at stay visible to them.
+fn item_start(map: &SourceMap, item: &Item) -> usize {
+    let mut item = item.clone();
+    let _ = item.take_attrs();
+    let first = item.into_token_stream().into_iter().next().expect("items aren't empty");
+    map.start(first.span())
+}
+
+/// `Type` from `Type::Variant`, `Type::new(..)`, `Type(..)` or `Type { .. }`
+fn value_type(expr: &Expr) -> Option<Path> {
+    let path = match expr {
+        Expr::Path(expr) if expr.qself.is_none() => &expr.path,
+        Expr::Call(call) => match &*call.func {
+            Expr::Path(func) if func.qself.is_none() => &func.path,
+            _ => return None,
+        },
+        Expr::Struct(expr) if expr.qself.is_none() => return Some(expr.path.clone()),
+        _ => return None,
+    };
+    match path.segments.len() {
+        // `Type` or `Type(..)`
+        1 if matches!(expr, Expr::Path(_) | Expr::Call(_)) => Some(path.clone()),
+        // `Type::Variant` or `Type::new(..)`
+        2 => Some(Path::from(path.segments[0].clone())),
+        _ => None,
+    }
+}
+
+fn turbofish_type(call: &ExprMethodCall) -> Option<Path> {
+    let turbofish = call.turbofish.as_ref()?;
+    let [syn::GenericArgument::Type(syn::Type::Path(ty))] =
+        turbofish.args.iter().collect::<Vec<_>>().as_slice()
+    else {
+        return None;
+    };
+    ty.qself.is_none().then(|| ty.path.clone())
+}
+
+/// Calls whose order matters even between different items, e.g. a sub state after its source
+const ORDERED_METHODS: &[&str] = &["add_plugins", "init_state", "insert_state", "add_sub_state"];
+
+struct Entry<'a> {
+    target: &'a Item,
+    attr: Attribute,
+    after_build: bool,
+    ordered: bool,
+    /// the call it replaces, for messages
+    call: String,
+    stmt: usize,
+    line: usize,
+}
+
+/// A statement that stays in `build`
+struct Kept {
+    stmt: usize,
+    line: usize,
+    idents: HashSet<String>,
+    ordered: bool,
+}
+
+impl Kept {
+    fn new(stmt: usize, tokens: &Stmt) -> Self {
+        fn collect(tokens: MacroStream, idents: &mut HashSet<String>) {
+            for tree in tokens {
+                match tree {
+                    proc_macro2::TokenTree::Ident(ident) => {
+                        idents.insert(ident.to_string());
+                    }
+                    proc_macro2::TokenTree::Group(group) => collect(group.stream(), idents),
+                    _ => {}
+                }
+            }
+        }
+        let mut idents = HashSet::new();
+        collect(tokens.to_token_stream(), &mut idents);
+        let ordered = ORDERED_METHODS.iter().any(|method| idents.contains(*method));
+        Self { stmt, line: tokens.span().start().line, idents, ordered }
+    }
+
+    /// Whether moving `entry` across this statement could change what either does
+    fn depends_on(&self, target: &Ident, ordered: bool) -> bool {
+        self.idents.contains(&target.to_string()) || (ordered && self.ordered)
+    }
+}
+
+struct Migrator<'a> {
+    map: &'a SourceMap<'a>,
+    plugin: Ident,
+    app_param: Ident,
+    targets: HashMap<String, Vec<&'a Item>>,
+    /// statements that stay in `build`
+    kept: Vec<Kept>,
+    /// the statement being migrated
+    stmt: usize,
+    warnings: Vec<String>,
+}
+
+/// Where an entry or a kept statement runs: before `build`, in it or after it
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum RunsAt {
+    BeforeBuild { item: usize, ix: usize },
+    Build { stmt: usize },
+    AfterBuild { item: usize, ix: usize },
+}
+
+/// Why a call wasn't migrated, `None` when it isn't one the tool knows
+type Skip = Option<String>;
+
+impl<'a> Migrator<'a> {
+    /// `Some` with the entries of every call in the statement, `None` if it stays in `build`
+    fn migrate_stmt(&mut self, stmt: &Stmt) -> syn::Result<Option<Vec<Entry<'a>>>> {
+        let Stmt::Expr(expr, Some(_)) = stmt else {
+            return Ok(None);
+        };
+        // `app.a().b()` runs `a` first
+        let mut calls = vec![];
+        let mut receiver = expr;
+        while let Expr::MethodCall(call) = receiver {
+            calls.push(call);
+            receiver = &call.receiver;
+        }
+        let is_app = matches!(receiver, Expr::Path(path) if path.path.is_ident(&self.app_param));
+        if !is_app || calls.is_empty() {
+            return Ok(None);
+        }
+        let line = stmt.span().start().line;
+        let mut entries = vec![];
+        for call in calls.into_iter().rev() {
+            match self.migrate_call(call) {
+                Ok(call_entries) => entries.extend(call_entries),
+                Err(reason) => {
+                    let call = format!(".{}(..)", call.method);
+                    self.warnings.push(match reason {
+                        Some(reason) => format!("line {line}: left `{call}` in `build`: {reason}"),
+                        None => format!("line {line}: left `{call}` in `build`"),
+                    });
+                    return Ok(None);
+                }
+            }
+        }
+        for entry in &mut entries {
+            entry.stmt = self.stmt;
+            entry.line = line;
+        }
+        Ok(Some(entries))
+    }
+
+    fn migrate_call(&self, call: &ExprMethodCall) -> Result<Vec<Entry<'a>>, Skip> {
+        let args = call.args.iter().collect::<Vec<_>>();
+        let method = call.method.to_string();
+        let ordered = ORDERED_METHODS.contains(&method.as_str());
+        let typed = || turbofish_type(call).filter(|_| args.is_empty()).ok_or(None);
+        let entry = match (method.as_str(), args.as_slice()) {
+            ("register_type", _) => self.entry::<IaRegisterType>(&typed()?, quote!(), ordered)?,
+            ("init_resource", _) => self.entry::<IaInitResource>(&typed()?, quote!(), ordered)?,
+            ("add_message", _) => self.entry::<IaAddMessage>(&typed()?, quote!(), ordered)?,
+            ("init_state", _) => self.entry::<IaInitState>(&typed()?, quote!(), ordered)?,
+            ("add_sub_state", _) => self.entry::<IaInitSubState>(&typed()?, quote!(), ordered)?,
+            ("insert_resource", [value]) => {
+                let target = value_type(value).ok_or(None)?;
+                self.entry::<IaInsertResource>(&target, quote!(insert = #value), ordered)?
+            }
+            ("insert_state", [initial]) => {
+                let target = value_type(initial).ok_or(None)?;
+                self.entry::<IaInitState>(&target, quote!(initial = #initial), ordered)?
+            }
+            ("add_observer", [Expr::Path(observer)]) if observer.qself.is_none() => {
+                self.entry::<IaAddObserver>(&observer.path, quote!(), ordered)?
+            }
+            ("add_systems", [schedule, systems]) => {
+                return self.add_systems(schedule, systems);
+            }
+            ("add_plugins", [plugins]) => return self.add_plugins(plugins),
+            _ => return Err(None),
+        };
+        Ok(vec![entry])
+    }
+
+    fn add_systems(&self, schedule: &Expr, systems: &Expr) -> Result<Vec<Entry<'a>>, Skip> {
+        // `(a, b).chain()` becomes `after = a` on `b`
+        let (systems, chain) = match systems {
+            Expr::MethodCall(call)
+                if call.args.is_empty()
+                    && call.turbofish.is_none()
+                    && matches!(&*call.receiver, Expr::Tuple(_)) =>
+            {
+                match call.method.to_string().as_str() {
+                    "chain" => (&*call.receiver, Some(quote!(after))),
+                    "chain_ignore_deferred" => {
+                        (&*call.receiver, Some(quote!(after_ignore_deferred)))
+                    }
+                    _ => (systems, None),
+                }
+            }
+            systems => (systems, None),
+        };
+        let systems = match systems {
+            Expr::Tuple(tuple) => tuple.elems.iter().map(split_config).collect::<Result<_, _>>()?,
+            system => vec![split_config(system)?],
+        };
+        let mut entries = vec![];
+        let mut previous = None;
+        for (system, mut config) in systems {
+            // also a config on the whole tuple, which isn't the same as one per system
+            let Expr::Path(system) = system else {
+                return Err(Some("only paths to systems can be migrated".to_string()));
+            };
+            if let (Some(chain), Some(previous)) = (&chain, previous) {
+                config.push(quote!(#chain = #previous));
+            }
+            let mut args = quote!(schedule = #schedule);
+            if !config.is_empty() {
+                args.extend(quote!(, config(#(#config),*)));
+            }
+            entries.push(self.entry::<IaAddSystem>(&system.path, args, false)?);
+            previous = Some(system);
+        }
+        Ok(entries)
+    }
+
+    fn add_plugins(&self, plugins: &Expr) -> Result<Vec<Entry<'a>>, Skip> {
+        let plugins = match plugins {
+            Expr::Tuple(tuple) => tuple.elems.iter().collect(),
+            plugin => vec![plugin],
+        };
+        let mut entries = vec![];
+        for plugin in plugins {
+            let entry = match plugin {
+                Expr::Path(plugin) if plugin.qself.is_none() => {
+                    self.entry::<IaAddPlugin>(&plugin.path, quote!(), true)?
+                }
+                Expr::Call(call)
+                    if call.args.is_empty()
+                        && matches!(&*call.func, Expr::Path(func)
+                            if func.path.segments.last().is_some_and(|segment| segment.ident == "default")) =>
+                {
+                    let target = value_type(plugin).ok_or(None)?;
+                    self.entry::<IaAddPlugin>(&target, quote!(init), true)?
+                }
+                plugin => {
+                    let target = value_type(plugin).ok_or(None)?;
+                    self.entry::<IaAddPlugin>(&target, quote!(init = #plugin), true)?
+                }
+            };
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+
+    /// Parses `args` with `T`'s parser against the target item and writes it with its emitter
+    ///
+    /// `ordered` calls depend on the order of the other `ordered` calls, whatever their target.
+    fn entry<T>(&self, target: &Path, args: MacroStream, ordered: bool) -> Result<Entry<'a>, Skip>
+    where
+        T: ItemAttributeParse + ItemAttributeInput + ItemAttributeContext + ItemAttributeArgs,
+        AttrEmitter<T>: ToTokens,
+    {
+        if target.segments.len() != 1 {
+            return Err(Some(format!(
+                "`{}` isn't declared in this file",
+                target.to_token_stream()
+            )));
+        }
+        let segment = &target.segments[0];
+        let ident = &segment.ident;
+        let item = match self.targets.get(&ident.to_string()).map(Vec::as_slice) {
+            Some([item]) => *item,
+            Some(_) => return Err(Some(format!("more than one item is named `{ident}`"))),
+            None => return Err(Some(format!("`{ident}` isn't declared in this file"))),
+        };
+        let plugin = &self.plugin;
+        let mut attr = vec![quote!(plugin = #plugin)];
+        // keeps it after the statements in `build` it came after and depends on
+        let after_build =
+            self.kept.iter().any(|kept| kept.stmt < self.stmt && kept.depends_on(ident, ordered));
+        if after_build {
+            attr.push(quote!(after_build));
+        }
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(generics) => {
+                let generics = &generics.args;
+                attr.push(quote!(generics(#generics)));
+            }
+            PathArguments::Parenthesized(_) => return Err(None),
+        }
+        if !args.is_empty() {
+            attr.push(args);
+        }
+        let parsed = T::from_attr_input_with_context(
+            quote!(#(#attr),*),
+            item.to_token_stream(),
+            Context::default(),
+        )
+        .map_err(|err| Some(err.to_string()))?;
+        let tokens = AttrEmitter::from_args(parsed).to_token_stream();
+        let mut attr = Attribute::parse_outer
+            .parse2(tokens)
+            .map_err(|err| Some(err.to_string()))?
+            .pop()
+            .expect("emitters write one attribute");
+        // the file imports the prelude instead
+        if let syn::Meta::List(list) = &mut attr.meta {
+            let name = list.path.segments.pop().expect("attribute paths aren't empty");
+            list.path = Path::from(name.into_value());
+        }
+        Ok(Entry {
+            target: item,
+            attr,
+            after_build,
+            ordered,
+            call: target.to_token_stream().to_string(),
+            stmt: self.stmt,
+            line: 0,
+        })
+    }
+
+    fn runs_at(&self, ix: usize, entry: &Entry) -> RunsAt {
+        // attributes on one item run in the order of the calls
+        let item = item_start(self.map, entry.target);
+        if entry.after_build {
+            RunsAt::AfterBuild { item, ix }
+        } else {
+            RunsAt::BeforeBuild { item, ix }
+        }
+    }
+
+    /// The statement of a call that would run on the other side of a call it depends on, since
+    /// the registry runs entries in the order their items are declared
+    fn reordered(&mut self, entries: &[Entry]) -> Option<usize> {
+        for (ix, entry) in entries.iter().enumerate() {
+            let runs_at = self.runs_at(ix, entry);
+            let target = entry.target.get_ident().expect("targets are named");
+            for (earlier_ix, earlier) in entries[..ix].iter().enumerate() {
+                let depends = std::ptr::eq(earlier.target, entry.target)
+                    || (earlier.ordered && entry.ordered);
+                if depends && runs_at < self.runs_at(earlier_ix, earlier) {
+                    return Some(self.keep(entry, earlier.line, "before"));
+                }
+            }
+            for kept in self.kept.iter().filter(|kept| kept.depends_on(target, entry.ordered)) {
+                let kept_runs_at = RunsAt::Build { stmt: kept.stmt };
+                if kept.stmt < entry.stmt && runs_at < kept_runs_at {
+                    return Some(self.keep(entry, kept.line, "before"));
+                }
+                if entry.stmt < kept.stmt && runs_at > kept_runs_at {
+                    return Some(self.keep(entry, kept.line, "after"));
+                }
+            }
+        }
+        None
+    }
+
+    fn keep(&mut self, entry: &Entry, other_line: usize, side: &str) -> usize {
+        self.warnings.push(format!(
+            "line {}: left `{}` in `build`: as an attribute it would run {side} line {other_line}, entries run in the order their items are declared",
+            entry.line, entry.call
+        ));
+        entry.stmt
+    }
+}
+
+/// Splits `system.run_if(..).after(..)` into the system and its `config(...)` args, e.g.
+/// `run_if = cond` from `.run_if(cond)`
+fn split_config(expr: &Expr) -> Result<(&Expr, Vec<MacroStream>), Skip> {
+    const CONFIG: &[&str] = &[
+        "in_set",
+        "before",
+        "after",
+        "run_if",
+        "distributive_run_if",
+        "ambiguous_with",
+        "before_ignore_deferred",
+        "after_ignore_deferred",
+    ];
+    let mut config = vec![];
+    let mut system = expr;
+    while let Expr::MethodCall(call) = system {
+        let supported = CONFIG.contains(&call.method.to_string().as_str())
+            && call.turbofish.is_none()
+            && call.args.len() == 1;
+        if !supported {
+            return Err(Some(format!("`.{}(..)` has no `config(...)` equivalent", call.method)));
+        }
+        let (method, arg) = (&call.method, &call.args[0]);
+        config.push(quote!(#method = #arg));
+        system = &call.receiver;
+    }
+    config.reverse();
+    Ok((system, config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tooling::manifest_dir;
+    use internal_test_proc_macro::xtest;
+
+    fn normalize(source: &str) -> String {
+        syn::parse_file(source).unwrap().to_token_stream().to_string()
+    }
+
+    #[xtest]
+    fn test_migrate_whole_build() {
+        let source = r#"
+            use bevy::prelude::*;
+
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.register_type::<Foo>().init_resource::<Foo>();
+                    app.add_systems(Update, tick.run_if(resource_exists::<Foo>).after(tock));
+                }
+            }
+
+            #[derive(Resource, Reflect, Default)]
+            struct Foo;
+
+            fn tick() {}
+        "#;
+        let migrated = migrate(source, None, manifest_dir()).unwrap();
+        assert_eq!(migrated.plugin, "MyPlugin");
+        assert_eq!(migrated.entries, 3);
+        // written like the attributes would be by hand
+        assert!(
+            migrated.source.contains(
+                "#[auto_add_system(plugin = MyPlugin, schedule = Update, config(after = tock, run_if = resource_exists::<Foo>))]\n            fn tick"
+            ),
+            "{}",
+            migrated.source
+        );
+        let migrated = normalize(&migrated.source);
+        assert!(migrated.contains("use bevy_auto_plugin :: prelude :: * ;"), "{migrated}");
+        assert!(
+            migrated.contains(
+                "# [derive (AutoPlugin)] # [auto_plugin (impl_plugin_trait)] struct MyPlugin ;"
+            ),
+            "{migrated}"
+        );
+        assert!(!migrated.contains("impl Plugin"), "{migrated}");
+        assert!(
+            migrated.contains(
+                "# [derive (Resource , Reflect , Default)] # [auto_register_type (plugin = MyPlugin)] # [auto_init_resource (plugin = MyPlugin)] struct Foo ;"
+            ),
+            "{migrated}"
+        );
+    }
+
+    #[xtest]
+    fn test_migrate_keeps_unrecognized_calls() {
+        let source = r#"
+            #[derive(AutoPlugin)]
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.init_resource::<Foo>();
+                    app.add_plugins(DefaultPlugins);
+                    app.insert_resource(Bar(1));
+                }
+            }
+
+            #[derive(Resource, Default)]
+            struct Foo;
+
+            #[derive(Resource)]
+            struct Bar(u8);
+        "#;
+        let migrated = migrate(source, Some("MyPlugin"), manifest_dir()).unwrap();
+        assert_eq!(migrated.entries, 2);
+        assert_eq!(
+            migrated.warnings,
+            vec![
+                "line 8: left `.add_plugins(..)` in `build`: `DefaultPlugins` isn't declared in this file"
+            ]
+        );
+        let migrated = normalize(&migrated.source);
+        assert!(
+            migrated.contains(
+                "# [auto_plugin] fn build (& self , app : & mut App) { app . add_plugins (DefaultPlugins) ; }"
+            ),
+            "{migrated}"
+        );
+        assert!(migrated.contains("# [auto_init_resource (plugin = MyPlugin)]"), "{migrated}");
+        // doesn't depend on `add_plugins`, so it doesn't have to stay after it
+        assert!(
+            migrated.contains("# [auto_insert_resource (plugin = MyPlugin , insert = Bar (1))]"),
+            "{migrated}"
+        );
+        assert_eq!(migrated.matches("derive (AutoPlugin)").count(), 1, "{migrated}");
+    }
+
+    #[xtest]
+    fn test_migrate_generics_and_tuples() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.register_type::<Stat<u8>>();
+                    app.register_type::<Stat<bool>>();
+                    app.add_systems(Startup, (a, b));
+                }
+            }
+
+            #[derive(Reflect)]
+            struct Stat<T>(T);
+
+            fn a() {}
+            fn b() {}
+        "#;
+        let migrated = normalize(&migrate(source, None, manifest_dir()).unwrap().source);
+        assert!(
+            migrated.contains(
+                "# [auto_register_type (plugin = MyPlugin , generics (u8))] # [auto_register_type (plugin = MyPlugin , generics (bool))] struct Stat"
+            ),
+            "{migrated}"
+        );
+        assert!(
+            migrated.contains("# [auto_add_system (plugin = MyPlugin , schedule = Startup)] fn a"),
+            "{migrated}"
+        );
+        assert!(
+            migrated.contains("# [auto_add_system (plugin = MyPlugin , schedule = Startup)] fn b"),
+            "{migrated}"
+        );
+    }
+
+    #[xtest]
+    fn test_migrate_after_build_only_when_ordered() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.add_plugins(DefaultPlugins);
+                    app.world_mut().resource_mut::<Score>().0 = 1;
+                    app.add_plugins(Other);
+                    app.init_resource::<Score>();
+                    app.add_message::<Hit>();
+                }
+            }
+
+            struct Other;
+            #[derive(Resource, Default)]
+            struct Score(u32);
+            #[derive(Message)]
+            struct Hit;
+        "#;
+        let migrated = migrate(source, None, manifest_dir()).unwrap();
+        assert_eq!(migrated.entries, 3);
+        let migrated = normalize(&migrated.source);
+        // plugins keep their order, `Score` stays after the statement touching it
+        assert!(
+            migrated.contains("# [auto_add_plugin (plugin = MyPlugin , after_build)] struct Other"),
+            "{migrated}"
+        );
+        assert!(
+            migrated
+                .contains("# [auto_init_resource (plugin = MyPlugin , after_build)] struct Score"),
+            "{migrated}"
+        );
+        assert!(
+            migrated.contains("# [auto_add_message (plugin = MyPlugin)] struct Hit"),
+            "{migrated}"
+        );
+    }
+
+    #[xtest]
+    fn test_migrate_refuses_reorder() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.add_message::<B>();
+                    app.add_message::<A>();
+                    app.init_state::<Parent>();
+                    app.add_sub_state::<Child>();
+                }
+            }
+
+            #[derive(Message)]
+            struct A;
+            #[derive(Message)]
+            struct B;
+            #[derive(SubStates, Default, Clone, PartialEq, Eq, Hash, Debug)]
+            #[source(Parent = Parent::On)]
+            enum Child { #[default] A }
+            #[derive(States, Default, Clone, PartialEq, Eq, Hash, Debug)]
+            enum Parent { #[default] On }
+        "#;
+        let migrated = migrate(source, None, manifest_dir()).unwrap();
+        // messages don't depend on each other, the sub state has to come after its source
+        assert_eq!(migrated.entries, 3);
+        assert_eq!(
+            migrated.warnings,
+            vec![
+                "line 9: left `Child` in `build`: as an attribute it would run before line 8, entries run in the order their items are declared"
+            ]
+        );
+        let migrated = normalize(&migrated.source);
+        assert!(migrated.contains("app . add_sub_state :: < Child > () ;"), "{migrated}");
+        assert!(
+            migrated.contains("# [auto_init_state (plugin = MyPlugin)] enum Parent"),
+            "{migrated}"
+        );
+    }
+
+    #[xtest]
+    fn test_migrate_refuses_moving_after_a_later_statement() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.world_mut().init_resource::<Score>();
+                    app.insert_resource(Score(1));
+                    app.world_mut().resource_mut::<Score>().0 += 1;
+                }
+            }
+
+            #[derive(Resource, Default)]
+            struct Score(u32);
+        "#;
+        // `after_build` would run it after the increment
+        let err = migrate(source, None, manifest_dir()).err().unwrap();
+        assert_eq!(err.to_string(), "nothing in `MyPlugin::build` can be migrated");
+    }
+
+    #[xtest]
+    fn test_migrate_chained_systems() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.add_systems(Update, (a, b.run_if(ready), c).chain());
+                }
+            }
+
+            fn c() {}
+            fn b() {}
+            fn a() {}
+        "#;
+        let migrated = normalize(&migrate(source, None, manifest_dir()).unwrap().source);
+        assert!(
+            migrated.contains("# [auto_add_system (plugin = MyPlugin , schedule = Update)] fn a"),
+            "{migrated}"
+        );
+        assert!(
+            migrated.contains(
+                "# [auto_add_system (plugin = MyPlugin , schedule = Update , config (after = a , run_if = ready))] fn b"
+            ),
+            "{migrated}"
+        );
+        assert!(
+            migrated.contains(
+                "# [auto_add_system (plugin = MyPlugin , schedule = Update , config (after = b))] fn c"
+            ),
+            "{migrated}"
+        );
+    }
+
+    #[xtest]
+    fn test_migrate_prelude_import() {
+        let source = |import: &str| {
+            format!(
+                r#"
+                {import}
+
+                struct MyPlugin;
+
+                impl Plugin for MyPlugin {{
+                    fn build(&self, app: &mut App) {{
+                        app.init_resource::<Foo>();
+                    }}
+                }}
+
+                #[derive(Resource, Default)]
+                struct Foo;
+            "#
+            )
+        };
+        let prelude = "use bevy_auto_plugin :: prelude :: * ;";
+        // a partial import doesn't bring in the inserted attributes
+        let migrated =
+            migrate(&source("use bevy_auto_plugin::prelude::AutoPlugin;"), None, manifest_dir())
+                .unwrap();
+        let migrated = normalize(&migrated.source);
+        assert!(migrated.contains(prelude), "{migrated}");
+        let migrated = migrate(
+            &source("use ::bevy_auto_plugin::{prelude::*, testing};"),
+            None,
+            manifest_dir(),
+        )
+        .unwrap();
+        let migrated = normalize(&migrated.source);
+        assert!(!migrated.contains(prelude), "{migrated}");
+    }
+
+    #[xtest]
+    fn test_migrate_nothing_to_do() {
+        let source = r#"
+            struct MyPlugin;
+
+            impl Plugin for MyPlugin {
+                fn build(&self, app: &mut App) {
+                    app.add_plugins(DefaultPlugins);
+                }
+            }
+        "#;
+        let err = migrate(source, None, manifest_dir()).err().unwrap();
+        assert_eq!(err.to_string(), "nothing in `MyPlugin::build` can be migrated");
+    }
+}
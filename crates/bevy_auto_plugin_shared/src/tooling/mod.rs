@@ -0,0 +1,404 @@
+//! Source-to-source tools behind `cargo auto-plugin`
+//!
+//! These parse files outside of a macro invocation, so spans carry real line/column info
+//! (`proc-macro2/span-locations`) which is used to edit the original text in place.
+
+pub mod cli;
+pub mod eject;
+pub mod manifest;
+pub mod migrate;
+pub mod report;
+
+use proc_macro2::{
+    Delimiter,
+    LineColumn,
+    Spacing,
+    Span,
+    TokenStream,
+    TokenTree,
+};
+use quote::ToTokens;
+use std::ops::Range;
+use syn::{
+    Attribute,
+    spanned::Spanned,
+};
+
+/// Maps span positions to byte offsets of the parsed source
+pub struct SourceMap<'a> {
+    source: &'a str,
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let line_starts =
+            std::iter::once(0).chain(source.match_indices('\n').map(|(ix, _)| ix + 1)).collect();
+        Self { source, line_starts }
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    pub fn offset(&self, pos: LineColumn) -> usize {
+        let line_start = self.line_starts.get(pos.line.saturating_sub(1)).copied().unwrap_or(0);
+        // columns count chars, not bytes
+        self.source[line_start..]
+            .char_indices()
+            .nth(pos.column)
+            .map(|(ix, _)| line_start + ix)
+            .unwrap_or(self.source.len())
+    }
+
+    pub fn start(&self, span: Span) -> usize {
+        self.offset(span.start())
+    }
+
+    pub fn end(&self, span: Span) -> usize {
+        self.offset(span.end())
+    }
+
+    /// Whitespace in front of the line `offset` is on
+    pub fn indent_at(&self, offset: usize) -> &'a str {
+        let line_start = self.source[..offset].rfind('\n').map_or(0, |ix| ix + 1);
+        let line = &self.source[line_start..];
+        &line[..line.len() - line.trim_start_matches([' ', '\t']).len()]
+    }
+
+    /// Extends `end` over the whitespace up to the next token, so removing a range drops its line
+    pub fn skip_whitespace(&self, end: usize) -> usize {
+        end + (self.source[end..].len() - self.source[end..].trim_start().len())
+    }
+
+    /// `file:line:column` prefix for an error at `span`
+    pub fn location(&self, file: &str, span: Span) -> String {
+        let LineColumn { line, column } = span.start();
+        format!("{file}:{line}:{}", column + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Edit {
+    pub range: Range<usize>,
+    pub text: String,
+}
+
+impl Edit {
+    pub fn replace(range: Range<usize>, text: impl Into<String>) -> Self {
+        Self { range, text: text.into() }
+    }
+
+    pub fn insert(at: usize, text: impl Into<String>) -> Self {
+        Self::replace(at..at, text)
+    }
+
+    pub fn remove(range: Range<usize>) -> Self {
+        Self::replace(range, String::new())
+    }
+}
+
+/// Applies non-overlapping edits, inserts at the same offset keep their order
+pub fn apply_edits(source: &str, edits: Vec<Edit>) -> String {
+    apply_edits_with_spans(source, edits).0
+}
+
+/// [`apply_edits`], also returning the ranges of the output the edits wrote
+pub fn apply_edits_with_spans(source: &str, mut edits: Vec<Edit>) -> (String, Vec<Range<usize>>) {
+    edits.sort_by_key(|edit| (edit.range.start, edit.range.end));
+    let mut out = String::with_capacity(source.len());
+    let mut spans = Vec::with_capacity(edits.len());
+    let mut cursor = 0;
+    for edit in edits {
+        let start = edit.range.start.max(cursor);
+        out.push_str(&source[cursor..start]);
+        spans.push(out.len()..out.len() + edit.text.len());
+        out.push_str(&edit.text);
+        cursor = edit.range.end.max(start);
+    }
+    out.push_str(&source[cursor..]);
+    (out, spans)
+}
+
+/// Formats the innermost items touched by `rewritten` with `format` (e.g. rustfmt), leaving the
+/// rest of the file as it was written
+pub fn format_rewritten(
+    source: &str,
+    rewritten: &[Range<usize>],
+    format: impl Fn(&str) -> Option<String>,
+) -> String {
+    let Ok(file) = syn::parse_file(source) else {
+        return source.to_string();
+    };
+    let map = SourceMap::new(source);
+    let mut touched = vec![];
+    touched_items(&map, &file.items, rewritten, &mut touched);
+    let edits = touched
+        .into_iter()
+        .filter_map(|range| {
+            let indent = map.indent_at(range.start);
+            let text = source[range.clone()]
+                .lines()
+                .map(|line| line.strip_prefix(indent).unwrap_or(line))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let formatted = format(&text)?;
+            let formatted = formatted
+                .trim_end()
+                .lines()
+                .map(|line| if line.is_empty() { String::new() } else { format!("{indent}{line}") })
+                .collect::<Vec<_>>()
+                .join("\n");
+            Some(Edit::replace(range, formatted))
+        })
+        .collect();
+    apply_edits(source, edits)
+}
+
+/// Line-start to end ranges of the innermost items overlapping `rewritten`
+fn touched_items(
+    map: &SourceMap,
+    items: &[syn::Item],
+    rewritten: &[Range<usize>],
+    touched: &mut Vec<Range<usize>>,
+) {
+    for item in items {
+        let range = item_range(map, item.span());
+        if !rewritten.iter().any(|span| overlaps(span, &range)) {
+            continue;
+        }
+        let before = touched.len();
+        match item {
+            syn::Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) => {
+                touched_items(map, items, rewritten, touched);
+            }
+            syn::Item::Impl(item_impl) => {
+                let fns = item_impl
+                    .items
+                    .iter()
+                    .filter(|item| matches!(item, syn::ImplItem::Fn(_)))
+                    .map(|item| item_range(map, item.span()));
+                let fns = fns.collect::<Vec<_>>();
+                // only narrowed when every edit is inside one of the fns
+                if rewritten
+                    .iter()
+                    .filter(|span| overlaps(span, &range))
+                    .all(|span| fns.iter().any(|item| contains(item, span)))
+                {
+                    touched.extend(
+                        fns.into_iter()
+                            .filter(|item| rewritten.iter().any(|span| overlaps(span, item))),
+                    );
+                }
+            }
+            _ => {}
+        }
+        if touched.len() == before {
+            touched.push(range);
+        }
+    }
+}
+
+fn item_range(map: &SourceMap, span: Span) -> Range<usize> {
+    let start = map.start(span);
+    start - map.indent_at(start).len()..map.end(span)
+}
+
+/// Empty spans are removals and touch the items they're in or next to
+fn overlaps(span: &Range<usize>, item: &Range<usize>) -> bool {
+    if span.is_empty() {
+        item.start <= span.start && span.start <= item.end
+    } else {
+        span.start < item.end && item.start < span.end
+    }
+}
+
+fn contains(item: &Range<usize>, span: &Range<usize>) -> bool {
+    item.start <= span.start && span.end <= item.end
+}
+
+/// `#[path(args)]` without the spaces `TokenStream::to_string` puts between every token
+///
+/// rustfmt leaves attributes alone unless their args are literals, so the tools print them the
+/// way they'd be written by hand.
+pub fn attr_to_string(attr: &Attribute) -> String {
+    let mut tokens = TokenStream::new();
+    attr.bracket_token.surround(&mut tokens, |tokens| attr.meta.to_tokens(tokens));
+    format!("#{}", tokens_to_string(tokens))
+}
+
+enum Token {
+    Word(String),
+    /// joint puncts are merged, e.g. `::`
+    Op(String),
+    Group(Delimiter, String),
+}
+
+const OPS: &[&str] = &[
+    "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "..", "..=",
+];
+
+/// Good enough for the paths, calls and simple expressions used in attribute args
+pub fn tokens_to_string(tokens: TokenStream) -> String {
+    let mut merged = Vec::<Token>::new();
+    let mut joint = false;
+    for tree in tokens {
+        match tree {
+            TokenTree::Punct(punct) => {
+                match merged.last_mut() {
+                    Some(Token::Op(op))
+                        if joint && OPS.contains(&format!("{op}{}", punct.as_char()).as_str()) =>
+                    {
+                        op.push(punct.as_char())
+                    }
+                    _ => merged.push(Token::Op(punct.as_char().to_string())),
+                }
+                joint = punct.spacing() == Spacing::Joint;
+                continue;
+            }
+            TokenTree::Ident(ident) => merged.push(Token::Word(ident.to_string())),
+            TokenTree::Literal(lit) => merged.push(Token::Word(lit.to_string())),
+            TokenTree::Group(group) => {
+                merged.push(Token::Group(group.delimiter(), tokens_to_string(group.stream())))
+            }
+        }
+        joint = false;
+    }
+
+    let mut out = String::new();
+    let mut prev: Option<&Token> = None;
+    for token in &merged {
+        let space = match (prev, token) {
+            (None, _) => false,
+            (Some(Token::Op(op)), _)
+                if matches!(op.as_str(), "::" | "." | "&" | "#" | "'" | "<" | "!") =>
+            {
+                false
+            }
+            (_, Token::Op(op)) if matches!(op.as_str(), "," | ";" | "." | ":" | ">" | "?") => false,
+            // generics and leading `::` in paths
+            (Some(Token::Word(_) | Token::Op(_)), Token::Op(op))
+                if matches!(op.as_str(), "::" | "<")
+                    && !matches!(prev, Some(Token::Op(prev)) if prev == "=" || prev == ",") =>
+            {
+                false
+            }
+            (Some(Token::Word(_)), Token::Op(op)) if op == "!" => false,
+            (
+                Some(Token::Word(_) | Token::Group(..)),
+                Token::Group(Delimiter::Parenthesis | Delimiter::Bracket, _),
+            ) => false,
+            (Some(Token::Op(op)), Token::Group(Delimiter::Parenthesis | Delimiter::Bracket, _))
+                if op == ">" =>
+            {
+                false
+            }
+            _ => true,
+        };
+        if space {
+            out.push(' ');
+        }
+        match token {
+            Token::Word(word) | Token::Op(word) => out.push_str(word),
+            Token::Group(delimiter, inner) => {
+                let (open, close) = match delimiter {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace if inner.is_empty() => ("{", "}"),
+                    Delimiter::Brace => ("{ ", " }"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                out.push_str(inner);
+                out.push_str(close);
+            }
+        }
+        prev = Some(token);
+    }
+    out
+}
+
+/// The manifest of this crate, for the tools' tests
+#[cfg(test)]
+pub(crate) fn manifest_dir() -> &'static std::path::Path {
+    std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use internal_test_proc_macro::xtest;
+    use syn::parse::Parser;
+
+    #[xtest]
+    fn test_source_map_offsets() {
+        let source = "struct A;\n  // é\n  struct B;\n";
+        let file = syn::parse_file(source).unwrap();
+        let map = SourceMap::new(source);
+        let syn::Item::Struct(b) = &file.items[1] else { unreachable!() };
+        let start = map.start(b.struct_token.span);
+        assert_eq!(&source[start..], "struct B;\n");
+        assert_eq!(map.indent_at(start), "  ");
+    }
+
+    #[xtest]
+    fn test_apply_edits() {
+        let source = "abcdef";
+        let edits = vec![
+            Edit::insert(6, "!"),
+            Edit::remove(1..3),
+            Edit::insert(0, "<"),
+            Edit::insert(0, ">"),
+        ];
+        assert_eq!(apply_edits(source, edits), "<>adef!");
+    }
+
+    #[xtest]
+    fn test_apply_edits_with_spans() {
+        let edits = vec![
+            Edit::replace(1..3, "XY"),
+            Edit::remove(4..5),
+            Edit::insert(6, "!"),
+        ];
+        let (out, spans) = apply_edits_with_spans("abcdef", edits);
+        assert_eq!(out, "aXYdf!");
+        assert_eq!(spans, vec![1..3, 4..4, 5..6]);
+    }
+
+    #[xtest]
+    fn test_format_rewritten_only_touched_items() {
+        let source = "struct A  ;\n\nmod m {\n    struct B  ;\n    struct C  ;\n}\n";
+        let start = source.find("struct C").unwrap();
+        let rewritten = start..start + 6;
+        let formatted = format_rewritten(source, std::slice::from_ref(&rewritten), |text| {
+            Some(text.replace("  ;", ";"))
+        });
+        assert_eq!(formatted, "struct A  ;\n\nmod m {\n    struct B  ;\n    struct C;\n}\n");
+    }
+
+    #[xtest]
+    fn test_format_rewritten_narrows_to_impl_fns() {
+        let source = "impl A {\n    fn a() {  }\n    fn b() {  }\n}\n";
+        let start = source.find("fn b").unwrap();
+        let rewritten = start..start;
+        let formatted = format_rewritten(source, std::slice::from_ref(&rewritten), |text| {
+            Some(text.replace("{  }", "{}"))
+        });
+        assert_eq!(formatted, "impl A {\n    fn a() {  }\n    fn b() {}\n}\n");
+    }
+
+    #[xtest]
+    fn test_attr_to_string() {
+        let attrs = [
+            "#[auto_register_type(plugin = MyPlugin, generics(Vec<u8>))]",
+            "#[auto_add_system(plugin = MyPlugin, schedule = Update, config(run_if = resource_exists::<Foo>))]",
+            "#[auto_insert_resource(plugin = MyPlugin, insert = Foo { a: 1 })]",
+            "#[derive(::bevy_ecs::prelude::Component, Debug)]",
+            "#[auto_event(plugin = MyPlugin, traversal = &'static ChildOf, observer = log!(\"hi\"))]",
+        ];
+        for attr in attrs {
+            let parsed = Attribute::parse_outer.parse_str(attr).unwrap().remove(0);
+            assert_eq!(attr_to_string(&parsed), attr);
+        }
+    }
+}
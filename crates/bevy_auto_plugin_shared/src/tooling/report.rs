@@ -0,0 +1,720 @@
+//! `cargo auto-plugin report`: which items register with which plugins, without building anything
+//!
+//! Every `auto_*` attribute is parsed with the macros' own parsers. Rewrites are expanded into the
+//! actions they stand for, so an `auto_system` shows up as the `auto_add_system` it registers.
+//! Plugins are matched by the last segment of their path, generic arguments included.
+
+use crate::{
+    __private::expand::attr::auto_bind_plugin::auto_bind_plugin_inner,
+    codegen::ExpandAttrs,
+    macro_api::{
+        prelude::*,
+        schedule_config::ScheduleWithScheduleConfigArgs,
+    },
+    syntax::{
+        extensions::item::ItemAttrsExt,
+        parse::generics_set::{
+            GenericsSets,
+            resolve_generics_sets,
+        },
+    },
+    tooling::{
+        eject::derives_auto_plugin,
+        manifest::with_manifest_dir,
+        tokens_to_string,
+    },
+};
+use proc_macro2::TokenStream as MacroStream;
+use quote::ToTokens;
+use serde::Serialize;
+use std::{
+    collections::{
+        BTreeMap,
+        VecDeque,
+    },
+    fmt::Write,
+    path::Path,
+};
+use syn::{
+    Attribute,
+    Item,
+    Meta,
+    parse::Parser,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct SourceLocation {
+    pub file: String,
+    pub line: usize,
+}
+
+impl std::fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Entry {
+    /// the action that registers it, e.g. `auto_add_system`
+    pub attribute: String,
+    /// the rewrite it was expanded from, e.g. `auto_system`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub via: Option<String>,
+    pub item: String,
+    pub location: SourceLocation,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub after_build: bool,
+    /// `None` falls back to the plugin's `default_schedule`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub in_set: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub run_if: Vec<String>,
+    /// concrete types registered for a generic item
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PluginReport {
+    /// `None` when no `#[derive(AutoPlugin)]` for it was scanned
+    pub location: Option<SourceLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_schedule: Option<String>,
+    /// `requires(...)`, e.g. `InputPlugin` or `InputPlugin (assert)`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requires: Vec<String>,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub plugins: BTreeMap<String, PluginReport>,
+    /// attributes that couldn't be parsed or resolved
+    pub notes: Vec<String>,
+}
+
+/// Last path segment with its generic args, e.g. `StatPlugin<Health>` for `crate::StatPlugin<Health>`
+fn plugin_name(path: &syn::Path) -> String {
+    path.segments
+        .last()
+        .map(|segment| tokens_to_string(segment.to_token_stream()))
+        .unwrap_or_default()
+}
+
+fn attr_name(attr: &Attribute) -> Option<String> {
+    attr.path().segments.last().map(|segment| segment.ident.to_string())
+}
+
+fn attr_args(attr: &Attribute) -> MacroStream {
+    match &attr.meta {
+        Meta::List(list) => list.tokens.clone(),
+        _ => MacroStream::new(),
+    }
+}
+
+/// What an entry registers besides its item
+trait EntryDetails {
+    fn details(&self, _entry: &mut Entry) {}
+}
+
+fn schedule_details(schedule_config: &ScheduleWithScheduleConfigArgs, entry: &mut Entry) {
+    let string = |tokens: &dyn ToTokens| tokens_to_string(tokens.to_token_stream());
+    entry.schedule = schedule_config.schedule.as_ref().map(|schedule| string(schedule));
+    let config = &schedule_config.config;
+    entry.in_set = config.in_set.iter().map(|in_set| string(in_set)).collect();
+    entry.run_if = config
+        .run_if
+        .iter()
+        .chain(&config.distributive_run_if)
+        .map(|run_if| string(run_if))
+        .collect();
+}
+
+impl EntryDetails for IaAddSystem {
+    fn details(&self, entry: &mut Entry) {
+        schedule_details(&self.args.base.schedule_config, entry);
+    }
+}
+
+impl EntryDetails for IaConfigureSystemSet {
+    fn details(&self, entry: &mut Entry) {
+        schedule_details(&self.args.base.schedule_config, entry);
+    }
+}
+
+impl EntryDetails for IaAddObserver {
+    fn details(&self, entry: &mut Entry) {
+        let run_if = &self.args.base.config.run_if;
+        entry.run_if =
+            run_if.iter().map(|run_if| tokens_to_string(run_if.to_token_stream())).collect();
+    }
+}
+
+macro_rules! impl_no_entry_details {
+    ($($ty:ty),+ $(,)?) => {
+        $(impl EntryDetails for $ty {})+
+    };
+}
+
+impl_no_entry_details!(
+    IaRunOnBuild,
+    IaRegisterType,
+    IaAddMessage,
+    IaInitResource,
+    IaInsertResource,
+    IaInitState,
+    IaInitSubState,
+    IaName,
+    IaRegisterStateType,
+    IaAddPlugin,
+    IaAutoPluginBuildHook,
+);
+
+macro_rules! report_attr_dispatch {
+    ($self:ident, $name:expr, $attr:ident, $input:ident;
+        actions: [$($action:ty),+ $(,)?];
+        rewrites: [$($rewrite:ty),+ $(,)?] $(;)?
+    ) => {{
+        $(
+            if $name == <$action as AttributeIdent>::IDENT {
+                return $self.action::<$action>($attr, $input).map(Step::Entries);
+            }
+        )+
+        $(
+            if $name == <$rewrite as AttributeIdent>::IDENT {
+                return $self.rewrite::<$rewrite>($attr, $input).map(Step::Expand);
+            }
+        )+
+    }};
+}
+
+/// An attribute waiting to be scanned, expanded ones keep the line of the attribute they came from
+struct Pending {
+    attr: Attribute,
+    via: Option<String>,
+    line: usize,
+}
+
+enum Step {
+    Skip,
+    Expand(Vec<Attribute>),
+    Entries(Vec<(String, Entry)>),
+}
+
+impl Report {
+    /// Adds the plugins and entries declared in `source`, resolving bevy crate paths from the
+    /// `Cargo.toml` in `manifest_dir`
+    pub fn scan(&mut self, file: &str, source: &str, manifest_dir: &Path) -> syn::Result<()> {
+        let parsed = syn::parse_file(source)?;
+        with_manifest_dir(manifest_dir, || {
+            self.scan_items(file, &parsed.items);
+            Ok(())
+        })
+    }
+
+    /// Registry order: entries run in the order their attributes are declared
+    pub fn sort(&mut self) {
+        for plugin in self.plugins.values_mut() {
+            plugin.entries.sort_by(|a, b| a.location.cmp(&b.location));
+        }
+    }
+
+    fn scan_items(&mut self, file: &str, items: &[Item]) {
+        for item in items {
+            self.scan_item(file, item);
+            match item {
+                Item::Mod(syn::ItemMod { content: Some((_, items)), .. }) => {
+                    self.scan_items(file, items);
+                }
+                Item::Impl(item_impl)
+                    if item_impl
+                        .attrs
+                        .iter()
+                        .any(|attr| attr_name(attr).as_deref() == Some("auto_assoc_fns")) =>
+                {
+                    self.notes.push(format!(
+                        "{file}:{}: `auto_assoc_fns` impls aren't scanned",
+                        item_impl.impl_token.span.start().line
+                    ));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn declare_plugin(&mut self, file: &str, item: &Item) {
+        let Some(ident) = item.get_ident() else {
+            return;
+        };
+        let location = SourceLocation { file: file.to_string(), line: ident.span().start().line };
+        let mut default_schedule = None;
+        let mut requires = vec![];
+        for attr in item.attrs().unwrap_or_default() {
+            if !attr.path().is_ident("auto_plugin") || matches!(attr.meta, Meta::Path(_)) {
+                continue;
+            }
+            match attr.parse_args::<AutoPluginStructOrEnumArgs>() {
+                Ok(args) => {
+                    default_schedule = args
+                        .default_schedule
+                        .or(args.defaults.system.schedule)
+                        .map(|schedule| tokens_to_string(schedule.to_token_stream()));
+                    for args in args.requires {
+                        let suffix = match args.mode {
+                            RequiresMode::Add => "",
+                            RequiresMode::Assert => " (assert)",
+                        };
+                        requires.extend(args.plugins.iter().map(|plugin| {
+                            format!("{}{suffix}", tokens_to_string(plugin.to_token_stream()))
+                        }));
+                    }
+                }
+                Err(err) => self.notes.push(format!("{location}: {err}")),
+            }
+        }
+        let plugin = self.plugins.entry(ident.to_string()).or_default();
+        plugin.location = Some(location);
+        plugin.default_schedule = default_schedule;
+        plugin.requires = requires;
+    }
+
+    fn scan_item(&mut self, file: &str, item: &Item) {
+        let Some(attrs) = item.attrs() else {
+            return;
+        };
+        if matches!(item, Item::Struct(_) | Item::Enum(_)) && derives_auto_plugin(item) {
+            self.declare_plugin(file, item);
+        }
+        let mut body = item.clone();
+        let _ = body.take_attrs();
+        let mut rest = attrs
+            .iter()
+            .map(|attr| Pending {
+                attr: attr.clone(),
+                via: None,
+                line: attr.pound_token.span.start().line,
+            })
+            .collect::<VecDeque<_>>();
+        while let Some(Pending { attr, via, line }) = rest.pop_front() {
+            let Some(name) = attr_name(&attr) else {
+                continue;
+            };
+            let location = SourceLocation { file: file.to_string(), line };
+            let mut input = body.clone();
+            let _ = input.put_attrs(rest.iter().map(|pending| pending.attr.clone()).collect());
+            let input = input.to_token_stream();
+            if name == "auto_bind_plugin" {
+                let bound = auto_bind_plugin_inner(attr_args(&attr), input, Context::default())
+                    .and_then(syn::parse2::<Item>);
+                match bound {
+                    // the attributes below, with the plugin filled in
+                    Ok(mut bound) => {
+                        for (pending, attr) in
+                            rest.iter_mut().zip(bound.take_attrs().unwrap_or_default())
+                        {
+                            pending.attr = attr;
+                        }
+                    }
+                    Err(err) => self.notes.push(format!("{location}: `{name}`: {err}")),
+                }
+                continue;
+            }
+            match self.scan_attr(&name, attr_args(&attr), input) {
+                Ok(Step::Skip) => {}
+                Ok(Step::Expand(attrs)) => {
+                    // the actions a rewrite expands into point back at it
+                    let via = via.or(Some(name));
+                    for attr in attrs.into_iter().rev() {
+                        rest.push_front(Pending { attr, via: via.clone(), line });
+                    }
+                }
+                Ok(Step::Entries(entries)) => {
+                    for (plugin, mut entry) in entries {
+                        entry.location = location.clone();
+                        entry.via = via.clone();
+                        self.plugins.entry(plugin).or_default().entries.push(entry);
+                    }
+                }
+                Err(err) => self.notes.push(format!("{location}: `{name}`: {err}")),
+            }
+        }
+    }
+
+    fn scan_attr(&self, name: &str, attr: MacroStream, input: MacroStream) -> syn::Result<Step> {
+        report_attr_dispatch! {
+            self, name, attr, input;
+            actions: [
+                IaRunOnBuild,
+                IaRegisterType,
+                IaAddMessage,
+                IaInitResource,
+                IaInsertResource,
+                IaInitState,
+                IaInitSubState,
+                IaName,
+                IaRegisterStateType,
+                IaAddSystem,
+                IaAddObserver,
+                IaAddPlugin,
+                IaConfigureSystemSet,
+                IaAutoPluginBuildHook,
+            ];
+            rewrites: [
+                IaBundle,
+                IaComponent,
+                IaRelationship,
+                IaResource,
+                IaSystem,
+                IaEvent,
+                IaMessage,
+                IaObserver,
+                IaState,
+                IaSubState,
+            ];
+        }
+        Ok(Step::Skip)
+    }
+
+    fn resolve_generics_sets(attr: MacroStream) -> syn::Result<MacroStream> {
+        match resolve_generics_sets(attr)? {
+            GenericsSets::Resolved(attr) => Ok(attr),
+            GenericsSets::Pending(set) => Err(syn::Error::new_spanned(
+                &set,
+                format!("generics set `{}` can't be resolved offline", set.to_token_stream()),
+            )),
+        }
+    }
+
+    /// `(plugin, entry)` for each item the action targets, locations are filled in by the caller
+    fn action<T>(&self, attr: MacroStream, input: MacroStream) -> syn::Result<Vec<(String, Entry)>>
+    where
+        T: ItemAttributeArgs
+            + ItemAttributeParse
+            + ItemAttributeInput
+            + ItemAttributeTargetMut
+            + ItemAttributeContext
+            + ItemAttributePlugin
+            + GenericsCap
+            + EntryDetails,
+    {
+        let attr = Self::resolve_generics_sets(attr)?;
+        let args = T::from_attr_input_with_context(attr, input, Context::default())?;
+        let plugin = plugin_name(args.plugin());
+        let targets = match args.input_item().use_targets()? {
+            None => vec![args.target().clone()],
+            Some(targets) => targets
+                .into_iter()
+                .map(|target| match target {
+                    UseTarget::Item(path) | UseTarget::Module(path) => path,
+                })
+                .collect(),
+        };
+        let mut entries = vec![];
+        for target in targets {
+            let mut args = args.clone();
+            args.set_target(target.clone());
+            let item = tokens_to_string(target.to_token_stream());
+            let generics = args
+                .concrete_paths()?
+                .iter()
+                .map(|path| tokens_to_string(path.to_token_stream()))
+                .filter(|path| path != &item)
+                .collect();
+            let mut entry = Entry {
+                attribute: T::IDENT.to_string(),
+                via: None,
+                item,
+                location: SourceLocation { file: String::new(), line: 0 },
+                after_build: args.plugin_after_build().unwrap_or(false),
+                schedule: None,
+                in_set: vec![],
+                run_if: vec![],
+                generics,
+            };
+            args.details(&mut entry);
+            entries.push((plugin.clone(), entry));
+        }
+        Ok(entries)
+    }
+
+    fn rewrite<T>(&self, attr: MacroStream, input: MacroStream) -> syn::Result<Vec<Attribute>>
+    where
+        T: ItemAttributeArgs
+            + ItemAttributeParse
+            + ItemAttributeInput
+            + ItemAttributeContext
+            + ItemAttributePlugin,
+        AttrExpansionEmitter<T>: AttrExpansionEmitterToExpandAttr,
+    {
+        let attr = Self::resolve_generics_sets(attr)?;
+        let args = T::from_attr_input_with_context(attr, input, Context::default())?;
+        let emitter = AttrExpansionEmitter::from_item_attribute(args);
+        emitter.validate()?;
+        let mut expand_attrs = ExpandAttrs::default();
+        emitter.to_expand_attrs(&mut expand_attrs);
+        let mut attrs = vec![];
+        for tokens in expand_attrs.attrs {
+            attrs.extend(Attribute::parse_outer.parse2(tokens)?);
+        }
+        Ok(attrs)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports only hold strings")
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Plugin report\n");
+        for (name, plugin) in &self.plugins {
+            let _ = writeln!(out, "\n## `{name}`\n");
+            match &plugin.location {
+                Some(location) => {
+                    let _ = write!(out, "Declared at {location}");
+                }
+                None => out.push_str("No `#[derive(AutoPlugin)]` found"),
+            }
+            if let Some(schedule) = &plugin.default_schedule {
+                let _ = write!(out, ", default schedule `{schedule}`");
+            }
+            if !plugin.requires.is_empty() {
+                let requires =
+                    plugin.requires.iter().map(|plugin| format!("`{plugin}`")).collect::<Vec<_>>();
+                let _ = write!(out, ", requires {}", requires.join(", "));
+            }
+            out.push_str("\n\n");
+            if plugin.entries.is_empty() {
+                out.push_str("No entries\n");
+                continue;
+            }
+            out.push_str(
+                "| attribute | item | schedule | in_set | run_if | generics | location |\n",
+            );
+            out.push_str("|---|---|---|---|---|---|---|\n");
+            let code = |values: &[String]| {
+                values.iter().map(|value| format!("`{value}`")).collect::<Vec<_>>().join(", ")
+            };
+            for entry in &plugin.entries {
+                let mut attribute = format!("`{}`", entry.attribute);
+                if let Some(via) = &entry.via {
+                    let _ = write!(attribute, " (via `{via}`)");
+                }
+                if entry.after_build {
+                    attribute.push_str(" after build");
+                }
+                let schedule = match (&entry.schedule, &plugin.default_schedule) {
+                    (Some(schedule), _) => format!("`{schedule}`"),
+                    (None, Some(schedule)) if entry.attribute == IaAddSystem::IDENT => {
+                        format!("`{schedule}` (default)")
+                    }
+                    _ => String::new(),
+                };
+                let _ = writeln!(
+                    out,
+                    "| {attribute} | `{}` | {schedule} | {} | {} | {} | {} |",
+                    entry.item,
+                    code(&entry.in_set),
+                    code(&entry.run_if),
+                    code(&entry.generics),
+                    entry.location,
+                );
+            }
+        }
+        if !self.notes.is_empty() {
+            out.push_str("\n## Notes\n\n");
+            for note in &self.notes {
+                let _ = writeln!(out, "- {note}");
+            }
+        }
+        out
+    }
+
+    /// Plugins are boxes, `auto_add_plugin` entries are edges between them and `requires` dashed edges
+    pub fn to_dot(&self) -> String {
+        let quote =
+            |value: &str| format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""));
+        let mut out = String::from("digraph auto_plugin {\n    rankdir=LR;\n");
+        for (name, plugin) in &self.plugins {
+            let style = if plugin.location.is_some() { "" } else { ", style=dashed" };
+            let _ = writeln!(out, "    {} [shape=box{style}];", quote(name));
+            for required in &plugin.requires {
+                let (required, label) = match required.strip_suffix(" (assert)") {
+                    Some(required) => (required, "asserts"),
+                    None => (required.as_str(), "requires"),
+                };
+                let _ = writeln!(
+                    out,
+                    "    {} -> {} [style=dashed, label={label}];",
+                    quote(name),
+                    quote(required)
+                );
+            }
+            for (ix, entry) in plugin.entries.iter().enumerate() {
+                if entry.attribute == IaAddPlugin::IDENT {
+                    let _ = writeln!(out, "    {} -> {};", quote(name), quote(&entry.item));
+                    continue;
+                }
+                let node = quote(&format!("{name}#{ix}"));
+                let mut label = format!("{}\\n{}", entry.attribute, entry.item);
+                if let Some(schedule) = &entry.schedule {
+                    let _ = write!(label, "\\n{schedule}");
+                }
+                let label = label.replace('"', "\\\"");
+                let _ = writeln!(out, "    {node} [shape=ellipse, label=\"{label}\"];");
+                let _ = writeln!(out, "    {} -> {node};", quote(name));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tooling::manifest_dir;
+    use internal_test_proc_macro::xtest;
+
+    fn report(source: &str) -> Report {
+        let mut report = Report::default();
+        report.scan("src/lib.rs", source, manifest_dir()).unwrap();
+        report.sort();
+        report
+    }
+
+    #[xtest]
+    fn test_report_plugins_and_entries() {
+        let report = report(
+            r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait, default_schedule = FixedUpdate)]
+            struct MyPlugin;
+
+            #[auto_system(plugin = MyPlugin, schedule = Update, config(in_set = MySet, run_if = ready))]
+            fn tick() {}
+
+            #[derive(Reflect)]
+            #[auto_register_type(plugin = MyPlugin, generics(u8), generics(bool))]
+            struct Stat<T>(T);
+
+            #[auto_add_system(plugin = Other)]
+            fn other() {}
+            "#,
+        );
+        assert_eq!(report.plugins.keys().collect::<Vec<_>>(), ["MyPlugin", "Other"]);
+        let plugin = &report.plugins["MyPlugin"];
+        assert_eq!(
+            plugin.location,
+            Some(SourceLocation { file: "src/lib.rs".to_string(), line: 4 })
+        );
+        assert_eq!(plugin.default_schedule.as_deref(), Some("FixedUpdate"));
+        let [system, register] = plugin.entries.as_slice() else {
+            panic!("{:?}", plugin.entries);
+        };
+        assert_eq!(system.attribute, "auto_add_system");
+        assert_eq!(system.via.as_deref(), Some("auto_system"));
+        assert_eq!(system.item, "tick");
+        assert_eq!(system.schedule.as_deref(), Some("Update"));
+        assert_eq!(system.in_set, ["MySet"]);
+        assert_eq!(system.run_if, ["ready"]);
+        assert_eq!(register.item, "Stat");
+        assert_eq!(register.generics, ["Stat::<u8>", "Stat::<bool>"]);
+        assert_eq!(register.location.line, 10);
+        assert_eq!(report.plugins["Other"].location, None);
+    }
+
+    #[xtest]
+    fn test_report_defaults_schedule() {
+        let report = report(
+            r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait, defaults(system(schedule = FixedUpdate)))]
+            struct MyPlugin;
+            "#,
+        );
+        assert_eq!(report.plugins["MyPlugin"].default_schedule.as_deref(), Some("FixedUpdate"));
+    }
+
+    #[xtest]
+    fn test_report_bind_plugin_and_use() {
+        let report = report(
+            r#"
+            #[auto_bind_plugin(plugin = MyPlugin)]
+            #[auto_init_resource]
+            #[derive(Resource, Default)]
+            struct Foo;
+
+            #[auto_register_type(plugin = MyPlugin)]
+            use a::{B, C};
+            "#,
+        );
+        let items = report.plugins["MyPlugin"]
+            .entries
+            .iter()
+            .map(|entry| (entry.attribute.as_str(), entry.item.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            [
+                ("auto_init_resource", "Foo"),
+                ("auto_register_type", "B"),
+                ("auto_register_type", "C")
+            ]
+        );
+    }
+
+    #[xtest]
+    fn test_report_notes_invalid_attributes() {
+        let report = report(
+            r#"
+            #[auto_init_resource(plugin = MyPlugin, shedule = Update)]
+            struct Foo;
+            "#,
+        );
+        assert!(report.plugins.is_empty());
+        assert_eq!(report.notes.len(), 1);
+        assert!(
+            report.notes[0].starts_with("src/lib.rs:2: `auto_init_resource`: "),
+            "{:?}",
+            report.notes
+        );
+    }
+
+    #[xtest]
+    fn test_report_formats() {
+        let report = report(
+            r#"
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait, requires(InputPlugin, mode = assert))]
+            struct MyPlugin;
+
+            #[derive(AutoPlugin)]
+            #[auto_plugin(impl_plugin_trait)]
+            #[auto_add_plugin(plugin = MyPlugin)]
+            struct Child;
+
+            #[auto_add_system(plugin = Child, schedule = Update)]
+            fn tick() {}
+            "#,
+        );
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("## `Child`\n\nDeclared at src/lib.rs:9\n"), "{markdown}");
+        assert!(
+            markdown.contains("| `auto_add_system` | `tick` | `Update` |  |  |  | src/lib.rs:11 |"),
+            "{markdown}"
+        );
+        assert!(markdown.contains("requires `InputPlugin (assert)`"), "{markdown}");
+        let dot = report.to_dot();
+        assert!(dot.contains("\"MyPlugin\" -> \"Child\";"), "{dot}");
+        assert!(
+            dot.contains("\"MyPlugin\" -> \"InputPlugin\" [style=dashed, label=asserts];"),
+            "{dot}"
+        );
+        assert!(dot.contains("\"Child\" -> \"Child#0\";"), "{dot}");
+        let json = serde_json::from_str::<serde_json::Value>(&report.to_json()).unwrap();
+        assert_eq!(json["plugins"]["Child"]["entries"][0]["schedule"], "Update");
+        assert_eq!(json["plugins"]["MyPlugin"]["entries"][0]["item"], "Child");
+    }
+}
@@ -80,7 +80,8 @@ macro_rules! as_cargo_alias {
 
 macro_rules! bevy_crate_path {
     ($target_crate:ident) => {{
-        use ::proc_macro_crate::{FoundCrate, crate_name};
+        use ::proc_macro_crate::FoundCrate;
+        use $crate::util::crate_name;
         use ::quote::quote;
         use ::syn::parse2;
         use ::std::{concat, stringify};
@@ -135,6 +136,19 @@ macro_rules! impl_from_default {
     };
 }
 
+macro_rules! impl_has_keys {
+    ($ty:ident => [$($key:literal),* $(,)?]) => {
+        impl $crate::macro_api::prelude::HasKeys for $ty {
+            fn keys() -> &'static [&'static str] {
+                &[$($key),*]
+            }
+            fn attribute() -> Option<&'static str> {
+                Some(<$ty as $crate::macro_api::prelude::AttributeIdent>::IDENT)
+            }
+        }
+    };
+}
+
 #[allow(unused_imports)]
 #[rustfmt::skip]
 pub(crate) use {
@@ -146,6 +160,7 @@ pub(crate) use {
     as_cargo_alias,
     bevy_crate_path,
     impl_from_default,
+    impl_has_keys,
 };
 
 #[cfg(test)]
@@ -1 +1,10 @@
 pub mod macros;
+
+/// `proc_macro_crate::crate_name`, against the manifest the tools scoped when there is one
+pub fn crate_name(name: &str) -> Result<proc_macro_crate::FoundCrate, String> {
+    #[cfg(feature = "tooling")]
+    if let Some(found) = crate::tooling::manifest::crate_name(name) {
+        return found;
+    }
+    proc_macro_crate::crate_name(name).map_err(|err| err.to_string())
+}
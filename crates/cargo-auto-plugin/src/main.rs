@@ -0,0 +1,13 @@
+//! `cargo auto-plugin <command>`
+//!
+//! Offline source tools for `bevy_auto_plugin`, built on the same parsers as the macros.
+
+use bevy_auto_plugin_shared::tooling::cli;
+use std::{
+    env,
+    process::ExitCode,
+};
+
+fn main() -> ExitCode {
+    cli::main(env::args().skip(1))
+}
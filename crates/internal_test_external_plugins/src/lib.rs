@@ -0,0 +1,69 @@
+//! Plugins declared outside the crate using them, for the cross-crate e2e tests
+
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+pub struct ExternalPlugin;
+
+pub mod defaults {
+    use bevy::prelude::*;
+    use bevy_auto_plugin::prelude::*;
+
+    #[derive(AutoPlugin)]
+    #[auto_plugin(impl_plugin_trait, defaults(component(reflect(Default))))]
+    pub struct ExternalDefaultsPlugin;
+}
+
+pub mod generic {
+    use bevy::prelude::*;
+    use bevy_auto_plugin::prelude::*;
+    use std::marker::PhantomData;
+
+    pub trait Gear: Send + Sync + 'static {}
+
+    #[derive(Resource)]
+    #[auto_init_resource(plugin = ExternalGearPlugin::<T>, generics(Armor))]
+    pub struct Worn<T: Gear>(PhantomData<T>);
+
+    impl<T: Gear> Default for Worn<T> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    pub struct Armor;
+    impl Gear for Armor {}
+
+    pub struct Boots;
+    impl Gear for Boots {}
+
+    #[derive(AutoPlugin)]
+    #[auto_plugin(impl_plugin_trait)]
+    pub struct ExternalGearPlugin<T: Gear>(PhantomData<T>);
+
+    impl<T: Gear> Default for ExternalGearPlugin<T> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+}
+
+/// Re-applied to through `use internal_test_external_plugins::exported::*`
+#[auto_export]
+pub mod exported {
+    use bevy::prelude::*;
+
+    #[derive(Resource, Reflect, Default)]
+    pub struct Score(pub u32);
+
+    #[derive(Reflect)]
+    pub struct Tag;
+
+    #[derive(Resource, Default)]
+    pub struct Lives(pub u32);
+
+    pub fn award(mut score: ResMut<Score>) {
+        score.0 += 1;
+    }
+}
@@ -148,7 +148,32 @@
 //! #[auto_register_type(plugin = MyPlugin)]
 //! use components::{FooComponent, BarComponent as BazComponent};
 //! ```
-//! Each imported name becomes its own entry. `use ...::*`, `use ...::self`, and `_` imports are not supported.
+//! Each imported name becomes its own entry.
+//!
+//! Glob (`use module::*`) and `self` imports are supported for modules marked with `#[auto_export]`,
+//! which expand to every public, non-generic struct, enum, and type alias (or function, for system-like actions) in the module.
+//! ```rust
+//! use bevy::prelude::*;
+//! use bevy_auto_plugin::prelude::*;
+//!
+//! #[derive(AutoPlugin)]
+//! #[auto_plugin(impl_plugin_trait)]
+//! struct MyPlugin;
+//!
+//! #[auto_export]
+//! mod components {
+//!     use bevy::prelude::*;
+//!
+//!     #[derive(Reflect)]
+//!     pub struct FooComponent;
+//!
+//!     #[derive(Reflect)]
+//!     pub struct BarComponent;
+//! }
+//!
+//! #[auto_register_type(plugin = MyPlugin)]
+//! use components::*;
+//! ```
 //!
 //! ### Custom Build Hooks (Third-Party Integration)
 //! You can use `#[auto_plugin_build_hook]` as a building block for third-party
@@ -184,6 +209,18 @@ pub mod __private {
     pub use bevy_auto_plugin_shared as shared;
 }
 
+#[cfg(feature = "testing")]
+#[doc = include_str!("../docs/testing.md")]
+pub mod testing {
+    pub use bevy_auto_plugin_shared::testing::*;
+}
+
+#[cfg(feature = "tooling")]
+#[doc = include_str!("../docs/tooling.md")]
+pub mod tooling {
+    pub use bevy_auto_plugin_shared::tooling::*;
+}
+
 pub mod prelude {
     #[doc = include_str!("../docs/derives/AutoPlugin.md")]
     pub use bevy_auto_plugin_proc_macros::AutoPlugin;
@@ -215,6 +252,15 @@ pub mod prelude {
     #[doc = include_str!("../docs/proc_attributes/auto_plugin.md")]
     pub use bevy_auto_plugin_proc_macros::auto_plugin;
 
+    #[doc = include_str!("../docs/proc_attributes/auto_assoc_fns.md")]
+    pub use bevy_auto_plugin_proc_macros::auto_assoc_fns;
+
+    #[doc = include_str!("../docs/proc_attributes/auto_export.md")]
+    pub use bevy_auto_plugin_proc_macros::auto_export;
+
+    #[doc = include_str!("../docs/proc_macros/auto_generics_set.md")]
+    pub use bevy_auto_plugin_proc_macros::auto_generics_set;
+
     #[doc = include_str!("../docs/proc_attributes/actions/auto_register_state_type.md")]
     pub use bevy_auto_plugin_proc_macros::auto_register_state_type;
 
@@ -227,9 +273,15 @@ pub mod prelude {
     #[doc = include_str!("../docs/proc_attributes/rewrites/auto_component.md")]
     pub use bevy_auto_plugin_proc_macros::auto_component;
 
+    #[doc = include_str!("../docs/proc_attributes/rewrites/auto_relationship.md")]
+    pub use bevy_auto_plugin_proc_macros::auto_relationship;
+
     #[doc = include_str!("../docs/proc_attributes/rewrites/auto_resource.md")]
     pub use bevy_auto_plugin_proc_macros::auto_resource;
 
+    #[doc = include_str!("../docs/proc_attributes/rewrites/auto_bundle.md")]
+    pub use bevy_auto_plugin_proc_macros::auto_bundle;
+
     #[doc = include_str!("../docs/proc_attributes/rewrites/auto_event.md")]
     pub use bevy_auto_plugin_proc_macros::auto_event;
 
@@ -260,6 +312,9 @@ pub mod prelude {
     #[doc(inline)]
     pub use super::__private::shared::AutoPluginBuildHook;
 
+    #[doc(inline)]
+    pub use super::__private::shared::update_manual_messages;
+
     #[doc = include_str!("../docs/proc_attributes/actions/auto_plugin_build_hook.md")]
     pub use bevy_auto_plugin_proc_macros::auto_plugin_build_hook;
 }
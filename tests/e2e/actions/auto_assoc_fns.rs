@@ -0,0 +1,101 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use internal_test_proc_macro::xtest;
+use std::marker::PhantomData;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = TestPlugin)]
+struct Counter(usize);
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = TestPlugin)]
+struct Built(bool);
+
+#[derive(Component)]
+struct Player;
+
+#[derive(Resource, Debug, Default, PartialEq)]
+#[auto_init_resource(plugin = TestPlugin)]
+struct PlayerAdded(bool);
+
+#[auto_assoc_fns]
+impl Player {
+    #[auto_system(plugin = TestPlugin, schedule = Update)]
+    fn movement(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    #[auto_add_system(plugin = TestPlugin, schedule = Update)]
+    fn jump(mut counter: ResMut<Counter>) {
+        counter.0 += 10;
+    }
+
+    #[auto_add_observer(plugin = TestPlugin)]
+    fn on_add(_add: On<Add, Player>, mut added: ResMut<PlayerAdded>) {
+        added.0 = true;
+    }
+
+    #[auto_run_on_build(plugin = TestPlugin)]
+    fn build(app: &mut App) {
+        app.insert_resource(Built(true));
+    }
+
+    #[allow(dead_code)]
+    fn untouched(&self) {}
+}
+
+trait Stat: Send + Sync + 'static {
+    const AMOUNT: usize;
+}
+
+struct Health;
+impl Stat for Health {
+    const AMOUNT: usize = 100;
+}
+
+struct Mana;
+impl Stat for Mana {
+    const AMOUNT: usize = 1000;
+}
+
+struct StatSystems<T>(PhantomData<T>);
+
+#[auto_assoc_fns]
+impl<T: Stat> StatSystems<T> {
+    #[auto_add_system(plugin = TestPlugin, schedule = Update, generics(Health))]
+    #[auto_add_system(plugin = TestPlugin, schedule = Update, generics(T = Mana))]
+    fn regen(mut counter: ResMut<Counter>) {
+        counter.0 += T::AMOUNT;
+    }
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_assoc_fns_systems() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Counter>(), &Counter(1111));
+}
+
+#[xtest]
+fn test_auto_assoc_fns_observer() {
+    let mut app = app();
+    app.world_mut().spawn(Player);
+    assert_eq!(app.world().resource::<PlayerAdded>(), &PlayerAdded(true));
+}
+
+#[xtest]
+fn test_auto_assoc_fns_run_on_build() {
+    let app = app();
+    assert_eq!(app.world().resource::<Built>(), &Built(true));
+}
@@ -40,7 +40,6 @@ struct FooDefaultRes(usize);
 #[derive(Resource, Debug, Default, PartialEq, Reflect)]
 #[reflect(Resource)]
 #[auto_register_type]
-#[auto_init_resource]
 #[auto_insert_resource(insert(FooRes(1)))]
 struct FooRes(usize);
 
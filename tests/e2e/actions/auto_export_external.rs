@@ -0,0 +1,56 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use internal_test_external_plugins::exported::{
+    Lives,
+    Score,
+    Tag,
+};
+use internal_test_proc_macro::xtest;
+use internal_test_util::type_id_of;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_register_type(plugin = TestPlugin)]
+#[allow(unused_imports)]
+use internal_test_external_plugins::exported::*;
+
+#[auto_init_resource(plugin = TestPlugin)]
+#[allow(unused_imports)]
+use internal_test_external_plugins::exported::*;
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update)]
+#[allow(unused_imports)]
+use internal_test_external_plugins::exported::*;
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_register_type_use_external_glob() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<Score>()), "did not auto register Score");
+    assert!(type_registry.contains(type_id_of::<Tag>()), "did not auto register Tag");
+    assert!(!type_registry.contains(type_id_of::<Lives>()), "registered a type without Reflect");
+}
+
+#[xtest]
+fn test_auto_init_resource_use_external_glob() {
+    let app = app();
+    assert!(app.world().contains_resource::<Score>(), "did not init Score");
+    assert!(app.world().contains_resource::<Lives>(), "did not init Lives");
+}
+
+#[xtest]
+fn test_auto_add_system_use_external_glob() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Score>().0, 1);
+}
@@ -7,11 +7,21 @@ use internal_test_proc_macro::xtest;
 #[auto_plugin(impl_plugin_trait)]
 struct TestPlugin;
 
-#[auto_init_resource(plugin = TestPlugin)]
 #[auto_insert_resource(plugin = TestPlugin, insert(Test(1)))]
 #[derive(Resource, Debug, Default, PartialEq)]
 struct Test(usize);
 
+// deprecated, but still accepted
+#[allow(deprecated)]
+mod stacked {
+    use super::*;
+
+    #[auto_init_resource(plugin = crate::e2e::actions::auto_insert_resource::TestPlugin)]
+    #[auto_insert_resource(plugin = TestPlugin, insert(Stacked(1)))]
+    #[derive(Resource, Debug, Default, PartialEq)]
+    pub struct Stacked(pub usize);
+}
+
 fn app() -> App {
     let mut app = internal_test_util::create_minimal_app();
     app.add_plugins(TestPlugin);
@@ -23,3 +33,13 @@ fn test_auto_insert_resource() {
     let app = app();
     assert_eq!(app.world().get_resource::<Test>(), Some(&Test(1)), "did not auto insert resource");
 }
+
+#[xtest]
+fn test_auto_insert_resource_stacked_with_init() {
+    let app = app();
+    assert_eq!(
+        app.world().get_resource::<stacked::Stacked>(),
+        Some(&stacked::Stacked(1)),
+        "did not auto insert resource"
+    );
+}
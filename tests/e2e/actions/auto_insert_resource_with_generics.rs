@@ -7,7 +7,6 @@ use internal_test_proc_macro::xtest;
 #[auto_plugin(impl_plugin_trait)]
 struct TestPlugin;
 
-#[auto_init_resource(plugin = TestPlugin, generics(usize, bool))]
 #[auto_insert_resource(plugin = TestPlugin, generics(usize, bool), insert(Test(1, true)))]
 #[derive(Resource, Debug, Default, PartialEq)]
 struct Test<T1, T2>(T1, T2);
@@ -0,0 +1,96 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+use std::marker::PhantomData;
+
+trait Stat: Send + Sync + 'static {
+    const REGEN: u32;
+}
+
+struct Health;
+impl Stat for Health {
+    const REGEN: u32 = 1;
+}
+
+struct Mana;
+impl Stat for Mana {
+    const REGEN: u32 = 2;
+}
+
+// no entries run for it
+struct Stamina;
+impl Stat for Stamina {
+    const REGEN: u32 = 3;
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct StatPlugin<T: Stat>(PhantomData<T>);
+
+impl<T: Stat> Default for StatPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+auto_generics_set!(STATS = [Health, Mana]);
+
+#[derive(Resource)]
+#[auto_init_resource(plugin = StatPlugin::<T>, generics(product(T = STATS)))]
+struct Pool<T: Stat>(u32, PhantomData<T>);
+
+impl<T: Stat> Default for Pool<T> {
+    fn default() -> Self {
+        Self(0, PhantomData)
+    }
+}
+
+#[auto_add_system(plugin = StatPlugin::<T>, generics(Health), generics(Mana), schedule = Update)]
+fn regen<T: Stat>(mut pool: ResMut<Pool<T>>) {
+    pool.0 += T::REGEN;
+}
+
+#[derive(Resource, Default)]
+struct Ticks(u32);
+
+// rewrites hand the generic plugin on to the actions they expand to
+#[auto_system(plugin = StatPlugin::<T>, generics(product(T = STATS)), schedule = Update)]
+fn tick<T: Stat>(mut ticks: ResMut<Ticks>) {
+    ticks.0 += 1;
+}
+
+fn app<M>(plugins: impl bevy_app::Plugins<M>) -> App {
+    let mut app = create_minimal_app();
+    app.init_resource::<Ticks>();
+    app.add_plugins(plugins);
+    app
+}
+
+#[xtest]
+fn test_family_entry_runs_for_the_built_instance() {
+    let mut app = app(StatPlugin::<Health>::default());
+    assert!(app.world().contains_resource::<Pool<Health>>());
+    assert!(!app.world().contains_resource::<Pool<Mana>>());
+    app.update();
+    assert_eq!(app.world().resource::<Pool<Health>>().0, 1);
+    assert_eq!(app.world().resource::<Ticks>().0, 1);
+}
+
+#[xtest]
+fn test_family_entry_runs_for_every_built_instance() {
+    let mut app = app((StatPlugin::<Health>::default(), StatPlugin::<Mana>::default()));
+    app.update();
+    app.update();
+    assert_eq!(app.world().resource::<Pool<Health>>().0, 2);
+    assert_eq!(app.world().resource::<Pool<Mana>>().0, 4);
+    assert_eq!(app.world().resource::<Ticks>().0, 4);
+}
+
+#[xtest]
+fn test_family_entries_skip_other_instances() {
+    let mut app = app(StatPlugin::<Stamina>::default());
+    app.update();
+    assert!(!app.world().contains_resource::<Pool<Stamina>>());
+    assert_eq!(app.world().resource::<Ticks>().0, 0);
+}
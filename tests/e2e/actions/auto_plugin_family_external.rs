@@ -0,0 +1,40 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_external_plugins::generic::{
+    Armor,
+    Boots,
+    ExternalGearPlugin,
+    Gear,
+    Worn,
+};
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+use std::marker::PhantomData;
+
+// instances of a plugin from another crate, including one that crate never names
+#[derive(Resource)]
+#[auto_init_resource(plugin = ExternalGearPlugin::<T>, generics(Armor), generics(Boots))]
+struct Equipped<T: Gear>(PhantomData<T>);
+
+impl<T: Gear> Default for Equipped<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[xtest]
+fn test_family_external_plugin() {
+    let mut app = create_minimal_app();
+    app.add_plugins(ExternalGearPlugin::<Armor>::default());
+    assert!(app.world().contains_resource::<Worn<Armor>>());
+    assert!(app.world().contains_resource::<Equipped<Armor>>());
+    assert!(!app.world().contains_resource::<Equipped<Boots>>());
+}
+
+#[xtest]
+fn test_family_external_plugin_downstream_instance() {
+    let mut app = create_minimal_app();
+    app.add_plugins(ExternalGearPlugin::<Boots>::default());
+    assert!(app.world().contains_resource::<Equipped<Boots>>());
+    assert!(!app.world().contains_resource::<Worn<Boots>>());
+}
@@ -30,7 +30,6 @@ where
 #[derive(Resource, Debug, Default, PartialEq, Reflect)]
 #[reflect(Resource)]
 #[auto_register_type(plugin = Test::<u8, bool>, generics(u8, bool))]
-#[auto_init_resource(plugin = Test::<u8, bool>, generics(u8, bool))]
 #[auto_insert_resource(plugin = Test::<u8, bool>, generics(u8, bool), insert(FooRes(1, true)))]
 struct FooRes<T1, T2>(T1, T2)
 where
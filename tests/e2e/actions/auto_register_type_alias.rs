@@ -0,0 +1,52 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::type_id_of;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[derive(Reflect, Default)]
+struct Health;
+
+#[derive(Resource, Message, Reflect, Default)]
+struct Stat<T: Send + Sync + 'static, V: Send + Sync + 'static>(T, V);
+
+#[auto_register_type(plugin = TestPlugin)]
+#[auto_init_resource(plugin = TestPlugin)]
+#[auto_add_message(plugin = TestPlugin)]
+type PlayerHealth = Stat<Health, f32>;
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_register_type_alias() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(
+        type_registry.contains(type_id_of::<Stat<Health, f32>>()),
+        "did not auto register Stat<Health, f32>"
+    );
+}
+
+#[xtest]
+fn test_auto_init_resource_alias() {
+    let app = app();
+    assert!(app.world().get_resource::<PlayerHealth>().is_some(), "did not auto init resource");
+}
+
+#[xtest]
+fn test_auto_add_message_alias() {
+    let mut app = app();
+    let mut messages = app.world_mut().resource_mut::<Messages<PlayerHealth>>();
+    messages.write(PlayerHealth::default());
+    assert_eq!(messages.drain().count(), 1, "did not auto add message");
+}
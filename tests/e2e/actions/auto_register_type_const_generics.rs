@@ -0,0 +1,60 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::type_id_of;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+const LARGE: usize = 32;
+
+#[derive(Reflect, Default)]
+#[auto_register_type(plugin = TestPlugin, generics(8))]
+#[auto_register_type(plugin = TestPlugin, generics(SLOTS = 16))]
+#[auto_register_type(plugin = TestPlugin, generics(LARGE))]
+struct Inventory<const SLOTS: usize>;
+
+#[derive(Reflect, Default)]
+#[auto_register_type(plugin = TestPlugin, generics(u8, 2))]
+#[auto_register_type(plugin = TestPlugin, generics(LAYER = { 1 + 2 }, T = bool))]
+struct Layered<T, const LAYER: u8>(T);
+
+#[derive(Resource, Default)]
+struct Ticks(Vec<u8>);
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update, generics(1))]
+#[auto_add_system(plugin = TestPlugin, schedule = Update, generics(LAYER = 2))]
+fn tick<const LAYER: u8>(mut ticks: ResMut<Ticks>) {
+    ticks.0.push(LAYER);
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.init_resource::<Ticks>();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_register_type_const_generics() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<Inventory<8>>()));
+    assert!(type_registry.contains(type_id_of::<Inventory<16>>()));
+    assert!(type_registry.contains(type_id_of::<Inventory<LARGE>>()));
+    assert!(type_registry.contains(type_id_of::<Layered<u8, 2>>()));
+    assert!(type_registry.contains(type_id_of::<Layered<bool, 3>>()));
+}
+
+#[xtest]
+fn test_auto_add_system_const_generics() {
+    let mut app = app();
+    app.update();
+    let mut ticks = app.world().resource::<Ticks>().0.clone();
+    ticks.sort();
+    assert_eq!(ticks, vec![1, 2]);
+}
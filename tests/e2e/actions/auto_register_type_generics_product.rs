@@ -0,0 +1,82 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_reflect::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::type_id_of;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[derive(Reflect, Default)]
+struct Foot;
+
+#[derive(Reflect, Default)]
+struct Horse;
+
+mod stats {
+    use bevy_auto_plugin::prelude::*;
+    use bevy_reflect::prelude::*;
+
+    #[derive(Reflect, Default)]
+    pub struct Hp;
+
+    #[derive(Reflect, Default)]
+    pub struct Mp;
+
+    auto_generics_set!(pub(crate) STATS = [Hp, Mp]);
+}
+
+use stats::{
+    Hp,
+    Mp,
+};
+
+auto_generics_set!(UNITS = [Foot, Horse]);
+
+#[derive(Reflect, Default)]
+#[auto_register_type(plugin = TestPlugin, generics(product(Unit = UNITS, Stat = stats::STATS)))]
+struct Modifier<Unit, Stat>(Unit, Stat);
+
+#[derive(Reflect, Default)]
+#[auto_register_type(plugin = TestPlugin, generics(product([u8, u16], [bool])))]
+struct Pair<A, B>(A, B);
+
+#[derive(Resource, Default)]
+struct Runs(usize);
+
+#[auto_system(plugin = TestPlugin, schedule = Update, generics(product(U = UNITS, S = [Hp])))]
+fn count_runs<U: Send + Sync + 'static, S: Send + Sync + 'static>(
+    mut runs: ResMut<Runs>,
+    _seen: Local<Option<(U, S)>>,
+) {
+    runs.0 += 1;
+}
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.init_resource::<Runs>();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_register_type_generics_product() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<Modifier<Foot, Hp>>()));
+    assert!(type_registry.contains(type_id_of::<Modifier<Foot, Mp>>()));
+    assert!(type_registry.contains(type_id_of::<Modifier<Horse, Hp>>()));
+    assert!(type_registry.contains(type_id_of::<Modifier<Horse, Mp>>()));
+    assert!(type_registry.contains(type_id_of::<Pair<u8, bool>>()));
+    assert!(type_registry.contains(type_id_of::<Pair<u16, bool>>()));
+}
+
+#[xtest]
+fn test_auto_system_generics_product() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Runs>().0, 2);
+}
@@ -0,0 +1,135 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::type_id_of;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_export(exclude(Excluded))]
+mod external {
+    use bevy_reflect::prelude::*;
+
+    #[derive(Reflect)]
+    pub struct ExternalA;
+
+    #[derive(Reflect)]
+    pub enum ExternalB {
+        B,
+    }
+
+    #[derive(Reflect)]
+    pub struct Excluded;
+
+    #[derive(Reflect)]
+    pub struct Generic<T>(T);
+
+    pub struct NotReflect;
+}
+
+#[auto_export]
+mod nested {
+    use bevy_reflect::prelude::*;
+
+    #[derive(Reflect)]
+    pub struct NestedA;
+}
+
+#[auto_export]
+mod other {
+    use bevy_reflect::prelude::*;
+
+    #[derive(Reflect)]
+    pub struct Underscore;
+}
+
+#[auto_export]
+mod systems {
+    use super::Counter;
+    use bevy_ecs::prelude::*;
+
+    pub fn increment(mut counter: ResMut<Counter>) {
+        counter.0 += 1;
+    }
+
+    pub fn increment_again(mut counter: ResMut<Counter>) {
+        counter.0 += 10;
+    }
+}
+
+#[derive(Resource, Debug, Default, PartialEq)]
+struct Counter(i32);
+
+#[auto_register_type(plugin = TestPlugin)]
+use external::*;
+
+#[auto_register_type(plugin = TestPlugin)]
+#[allow(unused_imports)]
+use self::{
+    nested::{
+        self as nested_alias,
+    },
+    other::Underscore as _,
+};
+
+#[auto_add_system(plugin = TestPlugin, schedule = Update)]
+#[allow(unused_imports)]
+use systems::*;
+
+fn app() -> App {
+    let mut app = internal_test_util::create_minimal_app();
+    app.init_resource::<Counter>();
+    app.add_plugins(TestPlugin);
+    app
+}
+
+#[xtest]
+fn test_auto_register_type_use_glob() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(
+        type_registry.contains(type_id_of::<external::ExternalA>()),
+        "did not auto register ExternalA"
+    );
+    assert!(
+        type_registry.contains(type_id_of::<external::ExternalB>()),
+        "did not auto register ExternalB"
+    );
+    assert!(
+        !type_registry.contains(type_id_of::<external::Excluded>()),
+        "registered excluded type"
+    );
+    assert!(
+        !type_registry.contains(type_id_of::<external::Generic<bool>>()),
+        "registered generic type"
+    );
+    assert!(
+        !type_registry.contains(type_id_of::<external::NotReflect>()),
+        "registered a type without Reflect"
+    );
+}
+
+#[xtest]
+fn test_auto_register_type_use_self_and_underscore() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(
+        type_registry.contains(type_id_of::<nested_alias::NestedA>()),
+        "did not auto register nested_alias::NestedA"
+    );
+    assert!(
+        type_registry.contains(type_id_of::<other::Underscore>()),
+        "did not auto register other::Underscore"
+    );
+}
+
+#[xtest]
+fn test_auto_add_system_use_glob() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<Counter>(), &Counter(11));
+}
@@ -11,6 +11,7 @@ mod auto_add_systems_complex_with_generics;
 mod auto_add_systems_pipe_in;
 mod auto_add_systems_with_generics;
 mod auto_add_systems_with_set;
+mod auto_assoc_fns;
 mod auto_bind_plugin;
 mod auto_bind_plugin_after_build;
 mod auto_configure_system_set;
@@ -18,6 +19,7 @@ mod auto_configure_system_set_schedule_config;
 mod auto_configure_system_set_schedule_config_multiple_groups;
 #[cfg(feature = "default_plugin")]
 mod auto_default_plugin;
+mod auto_export_external;
 mod auto_init_resource;
 mod auto_init_resource_generic;
 mod auto_init_state;
@@ -29,15 +31,21 @@ mod auto_name_with_generics;
 mod auto_plugin_build_hook;
 mod auto_plugin_default_param;
 mod auto_plugin_default_param_method;
+mod auto_plugin_family;
+mod auto_plugin_family_external;
 mod auto_plugin_param;
 mod auto_plugin_with_generics;
 mod auto_register_state_type;
 mod auto_register_type;
+mod auto_register_type_alias;
+mod auto_register_type_const_generics;
 mod auto_register_type_generic;
 #[cfg(feature = "compat_generics_angles")]
 mod auto_register_type_generic_angles;
+mod auto_register_type_generics_product;
 mod auto_register_type_named_generics;
 mod auto_register_type_use;
+mod auto_register_type_use_glob;
 mod auto_run_on_build;
 mod auto_run_on_build_ordered;
 mod auto_run_on_build_with_generics;
@@ -10,7 +10,6 @@ use internal_test_proc_macro::xtest;
 pub struct MyPlugin;
 
 #[derive(Resource, Default, PartialEq, Debug)]
-#[auto_init_resource(plugin = MyPlugin)]
 #[auto_insert_resource(plugin = MyPlugin, insert(MyResourceAuto(1)))]
 pub struct MyResourceAuto(usize);
 
@@ -1,5 +1,8 @@
 mod bare_fn;
 mod bare_fn_default_app_param;
+mod plugin_defaults;
+mod plugin_defaults_external;
+mod requires;
 mod self_impl_plugin;
 mod self_impl_plugin_default_app_param;
 mod self_impl_plugin_with_generics;
@@ -0,0 +1,133 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_state::app::StatesPlugin;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct DefaultSet;
+
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+struct OtherSet;
+
+#[derive(States, Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+enum TestState {
+    #[default]
+    Init,
+    Run,
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(
+    impl_plugin_trait,
+    defaults(
+        system(schedule = Update, in_set = DefaultSet, run_if = in_state(TestState::Run)),
+        component(reflect(Default)),
+        after_build,
+    )
+)]
+struct Test;
+
+type TestAlias = Test;
+
+#[derive(Resource, Default)]
+struct SystemCounter(Vec<&'static str>);
+
+#[auto_system(plugin = Test)]
+fn default_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("default");
+}
+
+#[auto_system(plugin = Test, config(run_if = || true))]
+fn run_if_override_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("run_if_override");
+}
+
+#[auto_system(plugin = Test, config(in_set = OtherSet))]
+fn in_set_override_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("in_set_override");
+}
+
+#[auto_component(plugin = Test, derive(Debug, Default), reflect, register)]
+struct DefaultReflectComponent;
+
+#[auto_component(plugin = Test, derive(Debug, Default), reflect(Debug), register)]
+struct ExplicitReflectComponent;
+
+#[auto_component(plugin = TestAlias, derive(Debug, Default), reflect, register)]
+struct AliasReflectComponent;
+
+#[auto_run_on_build(plugin = Test)]
+fn default_after_build(app: &mut App) {
+    app.world_mut().resource_mut::<SystemCounter>().0.push("after_build");
+}
+
+#[auto_run_on_build(plugin = Test, after_build = false)]
+fn before_build(app: &mut App) {
+    app.world_mut().resource_mut::<SystemCounter>().0.push("before_build");
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.init_state::<TestState>();
+    app.init_resource::<SystemCounter>();
+    app.add_plugins(Test);
+    app
+}
+
+fn take_counter(app: &mut App) -> Vec<&'static str> {
+    let mut counter = std::mem::take(&mut app.world_mut().resource_mut::<SystemCounter>().0);
+    counter.sort();
+    counter
+}
+
+#[xtest]
+fn test_plugin_defaults_after_build() {
+    let mut app = app();
+    assert_eq!(
+        std::mem::take(&mut app.world_mut().resource_mut::<SystemCounter>().0),
+        vec!["before_build", "after_build"]
+    );
+}
+
+#[xtest]
+fn test_plugin_defaults_system_run_if() {
+    let mut app = app();
+    take_counter(&mut app);
+    app.update();
+    assert_eq!(take_counter(&mut app), vec!["run_if_override"]);
+    app.world_mut().resource_mut::<NextState<TestState>>().set(TestState::Run);
+    app.update();
+    assert_eq!(take_counter(&mut app), vec!["default", "in_set_override", "run_if_override"]);
+}
+
+#[xtest]
+fn test_plugin_defaults_system_in_set() {
+    let mut app = app();
+    app.configure_sets(Update, DefaultSet.run_if(|| false));
+    take_counter(&mut app);
+    app.world_mut().resource_mut::<NextState<TestState>>().set(TestState::Run);
+    app.update();
+    app.update();
+    assert_eq!(take_counter(&mut app), vec!["in_set_override", "in_set_override"]);
+}
+
+#[xtest]
+fn test_plugin_defaults_component_reflect() {
+    let app = app();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let default_reflect = registry
+        .get(std::any::TypeId::of::<DefaultReflectComponent>())
+        .expect("DefaultReflectComponent not registered");
+    assert!(default_reflect.data::<ReflectComponent>().is_some());
+    assert!(default_reflect.data::<ReflectDefault>().is_some());
+    let explicit_reflect = registry
+        .get(std::any::TypeId::of::<ExplicitReflectComponent>())
+        .expect("ExplicitReflectComponent not registered");
+    assert!(explicit_reflect.data::<ReflectDefault>().is_none());
+    let alias_reflect = registry
+        .get(std::any::TypeId::of::<AliasReflectComponent>())
+        .expect("AliasReflectComponent not registered");
+    assert!(alias_reflect.data::<ReflectDefault>().is_some());
+}
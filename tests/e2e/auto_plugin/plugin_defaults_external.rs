@@ -0,0 +1,47 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_external_plugins::{
+    ExternalPlugin,
+    defaults::ExternalDefaultsPlugin,
+};
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[auto_component(plugin = internal_test_external_plugins::ExternalPlugin, derive(Default), reflect, register)]
+struct ExternalReflectComponent;
+
+#[auto_component(
+    plugin = internal_test_external_plugins::defaults::ExternalDefaultsPlugin,
+    derive(Debug, Default),
+    reflect,
+    register
+)]
+struct ExternalDefaultReflectComponent;
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins((ExternalPlugin, ExternalDefaultsPlugin));
+    app
+}
+
+#[xtest]
+fn test_plugin_defaults_external_without_defaults() {
+    let app = app();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let reflect = registry
+        .get(std::any::TypeId::of::<ExternalReflectComponent>())
+        .expect("ExternalReflectComponent not registered");
+    assert!(reflect.data::<ReflectComponent>().is_some());
+    assert!(reflect.data::<ReflectDefault>().is_none());
+}
+
+#[xtest]
+fn test_plugin_defaults_external_component_reflect() {
+    let app = app();
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    let reflect = registry
+        .get(std::any::TypeId::of::<ExternalDefaultReflectComponent>())
+        .expect("ExternalDefaultReflectComponent not registered");
+    assert!(reflect.data::<ReflectComponent>().is_some());
+    assert!(reflect.data::<ReflectDefault>().is_some());
+}
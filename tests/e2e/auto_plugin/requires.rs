@@ -0,0 +1,121 @@
+use bevy_app::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_ecs::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[derive(Resource, Default)]
+struct Builds(Vec<&'static str>);
+
+fn record(app: &mut App, name: &'static str) {
+    app.world_mut().get_resource_or_init::<Builds>().0.push(name);
+}
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+struct PhysicsPlugin;
+
+#[auto_run_on_build(plugin = PhysicsPlugin)]
+fn physics_built(app: &mut App) {
+    record(app, "physics");
+}
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+struct InputPlugin;
+
+#[auto_run_on_build(plugin = InputPlugin)]
+fn input_built(app: &mut App) {
+    record(app, "input");
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, requires(PhysicsPlugin, InputPlugin))]
+struct GamePlugin;
+
+#[auto_run_on_build(plugin = GamePlugin)]
+fn game_built(app: &mut App) {
+    record(app, "game");
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, requires(PhysicsPlugin, mode = assert))]
+struct VehiclePlugin;
+
+#[auto_run_on_build(plugin = VehiclePlugin)]
+fn vehicle_built(app: &mut App) {
+    record(app, "vehicle");
+}
+
+#[xtest]
+fn test_requires_adds_missing_plugins_first() {
+    let mut app = create_minimal_app();
+    app.add_plugins(GamePlugin);
+    assert!(app.is_plugin_added::<PhysicsPlugin>());
+    assert!(app.is_plugin_added::<InputPlugin>());
+    assert_eq!(app.world().resource::<Builds>().0, vec!["physics", "input", "game"]);
+}
+
+#[xtest]
+fn test_requires_keeps_added_plugins() {
+    let mut app = create_minimal_app();
+    app.add_plugins((PhysicsPlugin, GamePlugin));
+    assert_eq!(app.world().resource::<Builds>().0, vec!["physics", "input", "game"]);
+}
+
+#[xtest]
+fn test_requires_assert_with_plugin_added() {
+    let mut app = create_minimal_app();
+    app.add_plugins((PhysicsPlugin, VehiclePlugin));
+    assert_eq!(app.world().resource::<Builds>().0, vec!["physics", "vehicle"]);
+}
+
+#[xtest]
+#[should_panic(expected = "requires.rs")]
+fn test_requires_assert_without_plugin() {
+    let mut app = create_minimal_app();
+    app.add_plugins(VehiclePlugin);
+}
+
+// `CycleA` adds `CycleB`, which requires `CycleC`, which adds `CycleA` again
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+#[auto_add_plugin(plugin = CycleC)]
+struct CycleA;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, requires(CycleC))]
+#[auto_add_plugin(plugin = CycleA)]
+struct CycleB;
+
+#[derive(AutoPlugin, Default)]
+#[auto_plugin(impl_plugin_trait)]
+struct CycleC;
+
+#[xtest]
+#[should_panic(expected = "plugin dependency cycle")]
+fn test_dependency_cycle() {
+    let mut app = create_minimal_app();
+    app.add_plugins(CycleA);
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct FirstPlugin;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct SecondPlugin;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+#[auto_add_plugin(plugin = FirstPlugin)]
+#[auto_add_plugin(plugin = SecondPlugin)]
+struct SharedPlugin;
+
+#[xtest]
+#[should_panic(expected = "it's also added by")]
+fn test_duplicate_add_plugin() {
+    let mut app = create_minimal_app();
+    app.add_plugins((FirstPlugin, SecondPlugin));
+}
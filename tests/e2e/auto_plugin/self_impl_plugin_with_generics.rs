@@ -14,7 +14,6 @@ where
     T2: Default + Send + Sync + 'static;
 
 #[derive(Resource, Default, PartialEq, Debug)]
-#[auto_init_resource(plugin = MyPlugin::<u8, bool>, generics(u8, bool))]
 #[auto_insert_resource(plugin = MyPlugin::<u8, bool>, generics(u8, bool), insert(MyResourceAuto(1, true)))]
 pub struct MyResourceAuto<T1, T2>(T1, T2)
 where
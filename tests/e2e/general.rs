@@ -36,7 +36,6 @@ struct FooDefaultRes(usize);
 #[derive(Resource, Debug, Default, PartialEq, Reflect)]
 #[reflect(Resource)]
 #[auto_register_type(plugin = Test)]
-#[auto_init_resource(plugin = Test)]
 #[auto_insert_resource(plugin = Test, insert(FooRes(1)))]
 struct FooRes(usize);
 
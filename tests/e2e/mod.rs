@@ -2,5 +2,7 @@ mod actions;
 mod auto_plugin;
 mod general;
 mod rewrites;
+#[cfg(feature = "testing")]
+mod testing;
 #[cfg(not(wasm))]
 mod ui_tests;
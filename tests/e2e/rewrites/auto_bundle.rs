@@ -0,0 +1,79 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+use std::any::TypeId;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Component, Reflect, Debug, Default, PartialEq)]
+#[reflect(Component)]
+struct Health(f32);
+
+#[derive(Component, Reflect, Debug, Default, PartialEq)]
+#[reflect(Component)]
+struct Player;
+
+#[derive(Component, Debug, Default)]
+struct NotReflected;
+
+#[derive(Component, Debug, Default)]
+struct Unreflected;
+
+#[derive(Component, Reflect, Debug, Default, PartialEq)]
+#[reflect(Component)]
+struct Mana(f32);
+
+#[derive(Bundle, Debug, Default)]
+struct StatsBundle {
+    mana: Mana,
+}
+
+#[auto_bundle(plugin = Test, derive(Default), register, spawn)]
+struct PlayerBundle {
+    health: Health,
+    player: Player,
+    unreflected: Unreflected,
+    stats: StatsBundle,
+    #[bundle(ignore)]
+    #[allow(dead_code)]
+    ignored: NotReflected,
+}
+
+#[auto_bundle(plugin = Test, derive, spawn = "spawn_enemy")]
+struct EnemyBundle(Health);
+
+#[auto_bundle(plugin = Test, derive(Default), spawn)]
+struct HTTPBundle {
+    health: Health,
+}
+
+#[xtest]
+fn test_auto_bundle_register() {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    let registry = app.world().resource::<AppTypeRegistry>().read();
+    assert!(registry.contains(TypeId::of::<Health>()));
+    assert!(registry.contains(TypeId::of::<Player>()));
+    assert!(!registry.contains(TypeId::of::<NotReflected>()));
+    assert!(!registry.contains(TypeId::of::<Unreflected>()));
+    assert!(!registry.contains(TypeId::of::<Mana>()), "nested bundles are skipped");
+}
+
+#[xtest]
+fn test_auto_bundle_spawn() {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    let player = app.world_mut().commands().spawn_player_bundle(PlayerBundle::default()).id();
+    let enemy = app.world_mut().commands().spawn_enemy(EnemyBundle(Health(2.0))).id();
+    let http = app.world_mut().commands().spawn_http_bundle(HTTPBundle::default()).id();
+    app.world_mut().flush();
+    assert_eq!(app.world().get::<Health>(player), Some(&Health::default()));
+    assert!(app.world().get::<Player>(player).is_some());
+    assert!(app.world().get::<NotReflected>(player).is_none());
+    assert_eq!(app.world().get::<Health>(enemy), Some(&Health(2.0)));
+    assert!(app.world().get::<Health>(http).is_some());
+    assert!(app.world().get::<Mana>(player).is_some());
+}
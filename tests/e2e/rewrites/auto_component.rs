@@ -19,6 +19,31 @@ struct FooComponent;
 #[auto_component(plugin = Test, generics(usize), derive, register, reflect, auto_name)]
 struct GenericComponent<T>(T);
 
+#[auto_component(plugin = Test, derive(Default), storage = "SparseSet")]
+struct SparseComponent;
+
+#[auto_component(plugin = Test, derive(Default), immutable)]
+struct ImmutableComponent;
+
+#[auto_component(plugin = Test, derive, require(SparseComponent, Counter = Counter(3)))]
+struct RequiresComponent;
+
+#[auto_component(plugin = Test, derive(Default, Debug, PartialEq))]
+struct Counter(usize);
+
+#[auto_component(plugin = Test, derive, on_add = count_hook)]
+struct HookedComponent;
+
+fn count_hook(mut world: bevy_ecs::world::DeferredWorld, ctx: bevy_ecs::lifecycle::HookContext) {
+    world.commands().entity(ctx.entity).insert(Counter(1));
+}
+
+#[auto_component(plugin = Test, derive, relationship(relationship_target = Minions))]
+struct MinionOf(Entity);
+
+#[auto_component(plugin = Test, derive, relationship_target(relationship = MinionOf, linked_spawn))]
+struct Minions(Vec<Entity>);
+
 fn app() -> App {
     let mut app = create_minimal_app();
     app.add_plugins(StatesPlugin);
@@ -68,3 +93,47 @@ fn test_auto_name_generic_component() {
         .expect("failed to query GenericComponent");
     assert_eq!(name, &Name::new("GenericComponent<usize>"), "did not auto name GenericComponent");
 }
+
+#[xtest]
+fn test_auto_component_storage() {
+    let mut app = app();
+    let id = app.world_mut().register_component::<SparseComponent>();
+    let info = app.world().components().get_info(id).expect("missing component info");
+    assert_eq!(info.storage_type(), bevy_ecs::component::StorageType::SparseSet);
+}
+
+#[xtest]
+fn test_auto_component_immutable() {
+    let mut app = app();
+    let id = app.world_mut().register_component::<ImmutableComponent>();
+    let info = app.world().components().get_info(id).expect("missing component info");
+    assert!(!info.mutable(), "expected ImmutableComponent to be immutable");
+}
+
+#[xtest]
+fn test_auto_component_require() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(RequiresComponent).id();
+    let entity = app.world().entity(entity);
+    assert!(entity.contains::<SparseComponent>(), "missing required SparseComponent");
+    assert_eq!(entity.get::<Counter>(), Some(&Counter(3)));
+}
+
+#[xtest]
+fn test_auto_component_hook() {
+    let mut app = app();
+    let entity = app.world_mut().spawn(HookedComponent).id();
+    app.world_mut().flush();
+    assert_eq!(app.world().entity(entity).get::<Counter>(), Some(&Counter(1)));
+}
+
+#[xtest]
+fn test_auto_component_relationship() {
+    let mut app = app();
+    let boss = app.world_mut().spawn_empty().id();
+    let minion = app.world_mut().spawn(MinionOf(boss)).id();
+    let minions = app.world().entity(boss).get::<Minions>().expect("missing Minions");
+    assert_eq!(minions.0, vec![minion]);
+    app.world_mut().despawn(boss);
+    assert!(app.world().get_entity(minion).is_err(), "expected linked_spawn to despawn minion");
+}
@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::{
+    create_minimal_app,
+    type_id_of,
+};
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Resource, Default)]
+struct Clicks(Vec<Entity>);
+
+#[auto_event(
+    plugin = Test,
+    target(entity),
+    derive(Debug, Clone),
+    reflect,
+    register,
+    auto_propagate,
+    traversal = &'static ChildOf,
+    observer = on_click
+)]
+struct Click(#[event_target] Entity);
+
+fn on_click(click: On<Click>, mut clicks: ResMut<Clicks>) {
+    clicks.0.push(click.event_target());
+}
+
+#[auto_event(plugin = Test, target(entity), derive, propagate)]
+struct Hover(#[event_target] Entity);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.init_resource::<Clicks>();
+    app.add_plugins(Test);
+    app
+}
+
+#[xtest]
+fn test_auto_event_auto_propagate_with_observer() {
+    let mut app = app();
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    app.world_mut().trigger(Click(child));
+    assert_eq!(app.world().resource::<Clicks>().0, vec![child, parent]);
+}
+
+#[xtest]
+fn test_auto_event_propagate_is_opt_in() {
+    let mut app = app();
+    let parent = app.world_mut().spawn_empty().id();
+    let child = app.world_mut().spawn(ChildOf(parent)).id();
+    let hovered = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let seen = hovered.clone();
+    app.world_mut().add_observer(move |hover: On<Hover>| {
+        seen.lock().unwrap().push(hover.event_target());
+    });
+    app.world_mut().trigger(Hover(child));
+    assert_eq!(*hovered.lock().unwrap(), vec![child]);
+}
+
+#[xtest]
+fn test_auto_event_reflect_event() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    let registration = type_registry.get(type_id_of::<Click>()).expect("Click not registered");
+    assert!(registration.data::<ReflectEvent>().is_some(), "missing ReflectEvent");
+}
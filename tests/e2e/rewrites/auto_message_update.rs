@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_message(plugin = Test, derive, update_in = FixedPostUpdate)]
+struct FixedMessage;
+
+#[auto_message(plugin = Test, derive, manual_update)]
+struct ManualMessage;
+
+#[auto_add_message(plugin = Test, generics(bool), update_in = FixedPostUpdate)]
+#[derive(Message)]
+struct GenericMessage<T>(T);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    app
+}
+
+fn len<M: Message>(app: &App) -> usize {
+    app.world().resource::<Messages<M>>().len()
+}
+
+fn read<M: Message>(app: &mut App) -> usize {
+    app.world_mut().run_system_cached(|mut reader: MessageReader<M>| reader.read().count()).unwrap()
+}
+
+#[xtest]
+fn test_auto_message_update_in() {
+    let mut app = app();
+    app.world_mut().write_message(FixedMessage);
+    app.world_mut().write_message(GenericMessage(true));
+    app.update();
+    app.update();
+    assert_eq!(len::<FixedMessage>(&app), 1, "updated outside of FixedPostUpdate");
+    assert_eq!(len::<GenericMessage<bool>>(&app), 1, "updated outside of FixedPostUpdate");
+    app.world_mut().run_schedule(FixedPostUpdate);
+    app.world_mut().run_schedule(FixedPostUpdate);
+    assert_eq!(len::<FixedMessage>(&app), 0);
+    assert_eq!(len::<GenericMessage<bool>>(&app), 0);
+}
+
+#[xtest]
+fn test_auto_message_manual_update() {
+    let mut app = app();
+    app.world_mut().write_message(ManualMessage);
+    app.update();
+    app.update();
+    assert_eq!(len::<ManualMessage>(&app), 1, "expected the message to be kept until updated");
+    assert_eq!(read::<ManualMessage>(&mut app), 1);
+    app.world_mut().run_system_cached(update_manual_messages).unwrap();
+    app.world_mut().run_system_cached(update_manual_messages).unwrap();
+    assert_eq!(len::<ManualMessage>(&app), 0);
+}
+
+#[xtest]
+fn test_auto_message_update_in_clears_read_messages() {
+    let mut app = app();
+    app.world_mut().write_message(FixedMessage);
+    app.world_mut().run_schedule(FixedPostUpdate);
+    assert_eq!(read::<FixedMessage>(&mut app), 1);
+    app.world_mut().run_schedule(FixedPostUpdate);
+    assert_eq!(len::<FixedMessage>(&app), 0);
+    // nothing new is written, so the update stops clearing until there are more
+    app.world_mut().run_schedule(FixedPostUpdate);
+    app.world_mut().write_message(FixedMessage);
+    app.world_mut().run_schedule(FixedPostUpdate);
+    app.world_mut().run_schedule(FixedPostUpdate);
+    assert_eq!(len::<FixedMessage>(&app), 0);
+}
+
+#[xtest]
+fn test_auto_message_update_in_is_registered() {
+    let mut app = app();
+    app.add_message::<FixedMessage>();
+    app.world_mut().write_message(FixedMessage);
+    app.update();
+    app.update();
+    assert_eq!(len::<FixedMessage>(&app), 1, "`add_message` registered it a second time");
+}
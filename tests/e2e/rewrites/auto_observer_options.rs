@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(Component)]
+struct Player;
+
+#[derive(EntityEvent)]
+struct Hit {
+    entity: Entity,
+}
+
+#[derive(Resource, Default)]
+struct PlayerHits(Vec<Entity>);
+
+#[derive(Resource, Default)]
+struct GlobalHits(usize);
+
+#[derive(Resource)]
+struct HitsEnabled(bool);
+
+#[auto_observer(plugin = Test, on_entity_with = Player, name = "player_hits")]
+fn on_player_hit(hit: On<Hit>, mut hits: ResMut<PlayerHits>) {
+    hits.0.push(hit.entity);
+}
+
+#[auto_observer(plugin = Test, name = "global_hits", run_if = |enabled: Res<HitsEnabled>| enabled.0)]
+fn on_any_hit(_hit: On<Hit>, mut hits: ResMut<GlobalHits>) {
+    hits.0 += 1;
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.init_resource::<PlayerHits>();
+    app.init_resource::<GlobalHits>();
+    app.insert_resource(HitsEnabled(true));
+    app.add_plugins(Test);
+    app
+}
+
+#[xtest]
+fn test_auto_observer_on_entity_with() {
+    let mut app = app();
+    let player = app.world_mut().spawn(Player).id();
+    let other = app.world_mut().spawn_empty().id();
+    app.world_mut().flush();
+    app.world_mut().trigger(Hit { entity: player });
+    app.world_mut().trigger(Hit { entity: other });
+    assert_eq!(app.world().resource::<PlayerHits>().0, vec![player]);
+}
+
+#[xtest]
+fn test_auto_observer_name() {
+    let mut app = app();
+    app.world_mut().spawn(Player);
+    app.world_mut().flush();
+    let mut names = app
+        .world_mut()
+        .query_filtered::<&Name, With<Observer>>()
+        .iter(app.world())
+        .map(|name| name.as_str().to_string())
+        .collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, vec!["global_hits", "player_hits"]);
+}
+
+#[xtest]
+fn test_auto_observer_run_if() {
+    let mut app = app();
+    let entity = app.world_mut().spawn_empty().id();
+    app.world_mut().trigger(Hit { entity });
+    assert_eq!(app.world().resource::<GlobalHits>().0, 1);
+    app.world_mut().resource_mut::<HitsEnabled>().0 = false;
+    app.world_mut().trigger(Hit { entity });
+    assert_eq!(app.world().resource::<GlobalHits>().0, 1);
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code)]
+
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::{
+    create_minimal_app,
+    type_id_of,
+};
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_relationship(
+    plugin = Test,
+    target = Minions,
+    linked_spawn,
+    generate_target,
+    derive,
+    reflect,
+    register
+)]
+struct MinionOf(Entity);
+
+#[auto_relationship(plugin = Test, target = Likes, derive, reflect, register)]
+struct LikedBy(Entity);
+
+#[auto_component(plugin = Test, derive, reflect, relationship_target(relationship = LikedBy))]
+struct Likes(Vec<Entity>);
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    app
+}
+
+#[xtest]
+fn test_auto_relationship_registers_both_sides() {
+    let app = app();
+    let type_registry = app.world().resource::<AppTypeRegistry>().0.clone();
+    let type_registry = type_registry.read();
+    assert!(type_registry.contains(type_id_of::<MinionOf>()), "did not register MinionOf");
+    assert!(type_registry.contains(type_id_of::<Minions>()), "did not register Minions");
+    assert!(type_registry.contains(type_id_of::<LikedBy>()), "did not register LikedBy");
+    assert!(type_registry.contains(type_id_of::<Likes>()), "did not register Likes");
+}
+
+#[xtest]
+fn test_auto_relationship_generated_target() {
+    let mut app = app();
+    let boss = app.world_mut().spawn_empty().id();
+    let minion = app.world_mut().spawn(MinionOf(boss)).id();
+    let minions = app.world().entity(boss).get::<Minions>().expect("missing Minions");
+    assert_eq!(minions.0, vec![minion]);
+    app.world_mut().despawn(boss);
+    assert!(app.world().get_entity(minion).is_err(), "expected linked_spawn to despawn minion");
+}
+
+#[xtest]
+fn test_auto_relationship_existing_target() {
+    let mut app = app();
+    let fan = app.world_mut().spawn_empty().id();
+    let liked = app.world_mut().spawn(LikedBy(fan)).id();
+    let likes = app.world().entity(fan).get::<Likes>().expect("missing Likes");
+    assert_eq!(likes.0, vec![liked]);
+    app.world_mut().despawn(fan);
+    assert!(app.world().get_entity(liked).is_ok(), "expected liked entity to survive");
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+use serde::Deserialize;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_resource(plugin = Test, derive, load = "tests/e2e/fixtures/net.json")]
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+struct JsonNet {
+    host: String,
+    port: u16,
+}
+
+#[cfg(feature = "resource_config_toml")]
+#[auto_resource(plugin = Test, derive, load = "tests/e2e/fixtures/net.toml")]
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+struct TomlNet {
+    host: String,
+    port: u16,
+}
+
+#[cfg(feature = "resource_config_ron")]
+#[auto_resource(plugin = Test, derive, load = "tests/e2e/fixtures/net.ron")]
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+struct RonNet {
+    host: String,
+    port: u16,
+}
+
+#[auto_resource(plugin = Test, derive, load = "tests/e2e/fixtures/missing.json")]
+#[derive(Deserialize, Debug, PartialEq)]
+#[serde(default)]
+struct MissingNet {
+    port: u16,
+}
+
+impl Default for MissingNet {
+    fn default() -> Self {
+        Self { port: 1234 }
+    }
+}
+
+#[auto_resource(plugin = Test, derive, load = "tests/e2e/fixtures/net.json", env_prefix = "AUTO_RESOURCE_CONFIG_TEST_")]
+#[derive(Deserialize, Debug, Default, PartialEq)]
+#[serde(default)]
+struct EnvNet {
+    host: String,
+    port: u16,
+    name: String,
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(Test);
+    app
+}
+
+#[xtest]
+fn test_auto_resource_load_json() {
+    let app = app();
+    assert_eq!(
+        app.world().resource::<JsonNet>(),
+        &JsonNet { host: "example.com".to_string(), port: 7777 }
+    );
+}
+
+#[cfg(feature = "resource_config_toml")]
+#[xtest]
+fn test_auto_resource_load_toml() {
+    let app = app();
+    assert_eq!(
+        app.world().resource::<TomlNet>(),
+        &TomlNet { host: "example.org".to_string(), port: 8888 }
+    );
+}
+
+#[cfg(feature = "resource_config_ron")]
+#[xtest]
+fn test_auto_resource_load_ron() {
+    let app = app();
+    assert_eq!(
+        app.world().resource::<RonNet>(),
+        &RonNet { host: "example.net".to_string(), port: 9999 }
+    );
+}
+
+#[xtest]
+fn test_auto_resource_load_missing_file() {
+    let app = app();
+    assert_eq!(app.world().resource::<MissingNet>(), &MissingNet::default());
+}
+
+#[xtest]
+fn test_auto_resource_env_prefix() {
+    // SAFETY: the variable is only read by this test
+    unsafe {
+        std::env::set_var("AUTO_RESOURCE_CONFIG_TEST_PORT", "4242");
+        std::env::set_var("AUTO_RESOURCE_CONFIG_TEST_NAME", "1234");
+    }
+    let app = app();
+    assert_eq!(
+        app.world().resource::<EnvNet>(),
+        &EnvNet { host: "example.com".to_string(), port: 4242, name: "1234".to_string() }
+    );
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_state::app::StatesPlugin;
+use internal_test_proc_macro::xtest;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_states(
+    plugin = Test,
+    derive,
+    initial = GameState::Loading,
+    scoped_entities,
+    log_transitions,
+    allow_same_state_transitions = false
+)]
+enum GameState {
+    #[default]
+    Menu,
+    Loading,
+    Playing,
+}
+
+#[auto_sub_states(plugin = Test, derive, scoped_entities, log_transitions)]
+#[source(GameState = GameState::Playing)]
+enum PlayingState {
+    #[default]
+    Running,
+    Paused,
+}
+
+#[derive(Resource, Default)]
+struct LoadingEntered(usize);
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins(StatesPlugin);
+    app.add_plugins(Test);
+    app.init_resource::<LoadingEntered>();
+    app.add_systems(OnEnter(GameState::Loading), |mut entered: ResMut<LoadingEntered>| {
+        entered.0 += 1;
+    });
+    app
+}
+
+#[xtest]
+fn test_auto_states_initial() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<State<GameState>>().get(), &GameState::Loading);
+}
+
+#[xtest]
+fn test_auto_states_skips_same_state_transitions() {
+    let mut app = app();
+    app.update();
+    assert_eq!(app.world().resource::<LoadingEntered>().0, 1);
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Loading);
+    app.update();
+    assert_eq!(app.world().resource::<LoadingEntered>().0, 1, "re-entered the same state");
+}
+
+#[xtest]
+fn test_auto_states_scoped_entities() {
+    let mut app = app();
+    app.update();
+    let entity = app.world_mut().spawn(DespawnOnExit(GameState::Loading)).id();
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+    app.update();
+    assert!(app.world().get_entity(entity).is_err(), "entity was not despawned on exit");
+}
+
+#[xtest]
+fn test_auto_sub_states_options_imply_init() {
+    let mut app = app();
+    app.world_mut().resource_mut::<NextState<GameState>>().set(GameState::Playing);
+    app.update();
+    assert_eq!(
+        app.world().get_resource::<State<PlayingState>>().map(State::get),
+        Some(&PlayingState::Running)
+    );
+    let entity = app.world_mut().spawn(DespawnOnExit(PlayingState::Running)).id();
+    app.world_mut().resource_mut::<NextState<PlayingState>>().set(PlayingState::Paused);
+    app.update();
+    assert!(app.world().get_entity(entity).is_err(), "entity was not despawned on exit");
+}
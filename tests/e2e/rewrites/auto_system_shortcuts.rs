@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+use bevy_state::app::StatesPlugin;
+use internal_test_proc_macro::xtest;
+use internal_test_util::create_minimal_app;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait, default_schedule = PostUpdate)]
+struct TestDefault;
+
+#[derive(Resource, Default)]
+struct SystemCounter(Vec<&'static str>);
+
+#[derive(States, Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+enum TestState {
+    #[default]
+    Init,
+    Run,
+}
+
+#[auto_system(plugin = Test, startup)]
+fn startup_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("startup");
+}
+
+#[auto_system(plugin = Test, update)]
+fn update_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("update");
+}
+
+#[auto_system(plugin = Test, post_update)]
+fn post_update_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("post_update");
+}
+
+#[auto_system(plugin = Test, fixed)]
+fn fixed_system() {}
+
+#[auto_system(plugin = Test, update, in_state(TestState::Run))]
+fn in_state_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("in_state");
+}
+
+#[auto_system(plugin = TestDefault)]
+fn default_schedule_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("default_schedule");
+}
+
+#[auto_system(plugin = TestDefault, update)]
+fn default_schedule_override_system(mut counter: ResMut<SystemCounter>) {
+    counter.0.push("default_schedule_override");
+}
+
+fn app() -> App {
+    let mut app = create_minimal_app();
+    app.add_plugins(StatesPlugin);
+    app.init_state::<TestState>();
+    app.init_resource::<SystemCounter>();
+    app.add_plugins((Test, TestDefault));
+    app
+}
+
+#[xtest]
+fn test_auto_system_shortcuts() {
+    let mut app = app();
+    app.update();
+    let mut counter = app.world_mut().resource_mut::<SystemCounter>();
+    counter.0.sort();
+    assert_eq!(
+        counter.0,
+        vec![
+            "default_schedule",
+            "default_schedule_override",
+            "post_update",
+            "startup",
+            "update"
+        ]
+    );
+}
+
+#[xtest]
+fn test_auto_system_fixed() {
+    let app = app();
+    let schedules = app.world().resource::<Schedules>();
+    let fixed = schedules.get(FixedUpdate).expect("FixedUpdate schedule");
+    assert_eq!(fixed.systems_len(), 1);
+}
+
+#[xtest]
+fn test_auto_system_in_state() {
+    let mut app = app();
+    app.update();
+    assert!(!app.world().resource::<SystemCounter>().0.contains(&"in_state"));
+    app.world_mut().resource_mut::<NextState<TestState>>().set(TestState::Run);
+    app.update();
+    app.update();
+    assert!(app.world().resource::<SystemCounter>().0.contains(&"in_state"));
+}
@@ -1,8 +1,17 @@
+mod auto_bundle;
 mod auto_component;
 mod auto_event;
+mod auto_event_propagate;
 mod auto_message;
+mod auto_message_update;
 mod auto_observer;
+mod auto_observer_options;
+mod auto_relationship;
 mod auto_resource;
+#[cfg(feature = "resource_config")]
+mod auto_resource_config;
 mod auto_states;
+mod auto_states_options;
 mod auto_sub_states;
 mod auto_system;
+mod auto_system_shortcuts;
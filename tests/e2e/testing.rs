@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::{
+    prelude::*,
+    testing::*,
+};
+use internal_test_proc_macro::xtest;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Empty;
+
+#[auto_resource(plugin = Test, derive(Default), reflect, register, init)]
+struct FooResource;
+
+#[auto_states(plugin = Test, derive, init)]
+enum FooState {
+    #[default]
+    A,
+    B,
+}
+
+#[auto_event(plugin = Test, target(global), derive)]
+struct FooEvent;
+
+#[auto_observer(plugin = Test)]
+fn on_foo(_: On<FooEvent>) {}
+
+#[auto_system(plugin = Test, schedule = Update, in_state = FooState::A)]
+fn foo_system(mut next: ResMut<NextState<FooState>>) {
+    next.set(FooState::B);
+}
+
+fn not_added() {}
+
+#[xtest]
+fn test_assertions_pass() {
+    assert_has_registry_entries::<Test>();
+    let mut app = AutoPluginTestApp::new(Test);
+    app.assert_plugin_added::<Test>()
+        .assert_registered_type::<FooResource>()
+        .assert_resource_initialized::<FooResource>()
+        .assert_state_eq(FooState::A)
+        .assert_system_in_schedule(Update, foo_system)
+        .assert_observer_for::<FooEvent>();
+    app.update().update();
+    app.assert_state_eq(FooState::B).assert_system_in_schedule(Update, foo_system);
+}
+
+#[xtest]
+fn test_no_registry_entries() {
+    assert_eq!(registry_entry_count::<Empty>(), 0);
+}
+
+#[xtest]
+#[should_panic(expected = "is not registered")]
+fn test_assert_registered_type_fails() {
+    AutoPluginTestApp::new(Empty).assert_registered_type::<FooResource>();
+}
+
+#[xtest]
+#[should_panic(expected = "is not in schedule")]
+fn test_assert_system_in_schedule_fails() {
+    AutoPluginTestApp::new(Test).assert_system_in_schedule(Update, not_added);
+}
+
+#[xtest]
+#[should_panic(expected = "no observer for event")]
+fn test_assert_observer_for_fails() {
+    AutoPluginTestApp::new(Empty).assert_observer_for::<FooEvent>();
+}
+
+#[xtest]
+#[should_panic(expected = "is not initialized")]
+fn test_assert_state_fails() {
+    AutoPluginTestApp::new(Empty).assert_state::<FooState>();
+}
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct AddsStates;
+
+#[auto_run_on_build(plugin = AddsStates)]
+fn add_states_plugin(app: &mut App) {
+    app.add_plugins(bevy::state::app::StatesPlugin);
+}
+
+#[auto_states(plugin = AddsStates, derive, init)]
+enum BarState {
+    #[default]
+    A,
+}
+
+#[xtest]
+fn test_plugin_adding_states_plugin() {
+    AutoPluginTestApp::new(AddsStates)
+        .assert_plugin_added::<bevy::state::app::StatesPlugin>()
+        .assert_state_eq(BarState::A);
+}
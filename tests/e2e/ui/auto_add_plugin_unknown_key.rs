@@ -0,0 +1,11 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_add_plugin(plugin = Test, int)]
+#[derive(Default)]
+struct Inner;
+
+fn main() {}
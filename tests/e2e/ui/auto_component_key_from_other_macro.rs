@@ -0,0 +1,10 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_component(plugin = Test, derive, init)]
+struct Foo;
+
+fn main() {}
@@ -0,0 +1,14 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_component(plugin = TestPlugin, derive, relationship_target(relationship = ChildOfThing))]
+struct Things;
+
+#[auto_component(plugin = TestPlugin, derive, relationship(relationship_target = Things))]
+struct ChildOfThing;
+
+// dummy main
+fn main() {}
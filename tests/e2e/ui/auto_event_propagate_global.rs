@@ -0,0 +1,11 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_event(plugin = TestPlugin, target(global), derive, propagate)]
+struct GlobalEvent;
+
+// dummy main
+fn main() {}
@@ -0,0 +1,18 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_export]
+mod inner {
+    use bevy::prelude::*;
+
+    #[derive(Resource)]
+    pub struct Bar;
+}
+
+#[auto_insert_resource(plugin = Test, insert(inner::Bar))]
+use inner::*;
+
+fn main() {}
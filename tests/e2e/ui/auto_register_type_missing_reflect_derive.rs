@@ -0,0 +1,11 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_register_type(plugin = Test)]
+#[derive(Debug)]
+struct Foo;
+
+fn main() {}
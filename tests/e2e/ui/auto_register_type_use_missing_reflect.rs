@@ -0,0 +1,14 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+mod inner {
+    pub struct Bar;
+}
+
+#[auto_register_type(plugin = Test)]
+use inner::Bar;
+
+fn main() {}
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct TestPlugin;
+
+#[auto_relationship(plugin = TestPlugin, target = Minions, linked_spawn, derive)]
+struct MinionOf(Entity);
+
+#[auto_component(plugin = TestPlugin, derive, relationship_target(relationship = MinionOf))]
+struct Minions(Vec<Entity>);
+
+// dummy main
+fn main() {}
@@ -0,0 +1,15 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_resource(plugin = Test, derive, init, load = "config/net.json")]
+#[derive(Default)]
+struct Net;
+
+#[auto_resource(plugin = Test, derive, load = "config/net.yaml")]
+#[derive(Default)]
+struct OtherNet;
+
+fn main() {}
@@ -0,0 +1,11 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_component(plugin = Test, derive)]
+#[auto_resource(plugin = Test, derive)]
+struct Foo;
+
+fn main() {}
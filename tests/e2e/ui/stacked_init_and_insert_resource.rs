@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_init_resource(plugin = Test)]
+#[auto_insert_resource(plugin = Test, insert(Foo(1)))]
+#[derive(bevy_ecs::prelude::Resource, Default)]
+struct Foo(usize);
+
+fn main() {}
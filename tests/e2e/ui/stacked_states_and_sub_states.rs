@@ -0,0 +1,14 @@
+use bevy_auto_plugin::prelude::*;
+
+#[derive(AutoPlugin)]
+#[auto_plugin(impl_plugin_trait)]
+struct Test;
+
+#[auto_sub_states(plugin = Test)]
+#[auto_states(plugin = Test)]
+enum Foo {
+    A,
+    B,
+}
+
+fn main() {}
@@ -7,5 +7,17 @@ struct TestPlugin;
 #[auto_register_type(plugin = TestPlugin, generics(bool))]
 struct Foo<T1, T2>(T1, T2);
 
+#[auto_register_type(plugin = TestPlugin, generics(u8, 4, 8))]
+struct Inventory<T, const SLOTS: usize>([T; SLOTS]);
+
+#[auto_register_type(plugin = TestPlugin, generics(4))]
+struct Layered<T, const LAYER: u8>(T);
+
+#[auto_register_type(plugin = TestPlugin, generics(N = [u8; 4]))]
+struct Grid<const N: usize>;
+
+#[auto_register_type(plugin = TestPlugin, generics(8))]
+struct Wrapper<T>(T);
+
 // dummy main
 fn main() {}